@@ -0,0 +1,208 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::error::{Error, ErrorKind};
+use crate::input;
+use crate::params::{self, Selection};
+use crate::part::Part;
+
+
+/* the answers file sits next to a day's bundled input */
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/* Answer - one recorded answer: the input file it belongs to (by name, so
+`sample.txt` matches wherever the file is read from), the part and the value */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+  pub input: String,
+  pub part: Part,
+  pub answer: String,
+}
+
+/* Answers - a day's `answers.txt`: one `<input file> <part> <answer>` per
+line, with blank lines and `#` comments ignored */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+  entries: Vec<Answer>,
+}
+
+/* Verdict - how an answer compares with the recorded one */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+  Pass,
+  Fail { expected: String },
+  Unknown,
+}
+
+impl Answers {
+  pub fn parse(text: &str) -> Result<Answers, Error> {
+    let mut entries: Vec<Answer> = Vec::new();
+    for (y, line) in text.lines().enumerate() {
+      let line = line.split('#').next().unwrap_or_default().trim_end();
+      if line.trim().is_empty() {
+        continue;
+      }
+
+      let mut fields = line.split_whitespace();
+      let (Some(input), Some(part), Some(answer), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+      else {
+        return Err(
+          Error::syntax(format!(
+            "{ANSWERS_FILE}: expected '<input file> <part> <answer>'"
+          ))
+          .on_line(y + 1, line),
+        );
+      };
+      let part = part.parse::<Part>().map_err(|e| {
+        Error::value(format!("{ANSWERS_FILE}: {e}"))
+          .near(part)
+          .on_line(y + 1, line)
+      })?;
+      if entries.iter().any(|e| e.input == input && e.part == part) {
+        return Err(
+          Error::value(format!(
+            "{ANSWERS_FILE}: part {part} of '{input}' is recorded twice"
+          ))
+          .on_line(y + 1, line),
+        );
+      }
+
+      entries.push(Answer {
+        input: input.to_string(),
+        part,
+        answer: answer.to_string(),
+      });
+    }
+
+    Ok(Answers { entries })
+  }
+
+  /* load - reads an answers file; a day without one has no answers yet */
+  pub fn load(path: &Path) -> Result<Answers, Error> {
+    if !path.exists() {
+      return Ok(Answers::default());
+    }
+
+    let text = std::fs::read_to_string(path).map_err(|e| {
+      Error::new(
+        ErrorKind::Input,
+        format!("could not read '{}': {e}", path.display()),
+      )
+    })?;
+
+    Answers::parse(&text)
+  }
+
+  pub fn entries(&self) -> &[Answer] {
+    &self.entries
+  }
+
+  pub fn expected(&self, input: &str, part: Part) -> Option<&str> {
+    self
+      .entries
+      .iter()
+      .find(|e| e.input == input && e.part == part)
+      .map(|e| e.answer.as_str())
+  }
+
+  /* verdict - compares `answer` with the one recorded for `input`; input
+  without a file name (stdin) never has a recorded answer */
+  pub fn verdict(
+    &self,
+    input: Option<&str>,
+    part: Part,
+    answer: &str,
+  ) -> Verdict {
+    match input.and_then(|input| self.expected(input, part)) {
+      Some(expected) if expected == answer => Verdict::Pass,
+      Some(expected) => Verdict::Fail { expected: expected.to_string() },
+      None => Verdict::Unknown,
+    }
+  }
+}
+
+/* check - compares `answer` with the one recorded for whatever input the
+last `input::read` call read. The answers recorded are the puzzle's, so with
+other parameters selected there is nothing to compare with. */
+pub fn check(answer: &str, part: Part) -> Result<Verdict, Error> {
+  let Some(read) = input::last_read() else {
+    return Ok(Verdict::Unknown);
+  };
+  if params::selected().is_some_and(|s| s != Selection::default()) {
+    return Ok(Verdict::Unknown);
+  }
+  let answers = Answers::load(&read.bundled.with_file_name(ANSWERS_FILE))?;
+
+  Ok(answers.verdict(read.name().as_deref(), part, answer))
+}
+
+impl Verdict {
+  /* confirm - a failing verdict as the error it amounts to */
+  pub fn confirm(&self, answer: &str) -> Result<(), Error> {
+    match self {
+      Verdict::Fail { expected } => Err(Error::new(
+        ErrorKind::Mismatch,
+        format!("answer {answer} differs from the recorded {expected}"),
+      )),
+      Verdict::Pass | Verdict::Unknown => Ok(()),
+    }
+  }
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Verdict::Pass => write!(f, "pass"),
+      Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+      Verdict::Unknown => write!(f, "unknown"),
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::error::Position;
+
+  const ANSWERS: &str = "# input      part  answer
+sample.txt   1     142
+sample.txt   2     281   # from the puzzle text
+
+input.txt    1     54304
+";
+
+  #[test]
+  fn it_should_judge_answers_against_the_recorded_ones() {
+    let answers = Answers::parse(ANSWERS).unwrap();
+
+    assert_eq!(answers.entries().len(), 3);
+    assert_eq!(
+      answers.verdict(Some("sample.txt"), Part::Two, "281"),
+      Verdict::Pass
+    );
+    assert_eq!(
+      answers.verdict(Some("input.txt"), Part::One, "54305"),
+      Verdict::Fail { expected: "54304".to_string() }
+    );
+    assert_eq!(
+      answers.verdict(Some("input.txt"), Part::Two, "1"),
+      Verdict::Unknown
+    );
+    assert_eq!(answers.verdict(None, Part::One, "142"), Verdict::Unknown);
+  }
+
+  #[test]
+  fn it_should_position_malformed_entries() {
+    let error = Answers::parse("sample.txt 1 142\nsample.txt 3 9").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 12 }));
+
+    let error = Answers::parse("sample.txt 142").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+
+    let error = Answers::parse("a.txt 1 1\na.txt 1 2").unwrap_err();
+    assert_eq!(error.position.map(|p| p.line), Some(2));
+  }
+}
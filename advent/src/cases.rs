@@ -0,0 +1,284 @@
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::error::{Error, ErrorKind};
+use crate::params::{self, Selection};
+use crate::part::Part;
+use crate::solution::Solution;
+
+
+/* regression cases sit in this directory of a day, a file each */
+pub const CASES_DIR: &str = "cases";
+// a case file's header ends at this line, and its input starts after it
+const SEPARATOR: &str = "---";
+
+/* Case - an input a day is tested on: the file it came from, the parameters
+it is solved with and the answer expected of each part recorded for it */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Case {
+  pub name: String,
+  pub input: String,
+  pub params: Selection,
+  pub expected: Vec<(Part, String)>,
+}
+
+/* Feed - the input a day's mocked `src_provider` hands out, swapped for each
+case as it is solved. The mock keeps a clone and reads it on every call. */
+#[derive(Clone, Debug, Default)]
+pub struct Feed(Arc<Mutex<String>>);
+
+impl Feed {
+  pub fn input(&self) -> Result<String, Error> {
+    Ok(self.0.lock().unwrap_or_else(|e| e.into_inner()).clone())
+  }
+
+  fn set(&self, input: &str) {
+    *self.0.lock().unwrap_or_else(|e| e.into_inner()) = input.to_string();
+  }
+}
+
+/* discover - every case of the day in `dir`: each `sample*.txt`, expecting
+the answers `answers.txt` records for it, then each file in `cases/`, which
+carries its own. Both come in name order. */
+pub fn discover(dir: &Path) -> Result<Vec<Case>, Error> {
+  let answers = Answers::load(&dir.join(ANSWERS_FILE))?;
+  let samples = files(dir)?
+    .into_iter()
+    .filter(|name| name.starts_with("sample") && name.ends_with(".txt"));
+  let mut cases = Vec::new();
+  for name in samples {
+    let expected = answers
+      .entries()
+      .iter()
+      .filter(|entry| entry.input == name)
+      .map(|entry| (entry.part, entry.answer.clone()))
+      .collect();
+    let input = read(&dir.join(&name))?;
+    cases.push(Case { name, input, expected, ..Case::default() });
+  }
+
+  let cases_dir = dir.join(CASES_DIR);
+  if cases_dir.is_dir() {
+    for name in files(&cases_dir)? {
+      let name = format!("{CASES_DIR}/{name}");
+      cases.push(Case::parse(&name, &read(&dir.join(&name))?)?);
+    }
+  }
+
+  Ok(cases)
+}
+
+impl Case {
+  /* parse - a case file: a header of `part <part>: <answer>` and
+  `set <key>=<value>` lines, with blank lines and `#` comments, then `---` on
+  a line of its own and the input, verbatim */
+  pub fn parse(name: &str, text: &str) -> Result<Case, Error> {
+    let mut case = Case { name: name.to_string(), ..Case::default() };
+    let mut separated = false;
+    for (y, line) in text.split_inclusive('\n').enumerate() {
+      let line = line.split('#').next().unwrap_or_default().trim();
+      let malformed = |message: String| {
+        Error::syntax(format!("{name}: {message}")).on_line(y + 1, line)
+      };
+      if line == SEPARATOR {
+        separated = true;
+        case.input = text.split_inclusive('\n').skip(y + 1).collect();
+        break;
+      } else if line.is_empty() {
+        continue;
+      } else if let Some(spec) = line.strip_prefix("set ") {
+        case
+          .params
+          .overrides
+          .push(params::parse_override(spec).map_err(|e| {
+            Error::value(format!("{name}: {e}")).on_line(y + 1, line)
+          })?);
+      } else if let Some(answer) = line.strip_prefix("part ") {
+        let Some((part, answer)) = answer.split_once(':') else {
+          return Err(malformed("expected 'part <part>: <answer>'".into()));
+        };
+        let part = part.trim().parse::<Part>().map_err(|e| {
+          Error::value(format!("{name}: {e}"))
+            .near(part)
+            .on_line(y + 1, line)
+        })?;
+        if case.expected.iter().any(|(p, _)| *p == part) {
+          return Err(malformed(format!("part {part} is expected twice")));
+        }
+        case.expected.push((part, answer.trim().to_string()));
+      } else {
+        return Err(malformed(
+          "expected 'part <part>: <answer>', 'set <key>=<value>' or '---'"
+            .into(),
+        ));
+      }
+    }
+    if !separated {
+      return Err(Error::missing(format!(
+        "{name}: no '{SEPARATOR}' line before the input"
+      )));
+    }
+
+    Ok(case)
+  }
+}
+
+/* failures - solves every expected part of every case through `S`'s whole
+pipeline, `feed` handing out each case's input, and describes each answer
+that differs from the expected one or never came */
+pub fn failures<S: Solution>(cases: &[Case], feed: &Feed) -> Vec<String> {
+  let mut failures = Vec::new();
+  for case in cases {
+    feed.set(&case.input);
+    for (part, expected) in &case.expected {
+      // the case's parameters hold for this thread alone, so the day's other
+      // tests keep reading their own
+      let solved = params::scoped(case.params.clone(), || {
+        S::solve(*part).and_then(|consequent| S::answer(&consequent))
+      });
+      match solved {
+        Ok(answer) if answer == *expected => {}
+        Ok(answer) => failures.push(format!(
+          "{} part {part}: expected {expected}, got {answer}",
+          case.name
+        )),
+        Err(e) => failures.push(format!("{} part {part}: {e}", case.name)),
+      }
+    }
+  }
+
+  failures
+}
+
+/* assert_solves - the test every day runs: discovers the cases of the day in
+`dir` and solves them, panicking with every failure at once */
+pub fn assert_solves<S: Solution>(dir: &str, feed: &Feed) {
+  let cases = discover(Path::new(dir)).unwrap_or_else(|e| panic!("{e}"));
+  let failures = failures::<S>(&cases, feed);
+
+  assert!(
+    failures.is_empty(),
+    "day {} failed {} of its cases:\n{}",
+    S::DAY,
+    failures.len(),
+    failures.join("\n")
+  );
+}
+
+/* files - the names of the files in `dir`, in order */
+fn files(dir: &Path) -> Result<Vec<String>, Error> {
+  let entries = fs::read_dir(dir).map_err(|e| read_error(dir, e))?;
+  let mut names = Vec::new();
+  for entry in entries {
+    let entry = entry.map_err(|e| read_error(dir, e))?;
+    if entry.path().is_file() {
+      names.push(entry.file_name().to_string_lossy().into_owned());
+    }
+  }
+  names.sort();
+
+  Ok(names)
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+  fs::read_to_string(path).map_err(|e| read_error(path, e))
+}
+
+fn read_error(path: &Path, e: std::io::Error) -> Error {
+  Error::new(
+    ErrorKind::Input,
+    format!("could not read '{}': {e}", path.display()),
+  )
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CASE: &str = "# the walk from the puzzle text
+set part1_steps = 6
+part 1: 16   # plots
+
+---
+...
+.S.
+# not a comment down here
+";
+
+  struct Doubler;
+
+  impl Solution for Doubler {
+    type ProblemDefinition = i64;
+    type Consequent = i64;
+
+    const DAY: u8 = 0;
+
+    fn extract(_part: Part) -> Result<i64, Error> {
+      FEED
+        .with(|feed| feed.input())?
+        .trim()
+        .parse()
+        .map_err(|e| Error::value(format!("{e}")))
+    }
+
+    fn transform(data: i64, part: Part) -> Result<i64, Error> {
+      Ok(data * if part == Part::One { 2 } else { 3 })
+    }
+
+    fn load(_result: Result<i64, Error>, _part: Part) -> Result<(), Error> {
+      Ok(())
+    }
+
+    fn answer(consequent: &i64) -> Result<String, Error> {
+      Ok(consequent.to_string())
+    }
+  }
+
+  thread_local! {
+    static FEED: Feed = Feed::default();
+  }
+
+  #[test]
+  fn it_should_parse_a_case_file() {
+    let case = Case::parse("cases/walk.txt", CASE).unwrap();
+
+    assert_eq!(case.expected, [(Part::One, "16".to_string())]);
+    assert_eq!(
+      case.params.overrides,
+      [("part1_steps".to_string(), "6".to_string())]
+    );
+    assert_eq!(case.input, "...\n.S.\n# not a comment down here\n");
+
+    let error = Case::parse("a.txt", "part 3: 1\n---\n").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    let error = Case::parse("a.txt", "part 1: 1\npart 1: 2\n---\n");
+    assert_eq!(error.unwrap_err().kind, ErrorKind::Syntax);
+    let error = Case::parse("a.txt", "part 1: 1\n...\n").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    let error = Case::parse("a.txt", "part 1: 1\n").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Missing);
+  }
+
+  #[test]
+  fn it_should_report_every_failing_case() {
+    let case = |name: &str, input: &str, expected: &[(Part, &str)]| Case {
+      name: name.to_string(),
+      input: input.to_string(),
+      expected: expected.iter().map(|&(p, a)| (p, a.to_string())).collect(),
+      ..Case::default()
+    };
+    let cases = [
+      case("sample.txt", "7", &[(Part::One, "14"), (Part::Two, "21")]),
+      case("cases/wrong.txt", "7", &[(Part::Two, "22")]),
+      case("cases/broken.txt", "x", &[(Part::One, "0")]),
+    ];
+
+    let failures = FEED.with(|feed| failures::<Doubler>(&cases, feed));
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0], "cases/wrong.txt part 2: expected 22, got 21");
+    assert!(failures[1].starts_with("cases/broken.txt part 1: "));
+  }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+
+use crate::error::{Error, ErrorKind};
+
+
+/* Check - one property of the input that a day's `transform` relies on
+without verifying it, and what was found instead when the input lacks it */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+  pub assumption: String,
+  pub violation: Option<String>,
+}
+
+impl Check {
+  /* that - `assumption`, asking `found` what the input holds instead only
+  when it does not hold */
+  pub fn that<F>(assumption: impl Into<String>, holds: bool, found: F) -> Check
+  where
+    F: FnOnce() -> String,
+  {
+    Check {
+      assumption: assumption.into(),
+      violation: (!holds).then(found),
+    }
+  }
+
+  /* of - `assumption`, violated with the message of `result`'s error */
+  pub fn of(
+    assumption: impl Into<String>,
+    result: Result<(), String>,
+  ) -> Check {
+    Check { assumption: assumption.into(), violation: result.err() }
+  }
+
+  pub fn holds(&self) -> bool {
+    self.violation.is_none()
+  }
+}
+
+impl fmt::Display for Check {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.violation {
+      None => write!(f, "ok    {}", self.assumption),
+      Some(violation) => write!(f, "FAIL  {}: {violation}", self.assumption),
+    }
+  }
+}
+
+/* report - a line per check, or a note that the day assumes nothing beyond
+what it validates while reading its input */
+pub fn report(checks: &[Check]) -> String {
+  match checks {
+    [] => "no assumptions beyond the input's syntax\n".to_string(),
+    _ => checks.iter().map(|check| format!("{check}\n")).collect(),
+  }
+}
+
+/* confirm - an error when any check failed, since solving would then give a
+wrong answer or none */
+pub fn confirm(checks: &[Check]) -> Result<(), Error> {
+  match checks.iter().filter(|check| !check.holds()).count() {
+    0 => Ok(()),
+    failed => Err(Error::new(
+      ErrorKind::Unsupported,
+      format!("{failed} of {} assumptions do not hold", checks.len()),
+    )),
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_report_every_check() {
+    let checks = [
+      Check::that("the map is square", true, || unreachable!()),
+      Check::of("S is centred", Err("S is at 1,2".to_string())),
+    ];
+
+    assert_eq!(
+      report(&checks),
+      "ok    the map is square\nFAIL  S is centred: S is at 1,2\n"
+    );
+    assert_eq!(report(&[]), "no assumptions beyond the input's syntax\n");
+  }
+
+  #[test]
+  fn it_should_fail_when_any_check_fails() {
+    let holds = Check::that("holds", true, String::new);
+    let fails = Check::that("fails", false, || "no".to_string());
+
+    let checks = [holds, fails];
+
+    assert_eq!(confirm(&checks[..1]), Ok(()));
+    let error = confirm(&checks).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Unsupported);
+    assert_eq!(error.message, "1 of 2 assumptions do not hold");
+  }
+}
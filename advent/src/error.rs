@@ -0,0 +1,259 @@
+use std::fmt;
+
+
+/* Phase - the step of a day's pipeline an error surfaced in */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+  Extract,
+  Check,
+  Transform,
+  Load,
+  // extract and transform folded together, line by line
+  Stream,
+  // drawing the puzzle's state rather than solving it
+  Render,
+  // showing how the answer is arrived at rather than arriving at it
+  Explain,
+  // reading the day's parameters, whichever phase asked for them
+  Params,
+}
+
+/* ErrorKind - what went wrong, for callers and tests to match on */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+  // the command line could not be understood
+  Usage,
+  // the input could not be read at all
+  Input,
+  // a line does not have the expected shape
+  Syntax,
+  // a token has the right shape but an unusable value (number, tile, ...)
+  Value,
+  // something the puzzle relies on is absent (start tile, a section, ...)
+  Missing,
+  // the input was understood but no answer could be found
+  NoSolution,
+  // an answer differs from the one recorded for its input
+  Mismatch,
+  // the input lacks a property the solution relies on
+  Unsupported,
+  // free-form messages that have not been given a kind
+  Other,
+}
+
+/* Position - 1-based line and column in the puzzle input */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+  pub line: usize,
+  pub column: usize,
+}
+
+/* Error - the error every day returns. Days describe what and where; the
+driver fills in the day and phase. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+  pub kind: ErrorKind,
+  pub message: String,
+  pub day: Option<u8>,
+  pub phase: Option<Phase>,
+  pub position: Option<Position>,
+  pub snippet: Option<String>,
+}
+
+impl Error {
+  pub fn new(kind: ErrorKind, message: impl Into<String>) -> Error {
+    Error {
+      kind,
+      message: message.into(),
+      day: None,
+      phase: None,
+      position: None,
+      snippet: None,
+    }
+  }
+
+  pub fn syntax(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::Syntax, message)
+  }
+
+  pub fn value(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::Value, message)
+  }
+
+  pub fn missing(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::Missing, message)
+  }
+
+  pub fn no_solution(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::NoSolution, message)
+  }
+
+  /* at - positions the error at a 1-based line and column */
+  pub fn at(mut self, line: usize, column: usize) -> Error {
+    self.position = Some(Position { line, column });
+    self
+  }
+
+  /* near - quotes the offending fragment; `on_line` later turns it into a
+  column once the line is known */
+  pub fn near(self, fragment: &str) -> Error {
+    self.snippet(fragment)
+  }
+
+  /* on_line - positions the error on the 1-based `number`th line of input,
+  at the fragment given to `near` (or the start of the line), and quotes the
+  whole line */
+  pub fn on_line(self, number: usize, line: &str) -> Error {
+    let column = self
+      .snippet
+      .as_deref()
+      .filter(|fragment| !fragment.is_empty())
+      .and_then(|fragment| line.find(fragment))
+      .map_or(1, |offset| line[..offset].chars().count() + 1);
+
+    self.at(number, column).snippet(line)
+  }
+
+  /* at_offset - positions the error at a byte offset into the whole input,
+  e.g. where a parser gave up, and quotes the line it falls on */
+  pub fn at_offset(self, input: &str, offset: usize) -> Error {
+    let mut offset = offset.min(input.len());
+    while !input.is_char_boundary(offset) {
+      offset -= 1;
+    }
+    let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = input[offset..]
+      .find('\n')
+      .map_or(input.len(), |i| offset + i);
+    let line = input[..offset].matches('\n').count() + 1;
+    let column = input[start..offset].chars().count() + 1;
+
+    self.at(line, column).snippet(&input[start..end])
+  }
+
+  pub fn snippet(mut self, snippet: &str) -> Error {
+    self.snippet = Some(snippet.to_string());
+    self
+  }
+
+  /* during - records where the error surfaced, keeping anything already set */
+  pub fn during(mut self, phase: Phase, day: u8) -> Error {
+    self.phase.get_or_insert(phase);
+    self.day.get_or_insert(day);
+    self
+  }
+}
+
+impl From<String> for Error {
+  fn from(message: String) -> Self {
+    Error::new(ErrorKind::Other, message)
+  }
+}
+
+impl From<&str> for Error {
+  fn from(message: &str) -> Self {
+    Error::new(ErrorKind::Other, message)
+  }
+}
+
+impl fmt::Display for Phase {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Phase::Extract => write!(f, "extract"),
+      Phase::Check => write!(f, "check"),
+      Phase::Transform => write!(f, "transform"),
+      Phase::Load => write!(f, "load"),
+      Phase::Stream => write!(f, "stream"),
+      Phase::Render => write!(f, "render"),
+      Phase::Explain => write!(f, "explain"),
+      Phase::Params => write!(f, "params"),
+    }
+  }
+}
+
+/* `[day 4 extract] line 3, column 9: invalid number`, followed by the quoted
+line and a caret under the column when a snippet is known */
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let context: Vec<String> = [
+      self.day.map(|day| format!("day {day}")),
+      self.phase.map(|phase| phase.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !context.is_empty() {
+      write!(f, "[{}] ", context.join(" "))?;
+    }
+    if let Some(Position { line, column }) = self.position {
+      write!(f, "line {line}, column {column}: ")?;
+    }
+    write!(f, "{}", self.message)?;
+
+    if let Some(snippet) = &self.snippet {
+      write!(f, "\n  | {snippet}")?;
+      if let Some(Position { column, .. }) = self.position {
+        write!(f, "\n  | {:>column$}", "^")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl std::error::Error for Error {}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_locate_a_fragment_within_its_line() {
+    let line = "Card 1: 41 x8 | 83";
+    let error = Error::value("invalid number 'x8'")
+      .near("x8")
+      .on_line(3, line);
+
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 3, column: 12 }));
+    assert_eq!(error.snippet.as_deref(), Some(line));
+  }
+
+  #[test]
+  fn it_should_locate_an_offset_within_the_input() {
+    let input = "seeds: 1 2\n\nseed-to-soil map:\n50 x8 2\n";
+    let error = Error::syntax("expected a number").at_offset(input, 33);
+
+    assert_eq!(error.position, Some(Position { line: 4, column: 4 }));
+    assert_eq!(error.snippet.as_deref(), Some("50 x8 2"));
+  }
+
+  #[test]
+  fn it_should_render_context_position_and_snippet() {
+    let line = "R x (#70c710)";
+    let error = Error::value("invalid distance")
+      .near("x")
+      .on_line(2, line)
+      .during(Phase::Extract, 18)
+      .during(Phase::Load, 1);
+
+    assert_eq!(
+      error.to_string(),
+      "[day 18 extract] line 2, column 3: invalid distance\n  | R x \
+       (#70c710)\n  |   ^"
+    );
+  }
+
+  #[test]
+  fn it_should_keep_plain_messages_plain() {
+    let error = Error::from("no path found!");
+
+    assert_eq!(error.kind, ErrorKind::Other);
+    assert_eq!(error.to_string(), "no path found!");
+    assert_eq!(
+      error.on_line(4, "#.#").position,
+      Some(Position { line: 4, column: 1 })
+    );
+  }
+}
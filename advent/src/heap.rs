@@ -0,0 +1,115 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+/* Counting - the system allocator, keeping count of the bytes currently
+allocated and the most ever allocated at once. Installed as the global
+allocator of everything linking `advent`, so every day can report its peak
+heap use without an external profiler. */
+pub struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn grow(bytes: usize) {
+  let now = CURRENT.fetch_add(bytes, Ordering::Relaxed) + bytes;
+  PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+fn shrink(bytes: usize) {
+  CURRENT.fetch_sub(bytes, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc(layout);
+    if !ptr.is_null() {
+      grow(layout.size());
+    }
+
+    ptr
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc_zeroed(layout);
+    if !ptr.is_null() {
+      grow(layout.size());
+    }
+
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+    shrink(layout.size());
+  }
+
+  unsafe fn realloc(
+    &self,
+    ptr: *mut u8,
+    layout: Layout,
+    new_size: usize,
+  ) -> *mut u8 {
+    let moved = System.realloc(ptr, layout, new_size);
+    if !moved.is_null() {
+      match new_size > layout.size() {
+        true => grow(new_size - layout.size()),
+        false => shrink(layout.size() - new_size),
+      }
+    }
+
+    moved
+  }
+}
+
+/* reset_peak - starts a new peak at the current allocation, returning it as
+the baseline later peaks are measured against */
+pub fn reset_peak() -> usize {
+  let now = CURRENT.load(Ordering::Relaxed);
+  PEAK.store(now, Ordering::Relaxed);
+
+  now
+}
+
+/* peak_since - the most allocated at once since `reset_peak` returned
+`baseline`, beyond that baseline */
+pub fn peak_since(baseline: usize) -> usize {
+  PEAK.load(Ordering::Relaxed).saturating_sub(baseline)
+}
+
+/* format - a byte count in the largest binary unit that keeps it above 1 */
+pub fn format(bytes: usize) -> String {
+  let units = ["B", "KiB", "MiB", "GiB"];
+  let mut size = bytes as f64;
+  let mut unit = 0;
+  while size >= 1024.0 && unit < units.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+
+  match unit {
+    0 => format!("{bytes} B"),
+    _ => format!("{size:.1} {}", units[unit]),
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_count_allocations_towards_the_peak() {
+    let baseline = reset_peak();
+    let block = vec![0u8; 1 << 20];
+    assert!(peak_since(baseline) >= block.len());
+    drop(block);
+  }
+
+  #[test]
+  fn it_should_format_byte_counts() {
+    assert_eq!(format(512), "512 B");
+    assert_eq!(format(1536), "1.5 KiB");
+    assert_eq!(format(3 << 20), "3.0 MiB");
+  }
+}
@@ -0,0 +1,348 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, ErrorKind};
+
+
+/* Source - where a day reads its puzzle input from at runtime. When nothing is
+selected, `read` falls back to the file bundled with the day crate. `Text` is
+input already in memory, e.g. stdin read once and replayed for several runs. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+  File(PathBuf),
+  Stdin,
+  Text(String),
+}
+
+/* Provenance - what the last `read` call read: the day's bundled file, whose
+directory also holds its answers, the file actually read (none for stdin or
+in-memory text) and how long reading it took, once it has been read. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Provenance {
+  pub bundled: PathBuf,
+  pub path: Option<PathBuf>,
+  pub elapsed: Option<Duration>,
+}
+
+impl Provenance {
+  /* name - the file name answers are recorded under */
+  pub fn name(&self) -> Option<String> {
+    let name = self.path.as_deref()?.file_name()?;
+
+    Some(name.to_string_lossy().into_owned())
+  }
+
+  /* identifier - the path that was read, or `-` for stdin */
+  pub fn identifier(&self) -> String {
+    match &self.path {
+      Some(path) => path.display().to_string(),
+      None => "-".to_string(),
+    }
+  }
+}
+
+static SELECTED: Mutex<Option<Source>> = Mutex::new(None);
+static LAST_READ: Mutex<Option<Provenance>> = Mutex::new(None);
+
+impl Source {
+  pub fn read(&self) -> Result<String, Error> {
+    match self {
+      Source::File(path) => read_file(path),
+      Source::Stdin => {
+        let mut buffer = String::new();
+        std::io::stdin()
+          .read_to_string(&mut buffer)
+          .map_err(|e| input_error(format!("could not read stdin: {e}")))?;
+
+        Ok(buffer)
+      }
+      Source::Text(text) => Ok(text.clone()),
+    }
+  }
+
+  /* buffered - reads stdin now so the source can be read more than once */
+  pub fn buffered(self) -> Result<Source, Error> {
+    match self {
+      Source::Stdin => Source::Stdin.read().map(Source::Text),
+      source => Ok(source),
+    }
+  }
+}
+
+impl From<&str> for Source {
+  fn from(value: &str) -> Self {
+    match value {
+      "-" => Source::Stdin,
+      path => Source::File(PathBuf::from(path)),
+    }
+  }
+}
+
+/* select - chooses the input for subsequent `read` calls; `None` restores the
+bundled file. */
+pub fn select(source: Option<Source>) {
+  *SELECTED.lock().unwrap_or_else(|e| e.into_inner()) = source;
+}
+
+pub fn selected() -> Option<Source> {
+  SELECTED.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+pub fn last_read() -> Option<Provenance> {
+  LAST_READ.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/* read - reads the selected input, or the bundled file at `bundled` when no
+input was selected. Windows line endings are normalised so days only ever see
+`\n`, and an empty input is refused before any day tries to parse it. */
+pub fn read(bundled: &str) -> Result<String, Error> {
+  let start = Instant::now();
+  let selected = selected();
+  let path = match &selected {
+    Some(Source::File(path)) => Some(path.clone()),
+    Some(Source::Stdin | Source::Text(_)) => None,
+    None => Some(PathBuf::from(bundled)),
+  };
+  let mut provenance =
+    Provenance { bundled: PathBuf::from(bundled), path, elapsed: None };
+  *LAST_READ.lock().unwrap_or_else(|e| e.into_inner()) =
+    Some(provenance.clone());
+
+  let text = match selected {
+    Some(source) => source.read()?,
+    None => read_file(bundled_file(bundled)?)?,
+  };
+  let text = normalize(text);
+
+  provenance.elapsed = Some(start.elapsed());
+  *LAST_READ.lock().unwrap_or_else(|e| e.into_inner()) = Some(provenance);
+
+  text
+}
+
+/* lines - like `read`, but the input comes a line at a time as it is read
+rather than whole, so a day folding its answer as it goes holds only the
+current line however large the input is. */
+pub fn lines(bundled: &str) -> Result<Lines, Error> {
+  let start = Instant::now();
+  let selected = selected();
+  let path = match &selected {
+    Some(Source::File(path)) => Some(path.clone()),
+    Some(Source::Stdin | Source::Text(_)) => None,
+    None => Some(PathBuf::from(bundled)),
+  };
+  let provenance =
+    Provenance { bundled: PathBuf::from(bundled), path, elapsed: None };
+  *LAST_READ.lock().unwrap_or_else(|e| e.into_inner()) =
+    Some(provenance.clone());
+
+  let reader: Box<dyn BufRead> = match selected {
+    Some(Source::File(path)) => Box::new(open_file(&path)?),
+    Some(Source::Stdin) => Box::new(std::io::stdin().lock()),
+    Some(Source::Text(text)) => Box::new(Cursor::new(text.into_bytes())),
+    None => Box::new(open_file(bundled_file(bundled)?)?),
+  };
+  let mut lines = Lines::new(reader);
+  lines.elapsed = start.elapsed();
+  lines.provenance = Some(provenance);
+
+  Ok(lines)
+}
+
+/* Lines - an input read a line at a time. Line endings are dropped, `\r\n`
+included, and like `read` it refuses an input with nothing but whitespace:
+blank lines are held back until one with text turns up, so a day never sees
+them. Once the input ends, the time spent reading it is recorded for
+`last_read`. */
+pub struct Lines {
+  reader: Box<dyn BufRead>,
+  held: VecDeque<String>,
+  blank: bool,
+  done: bool,
+  elapsed: Duration,
+  provenance: Option<Provenance>,
+}
+
+impl Lines {
+  pub fn new(reader: impl BufRead + 'static) -> Lines {
+    Lines {
+      reader: Box::new(reader),
+      held: VecDeque::new(),
+      blank: true,
+      done: false,
+      elapsed: Duration::ZERO,
+      provenance: None,
+    }
+  }
+
+  fn read_line(&mut self) -> Result<Option<String>, Error> {
+    let start = Instant::now();
+    let mut line = String::new();
+    let read = self.reader.read_line(&mut line);
+    self.elapsed += start.elapsed();
+
+    match read {
+      Ok(0) => {
+        self.done = true;
+        if let Some(mut provenance) = self.provenance.take() {
+          provenance.elapsed = Some(self.elapsed);
+          *LAST_READ.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(provenance);
+        }
+
+        Ok(None)
+      }
+      Ok(_) => {
+        if line.ends_with('\n') {
+          line.pop();
+          if line.ends_with('\r') {
+            line.pop();
+          }
+        }
+
+        Ok(Some(line))
+      }
+      Err(e) => {
+        self.done = true;
+        Err(input_error(format!("could not read the input: {e}")))
+      }
+    }
+  }
+}
+
+impl Iterator for Lines {
+  type Item = Result<String, Error>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.blank && !self.done {
+      match self.read_line() {
+        Ok(Some(line)) => {
+          self.blank = line.trim().is_empty();
+          self.held.push_back(line);
+        }
+        Ok(None) => {
+          self.held.clear();
+          return Some(Err(input_error(
+            "the puzzle input is empty".to_string(),
+          )));
+        }
+        Err(e) => return Some(Err(e)),
+      }
+    }
+    if let Some(line) = self.held.pop_front() {
+      return Some(Ok(line));
+    }
+
+    match self.done {
+      true => None,
+      false => self.read_line().transpose(),
+    }
+  }
+}
+
+fn normalize(text: String) -> Result<String, Error> {
+  if text.trim().is_empty() {
+    return Err(input_error("the puzzle input is empty".to_string()));
+  }
+
+  match text.contains('\r') {
+    true => Ok(text.replace("\r\n", "\n")),
+    false => Ok(text),
+  }
+}
+
+/* bundled_file - the day's own input, used when nothing was selected */
+fn bundled_file(bundled: &str) -> Result<&Path, Error> {
+  let path = Path::new(bundled);
+  if !path.exists() {
+    return Err(input_error(format!(
+      "no input given and the bundled file '{}' does not exist; pass a path, \
+       or '-' to read stdin",
+      path.display()
+    )));
+  }
+
+  Ok(path)
+}
+
+fn open_file(path: &Path) -> Result<BufReader<std::fs::File>, Error> {
+  std::fs::File::open(path).map(BufReader::new).map_err(|e| {
+    input_error(format!("could not read '{}': {e}", path.display()))
+  })
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+  std::fs::read_to_string(path).map_err(|e| {
+    input_error(format!("could not read '{}': {e}", path.display()))
+  })
+}
+
+fn input_error(message: String) -> Error {
+  Error::new(ErrorKind::Input, message)
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_read_stdin_for_a_dash() {
+    assert_eq!(Source::from("-"), Source::Stdin);
+    assert_eq!(Source::from("a.txt"), Source::File(PathBuf::from("a.txt")));
+  }
+
+  #[test]
+  fn it_should_replay_text_sources() {
+    let source = Source::Text("1abc2".to_string()).buffered();
+    assert_eq!(
+      source.clone().and_then(|s| s.read()),
+      Ok("1abc2".to_string())
+    );
+    assert_eq!(source.and_then(|s| s.read()), Ok("1abc2".to_string()));
+  }
+
+  #[test]
+  fn it_should_report_a_missing_file() {
+    let source = Source::File(PathBuf::from("/definitely/not/here.txt"));
+    let err = source.read().unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Input);
+    assert!(err.message.contains("/definitely/not/here.txt"));
+  }
+
+  #[test]
+  fn it_should_normalize_line_endings_and_refuse_empty_input() {
+    assert_eq!(
+      normalize("1abc2\r\n\r\npqr3\r\n".to_string()),
+      Ok("1abc2\n\npqr3\n".to_string())
+    );
+    assert_eq!(
+      normalize(" \r\n".to_string()).map_err(|e| e.kind),
+      Err(ErrorKind::Input)
+    );
+  }
+
+  #[test]
+  fn it_should_stream_lines_without_their_endings() {
+    let lines = |text: &'static str| {
+      Lines::new(text.as_bytes())
+        .map(|line| line.map_err(|e| e.kind))
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+      lines("1abc2\r\n\r\npqr3\n"),
+      [
+        Ok("1abc2".to_string()),
+        Ok(String::new()),
+        Ok("pqr3".to_string())
+      ]
+    );
+    assert_eq!(lines("a\nb"), ["a", "b"].map(|s| Ok(s.to_string())));
+    assert_eq!(lines(" \r\n\n"), [Err(ErrorKind::Input)]);
+    assert_eq!(lines("\n\nx")[2], Ok("x".to_string()));
+  }
+}
@@ -0,0 +1,30 @@
+pub mod answers;
+pub mod cases;
+pub mod check;
+pub mod error;
+pub mod heap;
+pub mod input;
+pub mod measure;
+pub mod options;
+pub mod parallel;
+pub mod params;
+pub mod part;
+pub mod render;
+pub mod solution;
+pub mod total;
+pub mod trace;
+
+pub use answers::{Answers, Verdict};
+pub use check::Check;
+pub use error::{Error, ErrorKind, Phase, Position};
+pub use measure::{measure, Run, Sample, Stage, Stats};
+pub use options::{Format, Options};
+pub use params::Params;
+pub use part::Part;
+pub use render::{Cell, Frame, Renderer, Rgb};
+pub use solution::{main, Solution};
+pub use total::Total;
+
+
+#[global_allocator]
+static ALLOCATOR: heap::Counting = heap::Counting;
@@ -0,0 +1,407 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::answers::{self, Verdict};
+use crate::error::{Error, Phase};
+use crate::heap;
+use crate::input;
+use crate::part::Part;
+use crate::solution::Solution;
+
+
+/* Stage - a timed step of a measured run. `Read` is the day's `src_provider`
+and `Extract` the rest of its extract, parsing what was read. `Load` reduces
+the consequent to its answer, standing in for the day's own `load`. */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+  Read,
+  Extract,
+  Transform,
+  Load,
+}
+
+/* Sample - one repetition of a run: how long each stage took and the most
+heap it held at once. A stage that never ran (because an earlier one failed)
+has no duration. */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sample {
+  pub read: Option<Duration>,
+  pub extract: Option<Duration>,
+  pub transform: Option<Duration>,
+  pub load: Option<Duration>,
+  pub peak_heap: usize,
+}
+
+/* Stats - the spread of one stage's durations over the repetitions */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+  pub min: Duration,
+  pub median: Duration,
+  pub max: Duration,
+}
+
+/* Run - one part of one day: its answer, how it compares with the recorded
+one, the input it was read from and a sample per repetition. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+  pub answer: Result<String, Error>,
+  pub verdict: Verdict,
+  pub input: Option<String>,
+  pub samples: Vec<Sample>,
+}
+
+/* measure - drives a day through its pipeline `runs` times (at least once),
+timing each stage and tracking the heap, and checks the answer against the
+day's answers file. Repetition stops at the first failure. */
+pub fn measure<S: Solution>(part: Part, runs: usize) -> Run {
+  let (mut outcome, mut input, first) = sample::<S>(part);
+  let mut samples = vec![first];
+  while outcome.is_ok() && samples.len() < runs {
+    let (next, read, sample) = sample::<S>(part);
+    (outcome, input) = (outcome.and(next), read);
+    samples.push(sample);
+  }
+
+  let (answer, verdict) = match outcome {
+    Ok((answer, verdict)) => (Ok(answer), verdict),
+    Err(e) => (Err(e), Verdict::Unknown),
+  };
+
+  Run { answer, verdict, input, samples }
+}
+
+/* sample - a single pass through the pipeline. The read is timed by
+`input::read` itself, so it is taken out of the extract. */
+fn sample<S: Solution>(
+  part: Part,
+) -> (Result<(String, Verdict), Error>, Option<String>, Sample) {
+  let baseline = heap::reset_peak();
+  let mut sample = Sample::default();
+
+  let start = Instant::now();
+  let data = S::extract(part).map_err(|e| e.during(Phase::Extract, S::DAY));
+  let extracted = start.elapsed();
+  let read = input::last_read();
+  sample.read = read.as_ref().and_then(|read| read.elapsed);
+  sample.extract =
+    Some(extracted.saturating_sub(sample.read.unwrap_or_default()));
+  let input = read.map(|read| read.identifier());
+
+  let outcome = data.and_then(|data| {
+    let start = Instant::now();
+    let consequent =
+      S::transform(data, part).map_err(|e| e.during(Phase::Transform, S::DAY));
+    sample.transform = Some(start.elapsed());
+
+    let start = Instant::now();
+    let answer = consequent.and_then(|c| S::answer(&c));
+    sample.load = Some(start.elapsed());
+
+    answer
+      .and_then(|answer| {
+        let verdict = answers::check(&answer, part)?;
+        Ok((answer, verdict))
+      })
+      .map_err(|e| e.during(Phase::Load, S::DAY))
+  });
+  sample.peak_heap = heap::peak_since(baseline);
+
+  (outcome, input, sample)
+}
+
+impl Stage {
+  pub const ALL: [Stage; 4] =
+    [Stage::Read, Stage::Extract, Stage::Transform, Stage::Load];
+}
+
+impl fmt::Display for Stage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Stage::Read => write!(f, "read"),
+      Stage::Extract => write!(f, "extract"),
+      Stage::Transform => write!(f, "transform"),
+      Stage::Load => write!(f, "load"),
+    }
+  }
+}
+
+impl Sample {
+  pub fn duration(&self, stage: Stage) -> Option<Duration> {
+    match stage {
+      Stage::Read => self.read,
+      Stage::Extract => self.extract,
+      Stage::Transform => self.transform,
+      Stage::Load => self.load,
+    }
+  }
+}
+
+impl Stats {
+  /* of - the minimum, median (the lower middle one for an even count) and
+  maximum; nothing for no durations */
+  pub fn of(durations: &[Duration]) -> Option<Stats> {
+    let mut sorted = durations.to_vec();
+    sorted.sort_unstable();
+
+    Some(Stats {
+      min: *sorted.first()?,
+      median: sorted[(sorted.len() - 1) / 2],
+      max: *sorted.last()?,
+    })
+  }
+}
+
+/* a single duration shows as itself, a spread as `median (min..max)` */
+impl fmt::Display for Stats {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.min == self.max {
+      true => write!(f, "{:.2?}", self.median),
+      false => {
+        write!(
+          f,
+          "{:.2?} ({:.2?}..{:.2?})",
+          self.median, self.min, self.max
+        )
+      }
+    }
+  }
+}
+
+impl Run {
+  /* stats - the spread of `stage` over the repetitions it ran in */
+  pub fn stats(&self, stage: Stage) -> Option<Stats> {
+    let durations: Vec<Duration> = self
+      .samples
+      .iter()
+      .filter_map(|sample| sample.duration(stage))
+      .collect();
+
+    Stats::of(&durations)
+  }
+
+  /* peak_heap - the most heap any repetition held at once */
+  pub fn peak_heap(&self) -> Option<usize> {
+    self.samples.iter().map(|sample| sample.peak_heap).max()
+  }
+
+  /* outcome - the error this run amounts to, if any: its own, or a mismatch
+  with the recorded answer */
+  pub fn outcome(&self, day: u8) -> Result<(), Error> {
+    let answer = self.answer.as_ref().map_err(Clone::clone)?;
+
+    self
+      .verdict
+      .confirm(answer)
+      .map_err(|e| e.during(Phase::Load, day))
+  }
+
+  /* profile - the run as a short report, one line per stage */
+  pub fn profile(&self, day: u8, part: Part) -> String {
+    let input = self.input.as_deref().unwrap_or("-");
+    let mut report = match &self.answer {
+      Ok(answer) => {
+        format!(
+          "day {day} part {part} on {input}: {answer} ({})\n",
+          self.verdict
+        )
+      }
+      Err(_) => format!("day {day} part {part} on {input}: error\n"),
+    };
+    report += &format!("  {:<10} {}\n", "runs", self.samples.len());
+    for stage in Stage::ALL {
+      let stats = self.stats(stage).map_or("-".to_string(), |s| s.to_string());
+      report += &format!("  {:<10} {stats}\n", stage.to_string());
+    }
+    let peak = self.peak_heap().map_or("-".to_string(), heap::format);
+    report += &format!("  {:<10} {peak}\n", "peak heap");
+
+    report
+  }
+
+  /* to_json - the run as a single-line JSON object, e.g.
+  `{"day":3,"part":1,"input":"day-3/sample.txt","answer":"4361",
+  "check":"pass","error":null,"runs":1,"peak_heap_bytes":5012,
+  "read_ns":9021,...,"read_stats_ns":{"min":9021,"median":9021,"max":9021},
+  ...}`. Each stage keeps the single duration `--json` has always given, the
+  median of repeated runs, and adds a stats object under a key of its own. A
+  failed run has a null answer and check and the rendered error; a stage that
+  never ran is null. */
+  pub fn to_json(&self, day: u8, part: Part) -> String {
+    let (answer, check, error) = match &self.answer {
+      Ok(answer) => (
+        json_string(answer),
+        json_string(&self.verdict.to_string()),
+        "null".to_string(),
+      ),
+      Err(e) => (
+        "null".to_string(),
+        "null".to_string(),
+        json_string(&e.to_string()),
+      ),
+    };
+
+    let fields = [
+      ("day", day.to_string()),
+      ("part", part.to_string()),
+      (
+        "input",
+        self
+          .input
+          .as_deref()
+          .map_or("null".to_string(), json_string),
+      ),
+      ("answer", answer),
+      ("check", check),
+      ("error", error),
+      ("runs", self.samples.len().to_string()),
+      (
+        "peak_heap_bytes",
+        self
+          .peak_heap()
+          .map_or("null".to_string(), |b| b.to_string()),
+      ),
+    ];
+    let mut fields =
+      Vec::from(fields.map(|(key, value)| (key.to_string(), value)));
+    for stage in Stage::ALL {
+      let median = self.stats(stage).map(|s| s.median.as_nanos().to_string());
+      fields.push((format!("{stage}_ns"), median.unwrap_or("null".into())));
+    }
+    for stage in Stage::ALL {
+      let stats = self.stats(stage).map_or("null".to_string(), |s| {
+        json_object(&[
+          ("min", s.min.as_nanos().to_string()),
+          ("median", s.median.as_nanos().to_string()),
+          ("max", s.max.as_nanos().to_string()),
+        ])
+      });
+      fields.push((format!("{stage}_stats_ns"), stats));
+    }
+
+    json_object(&fields)
+  }
+}
+
+/* json_object - `fields` as a JSON object of already rendered values */
+pub fn json_object<K: fmt::Display>(fields: &[(K, String)]) -> String {
+  let members: Vec<String> = fields
+    .iter()
+    .map(|(key, value)| format!("\"{key}\":{value}"))
+    .collect();
+
+  format!("{{{}}}", members.join(","))
+}
+
+/* json_string - `text` as a quoted JSON string */
+pub fn json_string(text: &str) -> String {
+  let mut quoted = String::with_capacity(text.len() + 2);
+  quoted.push('"');
+  for c in text.chars() {
+    match c {
+      '"' => quoted.push_str("\\\""),
+      '\\' => quoted.push_str("\\\\"),
+      '\n' => quoted.push_str("\\n"),
+      '\r' => quoted.push_str("\\r"),
+      '\t' => quoted.push_str("\\t"),
+      c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+      c => quoted.push(c),
+    }
+  }
+  quoted.push('"');
+
+  quoted
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::error::ErrorKind;
+
+  fn timed(read: u64, extract: u64, transform: u64, load: u64) -> Sample {
+    Sample {
+      read: Some(Duration::from_nanos(read)),
+      extract: Some(Duration::from_nanos(extract)),
+      transform: Some(Duration::from_nanos(transform)),
+      load: Some(Duration::from_nanos(load)),
+      peak_heap: 0,
+    }
+  }
+
+  #[test]
+  fn it_should_render_a_run_as_one_json_object() {
+    let run = Run {
+      answer: Ok("4361".to_string()),
+      verdict: Verdict::Pass,
+      input: Some("day-3/sample.txt".to_string()),
+      samples: vec![Sample { peak_heap: 5012, ..timed(9, 90, 40, 2) }],
+    };
+
+    assert_eq!(
+      run.to_json(3, Part::One),
+      concat!(
+        r#"{"day":3,"part":1,"input":"day-3/sample.txt","answer":"4361","#,
+        r#""check":"pass","error":null,"runs":1,"peak_heap_bytes":5012,"#,
+        r#""read_ns":9,"extract_ns":90,"transform_ns":40,"load_ns":2,"#,
+        r#""read_stats_ns":{"min":9,"median":9,"max":9},"#,
+        r#""extract_stats_ns":{"min":90,"median":90,"max":90},"#,
+        r#""transform_stats_ns":{"min":40,"median":40,"max":40},"#,
+        r#""load_stats_ns":{"min":2,"median":2,"max":2}}"#
+      )
+    );
+  }
+
+  #[test]
+  fn it_should_escape_errors_and_leave_missing_phases_null() {
+    let run = Run {
+      answer: Err(Error::new(ErrorKind::Input, "no \"input.txt\"\n")),
+      verdict: Verdict::Unknown,
+      input: None,
+      samples: vec![Sample {
+        extract: Some(Duration::from_nanos(3)),
+        ..Sample::default()
+      }],
+    };
+
+    assert_eq!(
+      run.to_json(14, Part::Two),
+      concat!(
+        r#"{"day":14,"part":2,"input":null,"answer":null,"check":null,"#,
+        r#""error":"no \"input.txt\"\n","runs":1,"peak_heap_bytes":0,"#,
+        r#""read_ns":null,"extract_ns":3,"transform_ns":null,"load_ns":null,"#,
+        r#""read_stats_ns":null,"#,
+        r#""extract_stats_ns":{"min":3,"median":3,"max":3},"#,
+        r#""transform_stats_ns":null,"load_stats_ns":null}"#
+      )
+    );
+    assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+  }
+
+  #[test]
+  fn it_should_summarise_repeated_runs() {
+    let run = Run {
+      answer: Ok("42".to_string()),
+      verdict: Verdict::Unknown,
+      input: None,
+      samples: vec![
+        Sample { peak_heap: 10, ..timed(1, 30, 5, 1) },
+        Sample { peak_heap: 30, ..timed(2, 10, 5, 1) },
+        Sample { peak_heap: 20, ..timed(3, 20, 5, 1) },
+        Sample { peak_heap: 20, ..timed(4, 40, 5, 1) },
+      ],
+    };
+    let nanos = Duration::from_nanos;
+
+    assert_eq!(
+      run.stats(Stage::Extract),
+      Some(Stats { min: nanos(10), median: nanos(20), max: nanos(40) })
+    );
+    assert_eq!(run.peak_heap(), Some(30));
+    assert_eq!(run.stats(Stage::Transform).unwrap().to_string(), "5.00ns");
+    assert_eq!(
+      run.stats(Stage::Read).unwrap().to_string(),
+      "2.00ns (1.00ns..4.00ns)"
+    );
+    assert_eq!(Stats::of(&[]), None);
+  }
+}
@@ -0,0 +1,343 @@
+use std::num::{NonZeroU32, NonZeroUsize};
+
+use crate::input::{self, Source};
+use crate::parallel;
+use crate::params::{self, Selection};
+use crate::part::Part;
+use crate::render::Target;
+use crate::trace::{self, Filter};
+
+
+/* Options - the runtime switches every day binary understands:
+`[--part <1|2>] [--json | --profile] [--repeat <N>] [--trace <SPEC>]
+[--check] [--stream] [--workers <N>] [--params <FILE>] [--set <KEY=VALUE>]...
+[--render <- | FILE>] [--animate <FPS>] [--explain] [--input] [PATH | -]`,
+where `-` means stdin. Repeating a run only makes sense when it is measured, so
+`--repeat` alone implies `--profile`. `--workers` caps the threads a transform
+may use, one per core by default. `--params` reads the day's parameters from
+another file than its own `params.txt`, and each `--set` overrides one of them.
+Without `--trace` the filter comes from `AOC_TRACE`. `--check` verifies the
+input meets the day's assumptions before solving it, and `--stream` solves it a
+line at a time as it is read. A streamed run is not measured stage by stage, so
+it cannot be reported as JSON or a profile. `--render` draws the day's state
+before solving, on the terminal (`-`) or as a PPM image, and `--animate` draws
+every step at FPS frames a second. `--explain` shows how the day arrives at its
+answer instead of solving, as text or, with `--json`, as JSON. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+  pub input: Option<Source>,
+  pub part: Part,
+  pub format: Format,
+  pub runs: usize,
+  pub trace: Option<Filter>,
+  pub check: bool,
+  pub stream: bool,
+  pub workers: Option<NonZeroUsize>,
+  pub params: Selection,
+  pub render: Option<Target>,
+  pub animate: Option<NonZeroU32>,
+  pub explain: bool,
+}
+
+/* Format - how a run is reported: the day's own `load` text, one JSON object
+per run for scripts, or the time and heap each stage took */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+  #[default]
+  Text,
+  Json,
+  Profile,
+}
+
+impl Default for Options {
+  fn default() -> Self {
+    Options {
+      input: None,
+      part: Part::default(),
+      format: Format::default(),
+      runs: 1,
+      trace: None,
+      check: false,
+      stream: false,
+      workers: None,
+      params: Selection::default(),
+      render: None,
+      animate: None,
+      explain: false,
+    }
+  }
+}
+
+impl Options {
+  pub fn from_args<I>(args: I) -> Result<Options, String>
+  where
+    I: IntoIterator<Item = String>,
+  {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+      let mut value = |arg: &str| {
+        args
+          .next()
+          .ok_or(format!("[options] missing value for '{arg}'"))
+      };
+      match arg.as_str() {
+        "-p" | "--part" => options.part = value(&arg)?.parse()?,
+        "-i" | "--input" => options.set_input(&value(&arg)?)?,
+        "--json" => options.format = Format::Json,
+        "--profile" => options.format = Format::Profile,
+        "--repeat" => options.runs = parse_runs(&value(&arg)?)?,
+        "--trace" => options.trace = Some(value(&arg)?.parse()?),
+        "--check" => options.check = true,
+        "--stream" => options.stream = true,
+        "--workers" => options.workers = Some(parse_workers(&value(&arg)?)?),
+        "--params" => options.params.file = Some(value(&arg)?.into()),
+        "--set" => {
+          let spec = value(&arg)?;
+          options
+            .params
+            .overrides
+            .push(params::parse_override(&spec)?);
+        }
+        "--render" => {
+          options.render = Some(Target::from(value(&arg)?.as_str()))
+        }
+        "--animate" => options.animate = Some(parse_fps(&value(&arg)?)?),
+        "--explain" => options.explain = true,
+        flag if flag.starts_with("--") => {
+          return Err(format!("[options] unknown option '{flag}'"));
+        }
+        path => options.set_input(path)?,
+      }
+    }
+    if options.runs > 1 && options.format == Format::Text {
+      options.format = Format::Profile;
+    }
+    if options.stream && options.format != Format::Text {
+      return Err(
+        "[options] --stream cannot be combined with --json, --profile or \
+         --repeat"
+          .to_string(),
+      );
+    }
+    if options.animate.is_some() && options.render.is_none() {
+      return Err("[options] --animate needs --render".to_string());
+    }
+    if options.render.is_some()
+      && (options.stream || options.format != Format::Text)
+    {
+      return Err(
+        "[options] --render cannot be combined with --stream, --json, \
+         --profile or --repeat"
+          .to_string(),
+      );
+    }
+
+    if options.explain && (options.stream || options.format == Format::Profile)
+    {
+      return Err(
+        "[options] --explain cannot be combined with --stream, --profile or \
+         --repeat"
+          .to_string(),
+      );
+    }
+
+    Ok(options)
+  }
+
+  /* from_env - parses the process arguments, selects the requested input
+  for `input::read`, the worker count for `parallel::map` and the parameters
+  for `params::load`, and installs the trace filter. */
+  pub fn from_env() -> Result<Options, String> {
+    let options = Options::from_args(std::env::args().skip(1))?;
+    input::select(options.input.clone());
+    parallel::select(options.workers);
+    params::select(Some(options.params.clone()));
+    trace::install(options.trace.clone().map_or_else(Filter::from_env, Ok)?);
+
+    Ok(options)
+  }
+
+  fn set_input(&mut self, value: &str) -> Result<(), String> {
+    if self.input.is_some() {
+      return Err(format!("[options] more than one input given ('{value}')"));
+    }
+    self.input = Some(Source::from(value));
+
+    Ok(())
+  }
+}
+
+/* parse_runs - a repetition count, at least one */
+fn parse_runs(value: &str) -> Result<usize, String> {
+  match value.parse::<usize>() {
+    Ok(runs) if runs > 0 => Ok(runs),
+    _ => Err(format!("[options] invalid repeat count '{value}'")),
+  }
+}
+
+
+/* parse_workers - a thread count, at least one */
+fn parse_workers(value: &str) -> Result<NonZeroUsize, String> {
+  value
+    .parse()
+    .map_err(|_| format!("[options] invalid worker count '{value}'"))
+}
+
+/* parse_fps - an animation's frames a second, at least one */
+fn parse_fps(value: &str) -> Result<NonZeroU32, String> {
+  value
+    .parse()
+    .map_err(|_| format!("[options] invalid frame rate '{value}'"))
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::path::PathBuf;
+
+  fn parse(list: &[&str]) -> Result<Options, String> {
+    Options::from_args(list.iter().map(|s| s.to_string()))
+  }
+
+  #[test]
+  fn it_should_default_to_part_one_and_the_bundled_file() {
+    assert_eq!(
+      parse(&[]),
+      Ok(Options {
+        input: None,
+        part: Part::One,
+        format: Format::Text,
+        runs: 1,
+        trace: None,
+        check: false,
+        stream: false,
+        workers: None,
+        params: Selection::default(),
+        render: None,
+        animate: None,
+        explain: false,
+      })
+    );
+  }
+
+  #[test]
+  fn it_should_accept_a_positional_path_or_stdin() {
+    assert_eq!(
+      parse(&["other.txt"]).map(|o| o.input),
+      Ok(Some(Source::File(PathBuf::from("other.txt"))))
+    );
+    assert_eq!(parse(&["-"]).map(|o| o.input), Ok(Some(Source::Stdin)));
+    assert_eq!(
+      parse(&["--input", "x.txt"]).map(|o| o.input),
+      Ok(Some(Source::File(PathBuf::from("x.txt"))))
+    );
+  }
+
+  #[test]
+  fn it_should_select_the_part() {
+    assert_eq!(
+      parse(&["--part", "2", "x.txt"]),
+      Ok(Options {
+        input: Some(Source::File(PathBuf::from("x.txt"))),
+        part: Part::Two,
+        format: Format::Text,
+        runs: 1,
+        trace: None,
+        check: false,
+        stream: false,
+        workers: None,
+        params: Selection::default(),
+        render: None,
+        animate: None,
+        explain: false,
+      })
+    );
+    assert_eq!(parse(&["--json"]).map(|o| o.format), Ok(Format::Json));
+    assert_eq!(parse(&["--check"]).map(|o| o.check), Ok(true));
+    assert_eq!(parse(&["--stream"]).map(|o| o.stream), Ok(true));
+    assert!(parse(&["-p", "3"]).is_err());
+  }
+
+  #[test]
+  fn it_should_repeat_measured_runs() {
+    let repeat = |list| parse(list).map(|o| (o.format, o.runs));
+
+    assert_eq!(repeat(&["--repeat", "5"]), Ok((Format::Profile, 5)));
+    assert_eq!(repeat(&["--json", "--repeat", "3"]), Ok((Format::Json, 3)));
+    assert_eq!(repeat(&["--profile"]), Ok((Format::Profile, 1)));
+    assert!(parse(&["--repeat", "0"]).is_err());
+    assert!(parse(&["--repeat", "x"]).is_err());
+    assert!(parse(&["--stream", "--repeat", "2"]).is_err());
+  }
+
+  #[test]
+  fn it_should_take_a_worker_count() {
+    assert_eq!(
+      parse(&["--workers", "3"]).map(|o| o.workers),
+      Ok(NonZeroUsize::new(3))
+    );
+    assert!(parse(&["--workers", "0"]).is_err());
+    assert!(parse(&["--workers", "x"]).is_err());
+  }
+
+  #[test]
+  fn it_should_take_a_parameter_file_and_overrides() {
+    assert_eq!(
+      parse(&["--params", "p.txt", "--set", "steps=6", "--set", "a = b"])
+        .map(|o| o.params),
+      Ok(Selection {
+        file: Some(PathBuf::from("p.txt")),
+        overrides: vec![
+          ("steps".to_string(), "6".to_string()),
+          ("a".to_string(), "b".to_string())
+        ],
+      })
+    );
+    assert!(parse(&["--set", "steps"]).is_err());
+  }
+
+  #[test]
+  fn it_should_render_to_the_terminal_or_an_image() {
+    let parsed = parse(&["--render", "-", "--animate", "30"]).unwrap();
+    assert_eq!(
+      (parsed.render, parsed.animate),
+      (Some(Target::Terminal), NonZeroU32::new(30))
+    );
+    assert_eq!(
+      parse(&["--render", "out.ppm"]).map(|o| o.render),
+      Ok(Some(Target::Image(PathBuf::from("out.ppm"))))
+    );
+    assert!(parse(&["--animate", "30"]).is_err());
+    assert!(parse(&["--render", "-", "--animate", "0"]).is_err());
+    assert!(parse(&["--render", "-", "--stream"]).is_err());
+    assert!(parse(&["--render", "-", "--json"]).is_err());
+  }
+
+  #[test]
+  fn it_should_take_a_trace_filter() {
+    assert_eq!(
+      parse(&["--trace", "day-5=trace"]).map(|o| o.trace),
+      Ok(Some("day_5=trace".parse().unwrap()))
+    );
+    assert!(parse(&["--trace", "day-5=loud"]).is_err());
+  }
+
+  #[test]
+  fn it_should_reject_ambiguous_arguments() {
+    assert!(parse(&["a.txt", "b.txt"]).is_err());
+    assert!(parse(&["--input"]).is_err());
+    assert!(parse(&["--bogus"]).is_err());
+  }
+
+  #[test]
+  fn it_should_explain_as_text_or_json() {
+    let explain = |list| parse(list).map(|o| (o.explain, o.format));
+
+    assert_eq!(explain(&["--explain"]), Ok((true, Format::Text)));
+    assert_eq!(explain(&["--explain", "--json"]), Ok((true, Format::Json)));
+    assert!(parse(&["--explain", "--stream"]).is_err());
+    assert!(parse(&["--explain", "--repeat", "2"]).is_err());
+  }
+}
@@ -0,0 +1,88 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+
+// no count chosen: one worker per core
+static WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+/* select - how many threads `map` spreads its items over; `None` restores one
+per core */
+pub fn select(workers: Option<NonZeroUsize>) {
+  WORKERS.store(workers.map_or(0, NonZeroUsize::get), Ordering::Relaxed);
+}
+
+/* workers - the number of threads `map` uses */
+pub fn workers() -> usize {
+  match WORKERS.load(Ordering::Relaxed) {
+    0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+    workers => workers,
+  }
+}
+
+/* map - `f` applied to every item on up to `workers()` scoped threads. Each
+thread takes the next item not yet taken, so a slow item holds up only its
+own thread, and every result is put back at its item's index: the output is
+the sequential map's, whatever the number of workers or the order they
+finish in. With a single worker, or a single item, no thread is spawned. */
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+  T: Sync,
+  R: Send,
+  F: Fn(&T) -> R + Sync,
+{
+  let workers = workers().min(items.len());
+  if workers <= 1 {
+    return items.iter().map(f).collect();
+  }
+
+  let next = AtomicUsize::new(0);
+  let work = || {
+    let mut done = Vec::new();
+    loop {
+      let i = next.fetch_add(1, Ordering::Relaxed);
+      let Some(item) = items.get(i) else {
+        return done;
+      };
+      done.push((i, f(item)));
+    }
+  };
+
+  let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+  thread::scope(|scope| {
+    let threads: Vec<_> = (0..workers).map(|_| scope.spawn(work)).collect();
+    for thread in threads {
+      // a panicking item panics the caller, as it would sequentially
+      let done = thread
+        .join()
+        .unwrap_or_else(|e| std::panic::resume_unwind(e));
+      for (i, result) in done {
+        results[i] = Some(result);
+      }
+    }
+  });
+
+  results.into_iter().flatten().collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_keep_the_sequential_order() {
+    let items: Vec<u64> = (0..1000).collect();
+    // uneven work, so threads finish out of order
+    let slow = |n: &u64| (0..n % 97 * 50).fold(*n, |acc, k| acc ^ (k << 3));
+    let sequential: Vec<u64> = items.iter().map(slow).collect();
+
+    for workers in [1, 2, 3, 8] {
+      select(NonZeroUsize::new(workers));
+      assert_eq!(map(&items, slow), sequential, "{workers} workers");
+    }
+    select(None);
+    assert!(workers() >= 1);
+    assert_eq!(map(&[] as &[u64], slow), Vec::<u64>::new());
+  }
+}
@@ -0,0 +1,257 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use crate::error::{Error, ErrorKind, Phase};
+
+
+/* Selection - where a day's parameters come from at runtime: a file read
+instead of the day's own `params.txt`, and `--set` overrides applied on top
+of whichever file was read */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+  pub file: Option<PathBuf>,
+  pub overrides: Vec<(String, String)>,
+}
+
+/* Params - the values a day was given for its parameters, by key, each with
+where it was given. A day asks for a key with the default it was written
+with, which is what it gets when nothing set the key. */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+  values: BTreeMap<String, (String, String)>,
+}
+
+static SELECTED: Mutex<Option<Selection>> = Mutex::new(None);
+
+thread_local! {
+  // a selection made for this thread alone, ahead of the process's
+  static SCOPED: RefCell<Option<Selection>> = const { RefCell::new(None) };
+}
+
+/* select - chooses the parameters for subsequent `load` calls; `None`
+restores each day's own file */
+pub fn select(selection: Option<Selection>) {
+  *SELECTED.lock().unwrap_or_else(|e| e.into_inner()) = selection;
+}
+
+/* selected - the parameters chosen for this thread by `scoped`, or else for
+the process by `select` */
+pub fn selected() -> Option<Selection> {
+  SCOPED
+    .with(|scoped| scoped.borrow().clone())
+    .or_else(|| SELECTED.lock().unwrap_or_else(|e| e.into_inner()).clone())
+}
+
+/* scoped - runs `f` with `selection` as the parameters `load` reads on the
+current thread only, so tests solving with their own parameters do not
+change those of the tests running beside them. The previous selection is
+restored however `f` returns. */
+pub fn scoped<T>(selection: Selection, f: impl FnOnce() -> T) -> T {
+  struct Restore(Option<Selection>);
+
+  impl Drop for Restore {
+    fn drop(&mut self) {
+      SCOPED.with(|scoped| *scoped.borrow_mut() = self.0.take());
+    }
+  }
+
+  let _restore =
+    Restore(SCOPED.with(|scoped| scoped.borrow_mut().replace(selection)));
+
+  f()
+}
+
+/* load - the parameters in the selected file, or in `bundled` (the day's own,
+which need not exist) when none was selected, with any overrides on top.
+Keys the day does not take are refused, so a misspelt one cannot quietly
+leave its default in place. */
+pub fn load(bundled: &str, known: &[&str]) -> Result<Params, Error> {
+  let selection = selected().unwrap_or_default();
+  let path = selection.file.unwrap_or_else(|| PathBuf::from(bundled));
+  let mut params = match path.exists() {
+    true => Params::parse(&read_file(&path)?, &path.display().to_string())?,
+    false if path != Path::new(bundled) => {
+      return Err(params_error(format!(
+        "the parameter file '{}' does not exist",
+        path.display()
+      )));
+    }
+    false => Params::default(),
+  };
+  for (key, value) in selection.overrides {
+    params.values.insert(key, (value, "--set".to_string()));
+  }
+  params.only(known)?;
+
+  Ok(params)
+}
+
+/* parse_override - a `--set` argument, `<key>=<value>` */
+pub fn parse_override(spec: &str) -> Result<(String, String), String> {
+  match spec.split_once('=') {
+    Some((key, value)) if !key.trim().is_empty() => {
+      Ok((key.trim().to_string(), value.trim().to_string()))
+    }
+    _ => Err(format!("[params] expected <key>=<value>, found '{spec}'")),
+  }
+}
+
+impl Params {
+  /* parse - a parameter file, named `origin` in errors: one
+  `<key> = <value>` per line, skipping blank lines and `#` comments */
+  pub fn parse(text: &str, origin: &str) -> Result<Params, Error> {
+    let mut values = BTreeMap::new();
+    for (i, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+      let place = format!("{origin} line {}", i + 1);
+      let Some((key, value)) = line.split_once('=') else {
+        return Err(params_error(format!(
+          "{place}: expected '<key> = <value>'"
+        )));
+      };
+      let (key, value) = (key.trim(), value.trim());
+      if values.contains_key(key) {
+        return Err(params_error(format!("{place}: '{key}' is set twice")));
+      }
+      values.insert(key.to_string(), (value.to_string(), place));
+    }
+
+    Ok(Params { values })
+  }
+
+  /* get - the value given for `key`, or `default` */
+  pub fn get<T>(&self, key: &str, default: T) -> Result<T, Error>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    self.get_with(key, default, |value| {
+      value
+        .parse()
+        .map_err(|e: T::Err| Error::from(e.to_string()))
+    })
+  }
+
+  /* get_with - like `get`, for values `parse` reads */
+  pub fn get_with<T>(
+    &self,
+    key: &str,
+    default: T,
+    parse: impl Fn(&str) -> Result<T, Error>,
+  ) -> Result<T, Error> {
+    let Some((value, origin)) = self.values.get(key) else {
+      return Ok(default);
+    };
+
+    parse(value).map_err(|e| {
+      let message =
+        format!("invalid {key} '{value}' ({origin}): {}", e.message);
+      Error { phase: Some(Phase::Params), ..Error::value(message) }
+    })
+  }
+
+  fn only(&self, known: &[&str]) -> Result<(), Error> {
+    match self
+      .values
+      .iter()
+      .find(|(key, _)| !known.contains(&key.as_str()))
+    {
+      Some((key, (_, origin))) => Err(params_error(format!(
+        "unknown parameter '{key}' ({origin}); expected one of: {}",
+        known.join(", ")
+      ))),
+      None => Ok(()),
+    }
+  }
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+  std::fs::read_to_string(path).map_err(|e| {
+    params_error(format!("could not read '{}': {e}", path.display()))
+  })
+}
+
+/* params_error - a mistake in the parameters given rather than in the input,
+so it carries a phase of its own, which `during` keeps whichever phase of the
+pipeline read the parameters */
+fn params_error(message: String) -> Error {
+  Error {
+    phase: Some(Phase::Params),
+    ..Error::new(ErrorKind::Usage, message)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const FILE: &str = "# a variant\nsteps = 10\n\nname = seed\n";
+
+  #[test]
+  fn it_should_read_values_and_fall_back_to_defaults() {
+    let params = Params::parse(FILE, "params.txt").unwrap();
+
+    assert_eq!(params.get("steps", 64), Ok(10));
+    assert_eq!(params.get("name", "x".to_string()), Ok("seed".to_string()));
+    assert_eq!(params.get("other", 3), Ok(3));
+    assert_eq!(params.only(&["steps", "name"]), Ok(()));
+    assert!(params.only(&["steps"]).is_err());
+  }
+
+  #[test]
+  fn it_should_say_where_a_bad_value_was_given() {
+    let params = Params::parse("\nsteps = ten", "params.txt").unwrap();
+    let error = params.get("steps", 64).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(
+      error.message,
+      "invalid steps 'ten' (params.txt line 2): invalid digit found in string"
+    );
+    let error = params
+      .only(&["seed"])
+      .unwrap_err()
+      .during(Phase::Transform, 11);
+    assert_eq!(error.kind, ErrorKind::Usage);
+    assert!(
+      error.to_string().starts_with("[day 11 params] unknown"),
+      "{error}"
+    );
+    assert!(Params::parse("steps 10", "params.txt").is_err());
+    assert!(Params::parse("steps = 1\nsteps = 2", "params.txt").is_err());
+  }
+
+  #[test]
+  fn it_should_scope_a_selection_to_its_thread() {
+    let selection = Selection {
+      overrides: vec![("steps".to_string(), "6".to_string())],
+      ..Selection::default()
+    };
+
+    let (inside, beside) = scoped(selection.clone(), || {
+      let beside = std::thread::spawn(selected).join().unwrap();
+      (selected(), beside)
+    });
+    assert_eq!(inside, Some(selection));
+    assert_eq!(beside, None);
+    assert_eq!(selected(), None);
+  }
+
+  #[test]
+  fn it_should_parse_overrides() {
+    assert_eq!(
+      parse_override("steps = 10"),
+      Ok(("steps".to_string(), "10".to_string()))
+    );
+    assert!(parse_override("steps").is_err());
+    assert!(parse_override("=10").is_err());
+  }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+use std::str::FromStr;
+
+
+/* Part - which half of a day's puzzle to solve, chosen at runtime. */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+  #[default]
+  One,
+  Two,
+}
+
+impl Part {
+  pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim() {
+      "1" | "one" => Ok(Part::One),
+      "2" | "two" => Ok(Part::Two),
+      _ => Err(format!("[part] invalid part '{s}' (expected 1 or 2)")),
+    }
+  }
+}
+
+impl fmt::Display for Part {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Part::One => write!(f, "1"),
+      Part::Two => write!(f, "2"),
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_parse_and_display_parts() {
+    assert_eq!("1".parse::<Part>(), Ok(Part::One));
+    assert_eq!("two".parse::<Part>(), Ok(Part::Two));
+    assert!("3".parse::<Part>().is_err());
+    assert_eq!(Part::Two.to_string(), "2");
+  }
+}
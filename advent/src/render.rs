@@ -0,0 +1,317 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::{Error, ErrorKind};
+
+
+// the side an image's longer edge is scaled up towards, in pixels
+const IMAGE_SIDE: usize = 800;
+// ... without any cell growing larger than this
+const MAX_CELL_SIDE: usize = 16;
+
+/* Rgb - a colour, as both outputs take it: a 24-bit ANSI escape on the
+terminal, a pixel in an image */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+  pub const BLACK: Rgb = Rgb(0, 0, 0);
+  pub const WHITE: Rgb = Rgb(238, 238, 238);
+  pub const GREY: Rgb = Rgb(110, 110, 110);
+  pub const DARK: Rgb = Rgb(48, 48, 48);
+  pub const RED: Rgb = Rgb(220, 50, 47);
+  pub const ORANGE: Rgb = Rgb(238, 130, 40);
+  pub const YELLOW: Rgb = Rgb(240, 200, 50);
+  pub const GREEN: Rgb = Rgb(100, 190, 60);
+  pub const CYAN: Rgb = Rgb(42, 180, 200);
+  pub const BLUE: Rgb = Rgb(50, 110, 220);
+  pub const MAGENTA: Rgb = Rgb(200, 60, 160);
+}
+
+/* Cell - one grid position as drawn: its character on the terminal, a square
+of its colour in an image */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+  pub glyph: char,
+  pub colour: Rgb,
+}
+
+impl Cell {
+  pub const fn new(glyph: char, colour: Rgb) -> Cell {
+    Cell { glyph, colour }
+  }
+}
+
+/* Frame - a picture of one grid state, row by row from the top left */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+  width: usize,
+  height: usize,
+  cells: Vec<Cell>,
+}
+
+impl Frame {
+  pub fn new(width: usize, height: usize, fill: Cell) -> Frame {
+    Frame { width, height, cells: vec![fill; width * height] }
+  }
+
+  /* from_fn - a frame with `paint(x, y)` at every position */
+  pub fn from_fn<F>(width: usize, height: usize, mut paint: F) -> Frame
+  where
+    F: FnMut(usize, usize) -> Cell,
+  {
+    let cells = (0..height)
+      .flat_map(|y| (0..width).map(move |x| (x, y)))
+      .map(|(x, y)| paint(x, y))
+      .collect();
+
+    Frame { width, height, cells }
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn get(&self, x: usize, y: usize) -> Option<&Cell> {
+    (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+  }
+
+  /* set - paints one position; positions off the frame are left out */
+  pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+    if x < self.width && y < self.height {
+      self.cells[y * self.width + x] = cell;
+    }
+  }
+
+  /* ansi - the frame as terminal text, a colour escape only where the colour
+  changes and a reset at the end of every row */
+  pub fn ansi(&self) -> String {
+    let mut text = String::new();
+    for row in self.cells.chunks(self.width.max(1)) {
+      let mut colour = None;
+      for cell in row {
+        if colour != Some(cell.colour) {
+          let Rgb(r, g, b) = cell.colour;
+          let _ = write!(text, "\x1b[38;2;{r};{g};{b}m");
+          colour = Some(cell.colour);
+        }
+        text.push(cell.glyph);
+      }
+      text.push_str("\x1b[0m\n");
+    }
+
+    text
+  }
+
+  /* ppm - the frame as a binary PPM image, each cell a `scale`-pixel
+  square */
+  pub fn ppm(&self, scale: usize) -> Vec<u8> {
+    let (width, height) = (self.width * scale, self.height * scale);
+    let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+    image.reserve(width * height * 3);
+    for row in self.cells.chunks(self.width.max(1)) {
+      let pixels: Vec<u8> = row
+        .iter()
+        .flat_map(|cell| {
+          let Rgb(r, g, b) = cell.colour;
+          [r, g, b].repeat(scale)
+        })
+        .collect();
+      for _ in 0..scale {
+        image.extend_from_slice(&pixels);
+      }
+    }
+
+    image
+  }
+
+  /* scale - how many pixels a side each cell gets in an image: enough for
+  the longer edge to reach `IMAGE_SIDE`, within `1..=MAX_CELL_SIDE` */
+  fn scale(&self) -> usize {
+    (IMAGE_SIDE / self.width.max(self.height).max(1)).clamp(1, MAX_CELL_SIDE)
+  }
+}
+
+/* Target - where frames are drawn: the terminal (`-`), an image file, or
+memory for callers that look at the frames themselves */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+  Terminal,
+  Image(PathBuf),
+  Memory,
+}
+
+impl From<&str> for Target {
+  fn from(value: &str) -> Self {
+    match value {
+      "-" => Target::Terminal,
+      path => Target::Image(PathBuf::from(path)),
+    }
+  }
+}
+
+/* Renderer - what a day draws into. Unless animating only the last frame it
+is given is drawn, once the day is done; animating, every frame is drawn as
+it comes, paced on the terminal and numbered in files (`lagoon.ppm` becomes
+`lagoon-0001.ppm`, `lagoon-0002.ppm`, ...). */
+#[derive(Debug)]
+pub struct Renderer {
+  target: Target,
+  fps: Option<NonZeroU32>,
+  drawn: usize,
+  kept: Vec<Frame>,
+}
+
+impl Renderer {
+  /* new - a renderer for `target`, animating at `fps` frames a second if
+  given */
+  pub fn new(target: Target, fps: Option<NonZeroU32>) -> Renderer {
+    Renderer { target, fps, drawn: 0, kept: Vec::new() }
+  }
+
+  /* animating - whether every step is wanted, or just the final state; days
+  only build the frames on the way when it is */
+  pub fn animating(&self) -> bool {
+    self.fps.is_some()
+  }
+
+  /* frame - the state after the latest step */
+  pub fn frame(&mut self, frame: Frame) -> Result<(), Error> {
+    if !self.animating() {
+      self.kept = vec![frame];
+      return Ok(());
+    }
+    self.draw(&frame)?;
+    if self.target == Target::Memory {
+      self.kept.push(frame);
+    }
+
+    Ok(())
+  }
+
+  /* finish - draws the final frame if it was not drawn as it came; a day
+  that gave no frame at all has drawn nothing */
+  pub fn finish(&mut self) -> Result<(), Error> {
+    if !self.animating() {
+      if let Some(frame) = self.kept.last().cloned() {
+        self.draw(&frame)?;
+      }
+    }
+    match self.drawn {
+      0 => Err(Error::missing("nothing was drawn")),
+      _ => Ok(()),
+    }
+  }
+
+  /* frames - every frame kept in memory */
+  pub fn frames(&self) -> &[Frame] {
+    &self.kept
+  }
+
+  fn draw(&mut self, frame: &Frame) -> Result<(), Error> {
+    self.drawn += 1;
+    match &self.target {
+      Target::Terminal => {
+        let mut stdout = io::stdout().lock();
+        if let Some(fps) = self.fps {
+          // home the cursor and clear the screen, so frames play in place
+          write!(stdout, "\x1b[H\x1b[2J").map_err(output_error)?;
+          write!(stdout, "{}", frame.ansi()).map_err(output_error)?;
+          stdout.flush().map_err(output_error)?;
+          thread::sleep(Duration::from_secs(1) / fps.get());
+        } else {
+          write!(stdout, "{}", frame.ansi()).map_err(output_error)?;
+        }
+      }
+      Target::Image(path) => {
+        let path = match self.fps {
+          Some(_) => numbered(path, self.drawn),
+          None => path.clone(),
+        };
+        std::fs::write(&path, frame.ppm(frame.scale())).map_err(|e| {
+          Error::new(
+            ErrorKind::Other,
+            format!("could not write '{}': {e}", path.display()),
+          )
+        })?;
+      }
+      Target::Memory => {}
+    }
+
+    Ok(())
+  }
+}
+
+/* numbered - `path` with `-NNNN` before its extension */
+fn numbered(path: &Path, n: usize) -> PathBuf {
+  let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+  let name = match path.extension() {
+    Some(extension) => {
+      format!("{stem}-{n:04}.{}", extension.to_string_lossy())
+    }
+    None => format!("{stem}-{n:04}"),
+  };
+
+  path.with_file_name(name)
+}
+
+fn output_error(e: io::Error) -> Error {
+  Error::new(ErrorKind::Other, format!("could not draw: {e}"))
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ROCK: Cell = Cell::new('#', Rgb::GREY);
+  const PLOT: Cell = Cell::new('.', Rgb::GREEN);
+
+  #[test]
+  fn it_should_draw_ansi_and_ppm() {
+    let frame = Frame::from_fn(2, 2, |x, y| if x == y { ROCK } else { PLOT });
+
+    assert_eq!(
+      frame.ansi(),
+      "\x1b[38;2;110;110;110m#\x1b[38;2;100;190;60m.\x1b[0m\n\x1b[38;2;100;\
+       190;60m.\x1b[38;2;110;110;110m#\x1b[0m\n"
+    );
+    let image = frame.ppm(2);
+    let header = b"P6\n4 4\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(image.len(), header.len() + 4 * 4 * 3);
+    // the second pixel row repeats the first, a rock then a plot
+    let pixels = &image[header.len()..];
+    assert_eq!(&pixels[..12], &pixels[12..24]);
+    assert_eq!(&pixels[..6], &[110, 110, 110, 110, 110, 110]);
+    assert_eq!(&pixels[6..9], &[100, 190, 60]);
+  }
+
+  #[test]
+  fn it_should_keep_the_last_frame_unless_animating() {
+    let mut still = Renderer::new(Target::Memory, None);
+    assert!(still.finish().is_err());
+    still.frame(Frame::new(1, 1, ROCK)).unwrap();
+    still.frame(Frame::new(1, 1, PLOT)).unwrap();
+    assert_eq!(still.finish(), Ok(()));
+    assert_eq!(still.frames(), [Frame::new(1, 1, PLOT)]);
+
+    let mut animated = Renderer::new(Target::Memory, NonZeroU32::new(1000));
+    animated.frame(Frame::new(1, 1, ROCK)).unwrap();
+    animated.frame(Frame::new(1, 1, PLOT)).unwrap();
+    assert_eq!(animated.finish(), Ok(()));
+    assert_eq!(animated.frames().len(), 2);
+    assert_eq!(
+      numbered(Path::new("out/lagoon.ppm"), 2),
+      PathBuf::from("out/lagoon-0002.ppm")
+    );
+  }
+}
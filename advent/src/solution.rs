@@ -0,0 +1,326 @@
+use std::process::ExitCode;
+
+use crate::answers;
+use crate::check::{self, Check};
+use crate::error::{Error, ErrorKind, Phase};
+use crate::input;
+use crate::measure::measure;
+use crate::options::{Format, Options};
+use crate::part::Part;
+use crate::render::Renderer;
+
+
+/* Solution - a day's puzzle as the extract → transform → load pipeline every
+day follows. `extract` reads whatever `input::read` selects, so a generic
+driver only has to pick the input and the part. */
+pub trait Solution {
+  type ProblemDefinition;
+  type Consequent;
+
+  const DAY: u8;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error>;
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error>;
+
+  /* load - shows the consequent, or the error it failed with, to the user.
+  The error is still `run`'s to return, so a day only returns the errors of
+  showing it. */
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    part: Part,
+  ) -> Result<(), Error>;
+
+  /* answer - the value the consequent reduces to, as it would be submitted */
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error>;
+
+  /* check - every structural property of the input `transform` relies on for
+  `part` without verifying it, such as a square map or a single loop. Days
+  whose `extract` already rejects anything they cannot solve keep the
+  default. */
+  fn check(_data: &Self::ProblemDefinition, _part: Part) -> Vec<Check> {
+    Vec::new()
+  }
+
+  /* stream - the answer to `part`, as `answer` would give it, folded from
+  `input::lines` a line at a time rather than extracted whole, so inputs of
+  any size fit in memory. Only days whose lines stand alone can; the rest keep
+  the default, which refuses. */
+  fn stream(_part: Part) -> Result<String, Error> {
+    Err(Error::new(
+      ErrorKind::Unsupported,
+      format!("day {} cannot stream its input", Self::DAY),
+    ))
+  }
+
+  /* render - draws the state `transform` arrives at for `part`, and when
+  the renderer is animating every step on the way there. Drawing is kept out of
+  `transform`, so a day that draws works its way to what it shows on its own;
+  days without a picture to give keep the default, which refuses. */
+  fn render(
+    _data: &Self::ProblemDefinition,
+    _part: Part,
+    _renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    Err(Error::new(
+      ErrorKind::Unsupported,
+      format!("day {} has nothing to draw", Self::DAY),
+    ))
+  }
+
+  /* explain - how `transform` arrives at its answer for `part`, step by
+  step, in `format`: text, or JSON for scripts. A report is meant to be
+  compared between parts or inputs, so each step takes a line of its own. Days
+  with nothing to show keep the default, which refuses. */
+  fn explain(
+    _data: &Self::ProblemDefinition,
+    _part: Part,
+    _format: Format,
+  ) -> Result<String, Error> {
+    Err(Error::new(
+      ErrorKind::Unsupported,
+      format!("day {} has nothing to explain", Self::DAY),
+    ))
+  }
+
+  /* lint - extract, then check the input against every assumption */
+  fn lint(part: Part) -> Result<Vec<Check>, Error> {
+    let data =
+      Self::extract(part).map_err(|e| e.during(Phase::Extract, Self::DAY))?;
+
+    Ok(Self::check(&data, part))
+  }
+
+  /* draw - extract, then render into `renderer` and finish the drawing */
+  fn draw(part: Part, renderer: &mut Renderer) -> Result<(), Error> {
+    let data =
+      Self::extract(part).map_err(|e| e.during(Phase::Extract, Self::DAY))?;
+
+    Self::render(&data, part, renderer)
+      .and_then(|()| renderer.finish())
+      .map_err(|e| e.during(Phase::Render, Self::DAY))
+  }
+
+  /* describe - extract, then explain */
+  fn describe(part: Part, format: Format) -> Result<String, Error> {
+    let data =
+      Self::extract(part).map_err(|e| e.during(Phase::Extract, Self::DAY))?;
+
+    Self::explain(&data, part, format)
+      .map_err(|e| e.during(Phase::Explain, Self::DAY))
+  }
+
+  /* solve - extract and transform, leaving the consequent to the caller.
+  Errors come back tagged with the day and the phase they surfaced in. */
+  fn solve(part: Part) -> Result<Self::Consequent, Error> {
+    let data =
+      Self::extract(part).map_err(|e| e.during(Phase::Extract, Self::DAY))?;
+
+    Self::transform(data, part)
+      .map_err(|e| e.during(Phase::Transform, Self::DAY))
+  }
+
+  /* run - the whole pipeline, as a day's `main` would, then the answer
+  checked against the one recorded for the input. A differing answer is an
+  error; a matching or unrecorded one is noted on stderr. A failed solve is
+  returned once `load` has shown it, tagged with the phase it failed in. */
+  fn run(part: Part) -> Result<(), Error> {
+    let consequent = Self::solve(part);
+    let failure = consequent.as_ref().err().cloned();
+    let answer = consequent.as_ref().ok().map(Self::answer).transpose();
+    Self::load(consequent, part)
+      .and_then(|()| match answer? {
+        Some(answer) => verify(&answer, part),
+        None => Ok(()),
+      })
+      .map_err(|e| e.during(Phase::Load, Self::DAY))?;
+
+    failure.map_or(Ok(()), Err)
+  }
+}
+
+fn verify(answer: &str, part: Part) -> Result<(), Error> {
+  let verdict = answers::check(answer, part)?;
+  verdict.confirm(answer)?;
+  eprintln!("check: {verdict}");
+
+  Ok(())
+}
+
+/* main - a day binary: parse the command line, run the pipeline and render
+any error that escapes it. With `--json` or `--profile` the day's `load` is
+skipped and the measured run is printed instead. With `--check` the input is
+first checked against the day's assumptions, and only solved if it meets all
+of them. With `--stream` the answer is folded from the input as it is read,
+and printed on its own. With `--render` the day's state is drawn before it
+is solved. With `--explain` the day's report is printed instead of solving.
+Either way a run that fails exits with a failure. */
+pub fn main<S: Solution>() -> ExitCode {
+  // `None` is an error the day's `load` has already shown
+  let result: Result<(), Option<Error>> = Options::from_env()
+    .map_err(|e| Some(Error::new(ErrorKind::Usage, e)))
+    .and_then(|options| {
+      let part = options.part;
+      // stdin can only be read once, and checking or drawing, like each
+      // repetition, reads the input again
+      let rereads = options.check || options.render.is_some();
+      if rereads || options.format != Format::Text {
+        input::select(options.input.map(|s| s.buffered()).transpose()?);
+      }
+      if options.check {
+        let checks = S::lint(part)?;
+        eprint!("{}", check::report(&checks));
+        check::confirm(&checks).map_err(|e| e.during(Phase::Check, S::DAY))?;
+      }
+      if let Some(target) = options.render {
+        S::draw(part, &mut Renderer::new(target, options.animate))?;
+      }
+      if options.explain {
+        print!("{}", S::describe(part, options.format)?);
+        return Ok(());
+      }
+      if options.stream {
+        let answer =
+          S::stream(part).map_err(|e| e.during(Phase::Stream, S::DAY))?;
+        println!("{answer}");
+        verify(&answer, part).map_err(|e| e.during(Phase::Load, S::DAY))?;
+        return Ok(());
+      }
+      if options.format == Format::Text {
+        return S::run(part).map_err(|e| match e.phase {
+          Some(Phase::Load) => Some(e),
+          _ => None,
+        });
+      }
+
+      let run = measure::<S>(part, options.runs);
+      match options.format {
+        Format::Json => println!("{}", run.to_json(S::DAY, part)),
+        _ => print!("{}", run.profile(S::DAY, part)),
+      }
+
+      Ok(run.outcome(S::DAY)?)
+    });
+
+  match result {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) => {
+      if let Some(e) = e {
+        eprintln!("{e}");
+      }
+      ExitCode::FAILURE
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::render::Target;
+
+  struct Doubler;
+
+  impl Solution for Doubler {
+    type ProblemDefinition = usize;
+    type Consequent = usize;
+
+    const DAY: u8 = 0;
+
+    fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+      match part {
+        Part::One => Ok(21),
+        Part::Two => Err(Error::missing("no data")),
+      }
+    }
+
+    fn transform(
+      data: Self::ProblemDefinition,
+      _part: Part,
+    ) -> Result<Self::Consequent, Error> {
+      Ok(data * 2)
+    }
+
+    fn load(
+      _result: Result<Self::Consequent, Error>,
+      _part: Part,
+    ) -> Result<(), Error> {
+      Ok(())
+    }
+
+    fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+      Ok(consequent.to_string())
+    }
+
+    fn check(data: &Self::ProblemDefinition, _part: Part) -> Vec<Check> {
+      vec![Check::that("the data is even", data % 2 == 0, || {
+        format!("{data} is odd")
+      })]
+    }
+  }
+
+  #[test]
+  fn it_should_chain_extract_and_transform() {
+    assert_eq!(Doubler::solve(Part::One), Ok(42));
+    assert_eq!(Doubler::run(Part::One), Ok(()));
+    assert_eq!(Doubler::answer(&42), Ok("42".to_string()));
+  }
+
+  #[test]
+  fn it_should_return_a_failed_solve_once_loaded() {
+    let error = Doubler::run(Part::Two).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Missing);
+    assert_eq!(error.phase, Some(Phase::Extract));
+  }
+
+  #[test]
+  fn it_should_check_the_extracted_data() {
+    let checks = Doubler::lint(Part::One).unwrap();
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].violation, Some("21 is odd".to_string()));
+    assert_eq!(
+      Doubler::lint(Part::Two).unwrap_err().kind,
+      ErrorKind::Missing
+    );
+  }
+
+  #[test]
+  fn it_should_refuse_to_stream_by_default() {
+    let error = Doubler::stream(Part::One).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Unsupported);
+    assert_eq!(error.message, "day 0 cannot stream its input");
+  }
+
+  #[test]
+  fn it_should_refuse_to_draw_by_default() {
+    let mut renderer = Renderer::new(Target::Memory, None);
+    let error = Doubler::draw(Part::One, &mut renderer).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Unsupported);
+    assert_eq!(error.phase, Some(Phase::Render));
+    assert_eq!(error.message, "day 0 has nothing to draw");
+  }
+
+  #[test]
+  fn it_should_refuse_to_explain_by_default() {
+    let error = Doubler::describe(Part::One, Format::Json).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Unsupported);
+    assert_eq!(error.phase, Some(Phase::Explain));
+  }
+
+  #[test]
+  fn it_should_tag_errors_with_the_day_and_phase() {
+    let error = Doubler::solve(Part::Two).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Missing);
+    assert_eq!(error.phase, Some(Phase::Extract));
+    assert_eq!(error.day, Some(0));
+  }
+}
@@ -0,0 +1,293 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
+use std::ops::{Neg, Sub, SubAssign};
+
+use num::BigInt;
+
+
+/* Total - an answer worked out with checked arithmetic. It is kept in an
+`i64` while it fits, moves to an `i128` when an operation would overflow
+that, and past 128 bits to an integer as wide as it takes, so an input large
+enough to overflow gets the right answer rather than a wrapped one. The value
+is always held in the narrowest width it fits, so equal totals compare, hash
+and print alike however they were reached. */
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Total(Width);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Width {
+  Narrow(i64),
+  Wide(i128),
+  Big(BigInt),
+}
+
+impl Total {
+  pub const ZERO: Total = Total(Width::Narrow(0));
+  pub const ONE: Total = Total(Width::Narrow(1));
+
+  /* bits - the width the value is held in: 64, 128, or past that the bits
+  it takes */
+  pub fn bits(&self) -> u64 {
+    match &self.0 {
+      Width::Narrow(_) => 64,
+      Width::Wide(_) => 128,
+      Width::Big(n) => n.bits() + 1,
+    }
+  }
+
+  pub fn pow(self, exponent: u32) -> Total {
+    (0..exponent).fold(Total::ONE, |power, _| power * self.clone())
+  }
+
+  /* to_i128 - the value, if it fits */
+  pub fn to_i128(&self) -> Option<i128> {
+    match &self.0 {
+      Width::Narrow(n) => Some(i128::from(*n)),
+      Width::Wide(n) => Some(*n),
+      Width::Big(_) => None,
+    }
+  }
+
+  fn to_big(&self) -> BigInt {
+    match &self.0 {
+      Width::Narrow(n) => BigInt::from(*n),
+      Width::Wide(n) => BigInt::from(*n),
+      Width::Big(n) => n.clone(),
+    }
+  }
+
+  /* apply - `self op other` in the narrowest width that holds the result,
+  only widening the operands when the narrower operation overflows */
+  fn apply(
+    &self,
+    other: &Total,
+    narrow: fn(i64, i64) -> Option<i64>,
+    wide: fn(i128, i128) -> Option<i128>,
+    big: fn(BigInt, BigInt) -> BigInt,
+  ) -> Total {
+    if let (Width::Narrow(a), Width::Narrow(b)) = (&self.0, &other.0) {
+      if let Some(n) = narrow(*a, *b) {
+        return Total(Width::Narrow(n));
+      }
+      crate::trace!("{a} and {b} overflow 64 bits, widening");
+    }
+    let wide = match (self.to_i128(), other.to_i128()) {
+      (Some(a), Some(b)) => wide(a, b),
+      _ => None,
+    };
+
+    match wide {
+      Some(n) => Total::from(n),
+      None => Total::from(big(self.to_big(), other.to_big())),
+    }
+  }
+}
+
+impl Default for Total {
+  fn default() -> Self {
+    Total::ZERO
+  }
+}
+
+impl fmt::Display for Total {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.0 {
+      Width::Narrow(n) => write!(f, "{n}"),
+      Width::Wide(n) => write!(f, "{n}"),
+      Width::Big(n) => write!(f, "{n}"),
+    }
+  }
+}
+
+impl Ord for Total {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self.to_i128(), other.to_i128()) {
+      (Some(a), Some(b)) => a.cmp(&b),
+      _ => self.to_big().cmp(&other.to_big()),
+    }
+  }
+}
+
+impl PartialOrd for Total {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl From<i128> for Total {
+  fn from(n: i128) -> Self {
+    match i64::try_from(n) {
+      Ok(n) => Total(Width::Narrow(n)),
+      Err(_) => Total(Width::Wide(n)),
+    }
+  }
+}
+
+impl From<BigInt> for Total {
+  fn from(n: BigInt) -> Self {
+    match i128::try_from(&n) {
+      Ok(n) => Total::from(n),
+      Err(_) => Total(Width::Big(n)),
+    }
+  }
+}
+
+macro_rules! from_narrow {
+  ($($t:ty),*) => {$(
+    impl From<$t> for Total {
+      fn from(n: $t) -> Self {
+        Total(Width::Narrow(i64::from(n)))
+      }
+    }
+  )*};
+}
+
+macro_rules! from_wide {
+  ($($t:ty),*) => {$(
+    impl From<$t> for Total {
+      fn from(n: $t) -> Self {
+        // none of these is wider than 64 bits
+        Total::from(n as i128)
+      }
+    }
+  )*};
+}
+
+from_narrow!(i8, i16, i32, i64, u8, u16, u32);
+from_wide!(isize, usize, u64);
+
+impl From<u128> for Total {
+  fn from(n: u128) -> Self {
+    match i128::try_from(n) {
+      Ok(n) => Total::from(n),
+      Err(_) => Total(Width::Big(BigInt::from(n))),
+    }
+  }
+}
+
+/* the operators, for totals and anything that converts into one */
+macro_rules! operator {
+  ($trait:ident, $method:ident, $assign:ident, $assign_method:ident,
+   $checked:ident, $big:expr) => {
+    impl<T: Into<Total>> $trait<T> for Total {
+      type Output = Total;
+
+      fn $method(self, other: T) -> Total {
+        self.apply(
+          &other.into(),
+          |a, b| a.$checked(b),
+          |a, b| a.$checked(b),
+          $big,
+        )
+      }
+    }
+
+    impl<T: Into<Total>> $assign<T> for Total {
+      fn $assign_method(&mut self, other: T) {
+        *self = std::mem::take(self).$method(other);
+      }
+    }
+  };
+}
+
+operator!(Add, add, AddAssign, add_assign, checked_add, |a, b| a + b);
+operator!(Sub, sub, SubAssign, sub_assign, checked_sub, |a, b| a - b);
+operator!(Mul, mul, MulAssign, mul_assign, checked_mul, |a, b| a * b);
+// rounding towards zero, and panicking on zero, as the primitives do
+operator!(Div, div, DivAssign, div_assign, checked_div, |a, b| a / b);
+
+impl Neg for Total {
+  type Output = Total;
+
+  fn neg(self) -> Total {
+    Total::ZERO - self
+  }
+}
+
+impl<T: Into<Total>> Sum<T> for Total {
+  fn sum<I: Iterator<Item = T>>(iter: I) -> Total {
+    iter.fold(Total::ZERO, |sum, n| sum + n)
+  }
+}
+
+impl<T: Into<Total>> Product<T> for Total {
+  fn product<I: Iterator<Item = T>>(iter: I) -> Total {
+    iter.fold(Total::ONE, |product, n| product * n)
+  }
+}
+
+impl<'a> From<&'a Total> for Total {
+  fn from(n: &'a Total) -> Self {
+    n.clone()
+  }
+}
+
+/* comparisons with the primitives, without widening them into totals */
+macro_rules! compare {
+  ($($t:ty),*) => {$(
+    impl PartialEq<$t> for Total {
+      fn eq(&self, other: &$t) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+      }
+    }
+
+    impl PartialOrd<$t> for Total {
+      fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+        Some(match (self.to_i128(), i128::try_from(*other)) {
+          (Some(n), Ok(other)) => n.cmp(&other),
+          _ => self.to_big().cmp(&BigInt::from(*other)),
+        })
+      }
+    }
+  )*};
+}
+
+compare!(i32, i64, isize, u32, u64, usize, i128, u128);
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_widen_instead_of_wrapping() {
+    let max = Total::from(i64::MAX);
+    assert_eq!(max.bits(), 64);
+
+    let wide = max.clone() + 1;
+    assert_eq!(wide.bits(), 128);
+    assert_eq!(wide.to_string(), "9223372036854775808");
+    // back within 64 bits, it narrows again
+    assert_eq!((wide - 1).bits(), 64);
+
+    let big = Total::from(u64::MAX).pow(3);
+    assert!(big.bits() > 128);
+    assert_eq!(
+      big.to_string(),
+      "6277101735386680762814942322444851025767571854389858533375"
+    );
+    assert!(big > u128::MAX);
+    assert_eq!(big.clone() - big, 0);
+  }
+
+  #[test]
+  fn it_should_sum_and_multiply_anything_integral() {
+    let sum: Total = [u64::MAX, u64::MAX, 2].into_iter().sum();
+    assert_eq!(sum, u128::from(u64::MAX) * 2 + 2);
+
+    let product: Total = [4000_usize; 4].into_iter().product();
+    assert_eq!(product, 256_000_000_000_000_u64);
+    assert_eq!(-Total::from(i64::MIN), 9_223_372_036_854_775_808_u64);
+
+    let mut total = Total::default();
+    total += 7;
+    total *= Total::from(6);
+    total -= 2_usize;
+    assert_eq!(total, 40);
+    assert_eq!(Total::from(-7) / 2, -3);
+    assert_eq!(Total::from(u128::MAX) / Total::from(u128::MAX), 1);
+    assert_eq!(Total::from(-1).cmp(&Total::ZERO), Ordering::Less);
+  }
+}
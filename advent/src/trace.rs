@@ -0,0 +1,231 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+
+/* the environment variable read when no `--trace` is given */
+pub const TRACE_VAR: &str = "AOC_TRACE";
+
+/* Level - how much detail a message carries, from the rare and important to
+the per-step firehose */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+  Error = 1,
+  Warn,
+  Info,
+  Debug,
+  Trace,
+}
+
+/* Filter - which messages are printed, parsed from a comma-separated spec
+like `warn,day-5=debug,day_20::network=trace`. A bare level sets the default,
+a bare target enables everything in it, and `off` silences. Targets are day
+crates or modules within them, and the most specific one that contains the
+message's module wins. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+  default: Option<Level>,
+  targets: Vec<(String, Option<Level>)>,
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::DEFAULT);
+// the most detailed level any target allows, for a cheap check when tracing
+// is off
+static MOST_DETAILED: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+impl Filter {
+  // warnings and errors only
+  const DEFAULT: Filter =
+    Filter { default: Some(Level::Warn), targets: Vec::new() };
+
+  /* from_env - the filter in `AOC_TRACE`, or the default one */
+  pub fn from_env() -> Result<Filter, String> {
+    match std::env::var(TRACE_VAR) {
+      Ok(spec) => spec.parse(),
+      Err(_) => Ok(Filter::default()),
+    }
+  }
+
+  /* allows - whether a message at `level` from `module` gets through */
+  pub fn allows(&self, level: Level, module: &str) -> bool {
+    let within = |target: &str| {
+      module
+        .strip_prefix(target)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+    };
+    let limit = self
+      .targets
+      .iter()
+      .filter(|(target, _)| within(target))
+      .max_by_key(|(target, _)| target.len())
+      .map_or(self.default, |(_, limit)| *limit);
+
+    limit.is_some_and(|limit| level <= limit)
+  }
+
+  fn most_detailed(&self) -> Option<Level> {
+    let targets = self.targets.iter().filter_map(|(_, limit)| *limit);
+    targets.chain(self.default).max()
+  }
+}
+
+impl Default for Filter {
+  fn default() -> Self {
+    Filter::DEFAULT
+  }
+}
+
+impl FromStr for Filter {
+  type Err = String;
+
+  fn from_str(spec: &str) -> Result<Self, Self::Err> {
+    let mut filter = Filter::default();
+    for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+      match directive.split_once('=') {
+        Some((target, limit)) => filter
+          .targets
+          .push((module_name(target), parse_limit(limit)?)),
+        None => match parse_limit(directive) {
+          Ok(limit) => filter.default = limit,
+          Err(_) => filter
+            .targets
+            .push((module_name(directive), Some(Level::Trace))),
+        },
+      }
+    }
+
+    Ok(filter)
+  }
+}
+
+/* module_name - a target as a module path; crates are named `day-5` on the
+command line but `day_5` in module paths */
+fn module_name(target: &str) -> String {
+  target.trim().replace('-', "_")
+}
+
+/* parse_limit - a level, or `off` for none */
+fn parse_limit(limit: &str) -> Result<Option<Level>, String> {
+  match limit.trim().to_ascii_lowercase().as_str() {
+    "off" => Ok(None),
+    "error" => Ok(Some(Level::Error)),
+    "warn" => Ok(Some(Level::Warn)),
+    "info" => Ok(Some(Level::Info)),
+    "debug" => Ok(Some(Level::Debug)),
+    "trace" => Ok(Some(Level::Trace)),
+    other => Err(format!("[trace] unknown level '{other}'")),
+  }
+}
+
+impl fmt::Display for Level {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Level::Error => write!(f, "error"),
+      Level::Warn => write!(f, "warn"),
+      Level::Info => write!(f, "info"),
+      Level::Debug => write!(f, "debug"),
+      Level::Trace => write!(f, "trace"),
+    }
+  }
+}
+
+/* install - replaces the filter every later message is checked against */
+pub fn install(filter: Filter) {
+  let most_detailed = filter.most_detailed().map_or(0, |level| level as u8);
+  *FILTER.write().unwrap_or_else(|e| e.into_inner()) = filter;
+  MOST_DETAILED.store(most_detailed, Ordering::Relaxed);
+}
+
+/* enabled - whether a message at `level` from `module` would be printed;
+the macros ask before formatting anything */
+pub fn enabled(level: Level, module: &str) -> bool {
+  if level as u8 > MOST_DETAILED.load(Ordering::Relaxed) {
+    return false;
+  }
+
+  FILTER
+    .read()
+    .unwrap_or_else(|e| e.into_inner())
+    .allows(level, module)
+}
+
+/* emit - prints a message to stderr, tagged with its level and module */
+pub fn emit(level: Level, module: &str, message: fmt::Arguments) {
+  eprintln!("[{level} {module}] {message}");
+}
+
+/* event - a message at `level` from the calling module, formatted only when
+the installed filter lets it through */
+#[macro_export]
+macro_rules! event {
+  ($level:expr, $($arg:tt)+) => {{
+    let level = $level;
+    if $crate::trace::enabled(level, module_path!()) {
+      $crate::trace::emit(level, module_path!(), format_args!($($arg)+));
+    }
+  }};
+}
+
+#[macro_export]
+macro_rules! warn {
+  ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+  ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+  ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+  ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn filter(spec: &str) -> Filter {
+    spec.parse().unwrap()
+  }
+
+  #[test]
+  fn it_should_only_warn_by_default() {
+    let filter = Filter::default();
+
+    assert!(filter.allows(Level::Warn, "day_5"));
+    assert!(!filter.allows(Level::Info, "day_5"));
+    assert_eq!(Filter::from_str(""), Ok(Filter::default()));
+  }
+
+  #[test]
+  fn it_should_prefer_the_most_specific_target() {
+    let filter = filter("info, day-20=debug, day_20::network=trace, day-9");
+
+    assert!(filter.allows(Level::Info, "day_5"));
+    assert!(!filter.allows(Level::Debug, "day_5"));
+    assert!(filter.allows(Level::Debug, "day_20::machine"));
+    assert!(!filter.allows(Level::Trace, "day_20::machine"));
+    assert!(filter.allows(Level::Trace, "day_20::network"));
+    assert!(filter.allows(Level::Trace, "day_9"));
+    // a target is a whole module, not a prefix of its name
+    assert!(!filter.allows(Level::Debug, "day_200"));
+  }
+
+  #[test]
+  fn it_should_switch_targets_off() {
+    let filter = filter("trace,day-15=off");
+
+    assert!(!filter.allows(Level::Error, "day_15"));
+    assert!(filter.allows(Level::Trace, "day_16"));
+    assert_eq!(filter.most_detailed(), Some(Level::Trace));
+    assert!(Filter::from_str("day-5=loud").is_err());
+  }
+}
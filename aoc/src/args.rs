@@ -0,0 +1,233 @@
+use std::num::NonZeroUsize;
+
+use advent::input::Source;
+use advent::params::{self, Selection};
+use advent::trace::Filter;
+use advent::{Format, Part};
+
+
+/* Args - `aoc [DAY | FROM-TO | all]... [--part <1|2>] [--input <PATH | ->]
+[--repeat <N>] [--json] [--trace <SPEC>] [--workers <N>] [--speedup]
+[--params <FILE>] [--set <KEY=VALUE>]...`. Without days every day runs,
+without a part both parts run. An input, like a parameter file or override,
+only makes sense for a single day; otherwise each day reads its bundled
+files.
+`--repeat` runs each part N times and reports the spread of its timings;
+`--json` prints one JSON object per run instead of the table. `--trace`
+overrides `AOC_TRACE`. `--workers` caps the threads a transform may use, one
+per core by default, and `--speedup` runs each part again on a single worker
+to show how much faster the transform was for them. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Args {
+  pub days: Vec<u8>,
+  pub parts: Vec<Part>,
+  pub input: Option<Source>,
+  pub format: Format,
+  pub runs: usize,
+  pub trace: Option<Filter>,
+  pub workers: Option<NonZeroUsize>,
+  pub speedup: bool,
+  pub params: Selection,
+}
+
+impl Args {
+  pub fn from_args<I>(args: I, all_days: &[u8]) -> Result<Args, String>
+  where
+    I: IntoIterator<Item = String>,
+  {
+    let mut parsed = Args {
+      days: Vec::new(),
+      parts: Vec::new(),
+      input: None,
+      format: Format::Text,
+      runs: 1,
+      trace: None,
+      workers: None,
+      speedup: false,
+      params: Selection::default(),
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+      let mut value = |arg: &str| {
+        args
+          .next()
+          .ok_or(format!("[aoc] missing value for '{arg}'"))
+      };
+      match arg.as_str() {
+        "-p" | "--part" => parsed.parts.push(value(&arg)?.parse()?),
+        "-i" | "--input" => {
+          if parsed.input.is_some() {
+            return Err("[aoc] more than one input given".to_string());
+          }
+          parsed.input = Some(Source::from(value(&arg)?.as_str()));
+        }
+        "--json" => parsed.format = Format::Json,
+        "--repeat" => {
+          let runs = value(&arg)?;
+          parsed.runs = runs
+            .parse()
+            .ok()
+            .filter(|&runs| runs > 0)
+            .ok_or(format!("[aoc] invalid repeat count '{runs}'"))?;
+        }
+        "--trace" => parsed.trace = Some(value(&arg)?.parse()?),
+        "--workers" => {
+          let workers = value(&arg)?;
+          parsed.workers =
+            Some(workers.parse().map_err(|_| {
+              format!("[aoc] invalid worker count '{workers}'")
+            })?);
+        }
+        "--speedup" => parsed.speedup = true,
+        "--params" => parsed.params.file = Some(value(&arg)?.into()),
+        "--set" => {
+          let spec = value(&arg)?;
+          parsed.params.overrides.push(params::parse_override(&spec)?);
+        }
+        "all" => parsed.days.extend(all_days),
+        flag if flag.starts_with('-') => {
+          return Err(format!("[aoc] unknown option '{flag}'"));
+        }
+        days => parsed.days.extend(parse_days(days)?),
+      }
+    }
+
+    if parsed.days.is_empty() {
+      parsed.days.extend(all_days);
+    }
+    parsed.days.sort_unstable();
+    parsed.days.dedup();
+    if parsed.parts.is_empty() {
+      parsed.parts.extend(Part::ALL);
+    }
+    parsed.parts.sort_unstable();
+    parsed.parts.dedup();
+
+    if parsed.speedup && parsed.format == Format::Json {
+      return Err("[aoc] --speedup is only shown in the table".to_string());
+    }
+    if parsed.input.is_some() && parsed.days.len() > 1 {
+      return Err("[aoc] --input needs exactly one day".to_string());
+    }
+    if parsed.params != Selection::default() && parsed.days.len() > 1 {
+      return Err("[aoc] --params and --set need exactly one day".to_string());
+    }
+    if let Some(day) = parsed.days.iter().find(|day| !all_days.contains(day)) {
+      return Err(format!("[aoc] day {day} is not implemented"));
+    }
+
+    Ok(parsed)
+  }
+}
+
+/* parse_days - a single day (`14`) or an inclusive range (`3-7`) */
+fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+  let parse = |day: &str| {
+    day
+      .trim()
+      .parse::<u8>()
+      .map_err(|_| format!("[aoc] invalid day '{spec}'"))
+  };
+  match spec.split_once('-') {
+    Some((from, to)) => {
+      let (from, to) = (parse(from)?, parse(to)?);
+      if from > to {
+        return Err(format!("[aoc] empty day range '{spec}'"));
+      }
+
+      Ok((from..=to).collect())
+    }
+    None => Ok(vec![parse(spec)?]),
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::path::PathBuf;
+
+  const ALL: [u8; 4] = [1, 2, 3, 4];
+
+  fn parse(list: &[&str]) -> Result<Args, String> {
+    Args::from_args(list.iter().map(|s| s.to_string()), &ALL)
+  }
+
+  #[test]
+  fn it_should_run_everything_by_default() {
+    assert_eq!(
+      parse(&[]),
+      Ok(Args {
+        days: ALL.to_vec(),
+        parts: Part::ALL.to_vec(),
+        input: None,
+        format: Format::Text,
+        runs: 1,
+        trace: None,
+        workers: None,
+        speedup: false,
+        params: Selection::default(),
+      })
+    );
+    assert_eq!(parse(&["all"]).map(|a| a.days), Ok(ALL.to_vec()));
+  }
+
+  #[test]
+  fn it_should_select_days_and_ranges() {
+    assert_eq!(parse(&["3"]).map(|a| a.days), Ok(vec![3]));
+    assert_eq!(parse(&["2-3", "1"]).map(|a| a.days), Ok(vec![1, 2, 3]));
+    assert!(parse(&["3-2"]).is_err());
+    assert!(parse(&["5"]).is_err());
+    assert!(parse(&["x"]).is_err());
+  }
+
+  #[test]
+  fn it_should_select_a_part_and_an_input_for_one_day() {
+    assert_eq!(
+      parse(&["4", "--part", "2", "--input", "in.txt"]),
+      Ok(Args {
+        days: vec![4],
+        parts: vec![Part::Two],
+        input: Some(Source::File(PathBuf::from("in.txt"))),
+        format: Format::Text,
+        runs: 1,
+        trace: None,
+        workers: None,
+        speedup: false,
+        params: Selection::default(),
+      })
+    );
+    assert_eq!(parse(&["--json"]).map(|a| a.format), Ok(Format::Json));
+    assert_eq!(parse(&["--repeat", "7"]).map(|a| a.runs), Ok(7));
+    assert!(parse(&["--repeat", "0"]).is_err());
+    assert!(parse(&["--trace", "day-3=debug"]).is_ok_and(|a| a.trace.is_some()));
+    assert!(parse(&["1-2", "--input", "in.txt"]).is_err());
+    assert!(parse(&["1", "-i", "a", "-i", "b"]).is_err());
+    assert!(parse(&["--bogus"]).is_err());
+  }
+
+  #[test]
+  fn it_should_take_a_worker_count_and_a_speedup() {
+    let parsed = parse(&["--workers", "4", "--speedup"]).unwrap();
+    assert_eq!(
+      (parsed.workers, parsed.speedup),
+      (NonZeroUsize::new(4), true)
+    );
+    assert!(parse(&["--workers", "0"]).is_err());
+    assert!(parse(&["--speedup", "--json"]).is_err());
+  }
+
+  #[test]
+  fn it_should_take_parameters_for_one_day() {
+    let parsed = parse(&["2", "--params", "p.txt", "--set", "a=b"]).unwrap();
+    assert_eq!(
+      parsed.params,
+      Selection {
+        file: Some(PathBuf::from("p.txt")),
+        overrides: vec![("a".to_string(), "b".to_string())],
+      }
+    );
+    assert!(parse(&["--set", "a=b"]).is_err());
+    assert!(parse(&["2", "--set", "a"]).is_err());
+  }
+}
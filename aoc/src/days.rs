@@ -0,0 +1,198 @@
+use advent::{measure, Part, Run};
+
+
+/* Runner - measures one part of a day, repeating it the given number of
+times */
+pub type Runner = fn(Part, usize) -> Run;
+
+/* DAYS - every day the runner can dispatch to, in order */
+pub const DAYS: [(u8, Runner); 21] = [
+  (1, measure::<day_1::Day1>),
+  (2, measure::<day_2::Day2>),
+  (3, measure::<day_3::Day3>),
+  (4, measure::<day_4::Day4>),
+  (5, measure::<day_5::Day5>),
+  (6, measure::<day_6::Day6>),
+  (7, measure::<day_7::Day7>),
+  (8, measure::<day_8::Day8>),
+  (9, measure::<day_9::Day9>),
+  (10, measure::<day_10::Day10>),
+  (11, measure::<day_11::Day11>),
+  (12, measure::<day_12::Day12>),
+  (13, measure::<day_13::Day13>),
+  (14, measure::<day_14::Day14>),
+  (15, measure::<day_15::Day15>),
+  (16, measure::<day_16::Day16>),
+  (17, measure::<day_17::Day17>),
+  (18, measure::<day_18::Day18>),
+  (19, measure::<day_19::Day19>),
+  (20, measure::<day_20::Day20>),
+  (21, measure::<day_21::Day21>),
+];
+
+pub fn numbers() -> Vec<u8> {
+  DAYS.iter().map(|(day, _)| *day).collect()
+}
+
+pub fn find(day: u8) -> Option<Runner> {
+  DAYS
+    .iter()
+    .find(|(number, _)| *number == day)
+    .map(|(_, run)| *run)
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::input::{self, Source};
+  use advent::parallel;
+  use advent::{answers::ANSWERS_FILE, Answers, Error, Solution, Verdict};
+  use std::num::NonZeroUsize;
+  use std::path::PathBuf;
+  use std::sync::Mutex;
+
+  // input selection is global, so tests that select input take turns
+  static INPUT: Mutex<()> = Mutex::new(());
+
+  /* every answer recorded for an input that is present, reproduced through the
+  runner. Puzzle inputs are not committed, so their answers are checked only
+  where the file has been downloaded. */
+  #[test]
+  fn it_should_reproduce_every_recorded_answer() {
+    let _input = INPUT.lock().unwrap_or_else(|e| e.into_inner());
+    let root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for (day, run) in DAYS {
+      let dir = root.join(format!("day-{day}"));
+      let answers = Answers::load(&dir.join(ANSWERS_FILE)).unwrap();
+      for entry in answers.entries() {
+        let path = dir.join(&entry.input);
+        if !path.exists() {
+          continue;
+        }
+
+        input::select(Some(Source::File(path)));
+        let result = run(entry.part, 1);
+        checked += 1;
+        if result.verdict != Verdict::Pass {
+          failures.push(format!(
+            "day {day} part {} on {}: {} ({:?})",
+            entry.part,
+            entry.input,
+            result.verdict,
+            result.answer.map_err(|e| e.to_string())
+          ));
+        }
+      }
+    }
+    input::select(None);
+
+    assert!(checked > 0, "no recorded answers were checked");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+  }
+  /* every day solves inputs from its generator, at a small scale to keep the
+  brute-force parts quick; days whose inputs stop growing well before their
+  answers slow down are solved at puzzle size too */
+  #[test]
+  fn it_should_solve_generated_inputs() {
+    let _input = INPUT.lock().unwrap_or_else(|e| e.into_inner());
+    let mut failures = Vec::new();
+    for (day, run) in DAYS {
+      let scales: &[f64] = match day {
+        6 => &[0.2, 1.0],
+        _ => &[0.2],
+      };
+      for seed in 1..=3 {
+        for &scale in scales {
+          // a day scaffolded since may not have a generator yet
+          let Some(text) = generate::generate(day, seed, scale) else {
+            continue;
+          };
+          for part in [Part::One, Part::Two] {
+            input::select(Some(Source::Text(text.clone())));
+            if let Err(e) = run(part, 1).answer {
+              failures.push(format!(
+                "day {day} part {part} seed {seed} scale {scale}: {e}"
+              ));
+            }
+          }
+        }
+      }
+    }
+    input::select(None);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+  }
+
+  /* the transforms spread over threads answer as they do on one */
+  #[test]
+  fn it_should_solve_generated_inputs_alike_on_any_number_of_workers() {
+    let _input = INPUT.lock().unwrap_or_else(|e| e.into_inner());
+    let mut failures = Vec::new();
+    for day in [5, 9, 12, 13, 16] {
+      let run = find(day).unwrap();
+      for seed in 1..=2 {
+        let text = generate::generate(day, seed, 0.2).unwrap();
+        input::select(Some(Source::Text(text)));
+        for part in [Part::One, Part::Two] {
+          let answers: Vec<_> = [1, 4]
+            .map(|workers| {
+              parallel::select(NonZeroUsize::new(workers));
+              run(part, 1).answer
+            })
+            .into();
+          if answers[0] != answers[1] {
+            failures
+              .push(format!("day {day} part {part} seed {seed}: {answers:?}"));
+          }
+        }
+      }
+    }
+    parallel::select(None);
+    input::select(None);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+  }
+
+  /* the days that fold their answer a line at a time reach the same answers
+  streaming as they do through the pipeline */
+  #[test]
+  fn it_should_stream_generated_inputs_to_the_same_answers() {
+    type Stream = fn(Part) -> Result<String, Error>;
+    const STREAMING: [(u8, Stream); 8] = [
+      (1, day_1::Day1::stream),
+      (2, day_2::Day2::stream),
+      (4, day_4::Day4::stream),
+      (6, day_6::Day6::stream),
+      (7, day_7::Day7::stream),
+      (9, day_9::Day9::stream),
+      (12, day_12::Day12::stream),
+      (18, day_18::Day18::stream),
+    ];
+
+    let _input = INPUT.lock().unwrap_or_else(|e| e.into_inner());
+    let mut failures = Vec::new();
+    for (day, stream) in STREAMING {
+      let run = find(day).unwrap();
+      for seed in 1..=3 {
+        let text = generate::generate(day, seed, 0.2).unwrap();
+        for part in [Part::One, Part::Two] {
+          input::select(Some(Source::Text(text.clone())));
+          let solved = run(part, 1).answer;
+          let streamed = stream(part);
+          if streamed != solved {
+            failures.push(format!(
+              "day {day} part {part} seed {seed}: streamed {streamed:?}, \
+               solved {solved:?}"
+            ));
+          }
+        }
+      }
+    }
+    input::select(None);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+  }
+}
@@ -0,0 +1,75 @@
+mod args;
+mod days;
+mod report;
+
+use advent::trace::{self, Filter};
+use advent::{parallel, Error, ErrorKind, Format};
+use args::Args;
+use report::Row;
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+
+
+fn main() -> ExitCode {
+  match run() {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) => {
+      eprintln!("{e}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+fn run() -> Result<(), Error> {
+  let args = Args::from_args(std::env::args().skip(1), &days::numbers())
+    .map_err(|e| Error::new(ErrorKind::Usage, e))?;
+  let filter = args.trace.map_or_else(Filter::from_env, Ok);
+  trace::install(filter.map_err(|e| Error::new(ErrorKind::Usage, e))?);
+  let input = args.input.map(|source| source.buffered()).transpose()?;
+  advent::input::select(input);
+  parallel::select(args.workers);
+  advent::params::select(Some(args.params));
+
+  let mut rows = Vec::new();
+  for &day in &args.days {
+    let run = days::find(day).ok_or(Error::new(
+      ErrorKind::Usage,
+      format!("[aoc] unknown day {day}"),
+    ))?;
+    for &part in &args.parts {
+      let sequential = args.speedup.then(|| {
+        parallel::select(NonZeroUsize::new(1));
+        let sequential = run(part, args.runs);
+        parallel::select(args.workers);
+
+        sequential
+      });
+      rows.push(Row { day, part, run: run(part, args.runs), sequential });
+    }
+  }
+
+  match args.format {
+    Format::Json => {
+      for row in &rows {
+        println!("{}", row.run.to_json(row.day, row.part));
+      }
+    }
+    Format::Text | Format::Profile => print!("{}", report::render(&rows)),
+  }
+
+  let failures: Vec<Error> = rows
+    .iter()
+    .filter_map(|row| {
+      let outcome = row.run.outcome(row.day).and_then(|()| row.consistent());
+      outcome.err().map(|e| (row.part, e))
+    })
+    .map(|(part, e)| {
+      eprintln!("(part {part}) {e}");
+      e
+    })
+    .collect();
+  match failures.len() {
+    0 => Ok(()),
+    n => Err(format!("[aoc] {n} of {} runs failed", rows.len()).into()),
+  }
+}
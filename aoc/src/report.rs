@@ -0,0 +1,223 @@
+use advent::{heap, Error, ErrorKind, Part, Run, Stage};
+
+
+/* Row - one part of one day, and the same part on a single worker when the
+speedup was asked for */
+pub struct Row {
+  pub day: u8,
+  pub part: Part,
+  pub run: Run,
+  pub sequential: Option<Run>,
+}
+
+impl Row {
+  /* speedup - how many times faster the transform ran than on one worker,
+  by median */
+  pub fn speedup(&self) -> Option<f64> {
+    let median = |run: &Run| run.stats(Stage::Transform).map(|s| s.median);
+    let (parallel, sequential) =
+      (median(&self.run)?, median(self.sequential.as_ref()?)?);
+
+    match parallel.is_zero() {
+      true => None,
+      false => Some(sequential.as_secs_f64() / parallel.as_secs_f64()),
+    }
+  }
+
+  /* consistent - a parallel transform has to answer as a sequential one */
+  pub fn consistent(&self) -> Result<(), Error> {
+    let Some(sequential) = &self.sequential else {
+      return Ok(());
+    };
+    match (&self.run.answer, &sequential.answer) {
+      (Ok(parallel), Ok(sequential)) if parallel != sequential => {
+        Err(Error::new(
+          ErrorKind::Mismatch,
+          format!(
+            "[aoc] day {} answered {parallel} in parallel but {sequential} on \
+             one worker",
+            self.day
+          ),
+        ))
+      }
+      _ => Ok(()),
+    }
+  }
+}
+
+/* render - the summary table, one row per day and part. Each stage shows its
+median time, with the range when the runs were repeated, and the speedup
+follows when it was measured. */
+pub fn render(rows: &[Row]) -> String {
+  let mut header = vec!["day", "part", "answer", "check"];
+  let stages: Vec<String> = Stage::ALL.iter().map(Stage::to_string).collect();
+  header.extend(stages.iter().map(String::as_str));
+  header.push("heap");
+  let speedup = rows.iter().any(|row| row.sequential.is_some());
+  if speedup {
+    header.push("speedup");
+  }
+
+  let cells: Vec<Vec<String>> = rows
+    .iter()
+    .map(|row| {
+      let mut cells = vec![
+        row.day.to_string(),
+        row.part.to_string(),
+        match &row.run.answer {
+          Ok(answer) => answer.clone(),
+          Err(_) => "error".to_string(),
+        },
+        match &row.run.answer {
+          Ok(_) => row.run.verdict.to_string(),
+          Err(_) => "-".to_string(),
+        },
+      ];
+      cells.extend(Stage::ALL.map(|stage| {
+        row
+          .run
+          .stats(stage)
+          .map_or("-".to_string(), |s| s.to_string())
+      }));
+      cells.push(row.run.peak_heap().map_or("-".to_string(), heap::format));
+      if speedup {
+        cells.push(
+          row
+            .speedup()
+            .map_or("-".to_string(), |x| format!("{x:.2}x")),
+        );
+      }
+
+      cells
+    })
+    .collect();
+
+  let widths: Vec<usize> = (0..header.len())
+    .map(|i| {
+      cells
+        .iter()
+        .map(|cell| cell[i].chars().count())
+        .chain([header[i].len()])
+        .max()
+        .unwrap_or(0)
+    })
+    .collect();
+
+  let line = |cells: &[&str]| {
+    let text = cells
+      .iter()
+      .zip(&widths)
+      .enumerate()
+      .map(|(i, (cell, &width))| match i {
+        2 | 3 => format!("{cell:<width$}"),
+        _ => format!("{cell:>width$}"),
+      })
+      .collect::<Vec<_>>()
+      .join("  ");
+
+    format!("{}\n", text.trim_end())
+  };
+
+  let mut table = line(&header);
+  for cell in &cells {
+    table += &line(&cell.iter().map(String::as_str).collect::<Vec<_>>());
+  }
+
+  table
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::{Sample, Verdict};
+  use std::time::Duration;
+
+  #[test]
+  fn it_should_align_the_summary_table() {
+    let rows = [
+      Row {
+        day: 1,
+        part: Part::One,
+        run: Run {
+          answer: Ok("142".to_string()),
+          verdict: Verdict::Pass,
+          input: Some("sample.txt".to_string()),
+          samples: vec![
+            Sample {
+              read: Some(Duration::from_micros(40)),
+              extract: Some(Duration::from_micros(1500)),
+              transform: Some(Duration::from_micros(20)),
+              load: Some(Duration::from_nanos(300)),
+              peak_heap: 2048,
+            },
+            Sample {
+              read: Some(Duration::from_micros(60)),
+              extract: Some(Duration::from_micros(1500)),
+              transform: Some(Duration::from_micros(20)),
+              load: Some(Duration::from_nanos(300)),
+              peak_heap: 1024,
+            },
+          ],
+        },
+        sequential: None,
+      },
+      Row {
+        day: 14,
+        part: Part::Two,
+        run: Run {
+          answer: Err(Error::new(ErrorKind::Input, "missing")),
+          verdict: Verdict::Unknown,
+          input: None,
+          samples: vec![Sample {
+            extract: Some(Duration::from_micros(3)),
+            ..Sample::default()
+          }],
+        },
+        sequential: None,
+      },
+    ];
+
+    assert_eq!(
+      render(&rows),
+      concat!(
+        "day  part  answer  check                        read  extract  ",
+        "transform      load     heap\n",
+        "  1     1  142     pass   40.00µs (40.00µs..60.00µs)   1.50ms    ",
+        "20.00µs  300.00ns  2.0 KiB\n",
+        " 14     2  error   -                               -   3.00µs",
+        "          -         -      0 B\n"
+      )
+    );
+  }
+
+  #[test]
+  fn it_should_show_the_speedup_over_one_worker() {
+    let run = |answer: &str, transform| Run {
+      answer: Ok(answer.to_string()),
+      verdict: Verdict::Unknown,
+      input: None,
+      samples: vec![Sample {
+        transform: Some(Duration::from_millis(transform)),
+        ..Sample::default()
+      }],
+    };
+    let row = |sequential| Row {
+      day: 12,
+      part: Part::Two,
+      run: run("7", 10),
+      sequential,
+    };
+
+    assert_eq!(row(Some(run("7", 20))).speedup(), Some(2.0));
+    assert_eq!(row(None).speedup(), None);
+    assert!(render(&[row(Some(run("7", 20)))])
+      .lines()
+      .all(|line| { line.ends_with("speedup") || line.ends_with("2.00x") }));
+    assert_eq!(row(Some(run("7", 20))).consistent(), Ok(()));
+    assert_eq!(
+      row(Some(run("8", 20))).consistent().map_err(|e| e.kind),
+      Err(ErrorKind::Mismatch)
+    );
+  }
+}
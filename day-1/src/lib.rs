@@ -0,0 +1,289 @@
+mod lib {
+  pub mod explain;
+  pub mod scanner;
+  pub mod vocabulary;
+}
+use advent::input::{self, Lines};
+use advent::{params, Error, Format, Part, Solution, Total};
+use lib::{explain, scanner::Scanner, vocabulary::Vocabulary};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(feature = "sample")]
+const PART2_DATA_PATH: &str =
+  concat!(env!("CARGO_MANIFEST_DIR"), "/sample-part2.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+#[cfg(not(feature = "sample"))]
+const PART2_DATA_PATH: &str = DATA_PATH;
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+  type ProblemDefinition = Vec<String>;
+  type Consequent = Total;
+
+  const DAY: u8 = 1;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(part)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(data_path(part))?, part)
+  }
+
+  fn explain(
+    data: &Self::ProblemDefinition,
+    part: Part,
+    format: Format,
+  ) -> Result<String, Error> {
+    Ok(explain::report(&scanner(part)?, data, format))
+  }
+}
+
+
+#[mry::mry]
+fn src_provider(part: Part) -> Result<String, Error> {
+  input::read(data_path(part))
+}
+
+fn data_path(part: Part) -> &'static str {
+  match part {
+    Part::One => DATA_PATH,
+    Part::Two => PART2_DATA_PATH,
+  }
+}
+
+fn extract(part: Part) -> Result<Vec<String>, Error> {
+  src_provider(part)?
+    .lines()
+    .enumerate()
+    .map(|(i, line)| parse_line(i + 1, line))
+    .collect()
+}
+
+fn parse_line(number: usize, line: &str) -> Result<String, Error> {
+  if line.is_empty() {
+    return Err(Error::missing("empty calibration line").at(number, 1));
+  }
+  match line
+    .char_indices()
+    .find(|(_, c)| !(c.is_ascii_graphic() || *c == ' '))
+  {
+    Some((offset, c)) => Err(
+      Error::value(format!("unexpected character {c:?}"))
+        .near(&line[offset..])
+        .on_line(number, line),
+    ),
+    None => Ok(line.to_string()),
+  }
+}
+
+/* stream - `extract` and `transform` a line at a time: each line is
+calibrated as soon as it is read and only the running sum is kept */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let scanner = scanner(part)?;
+  let mut sum = Total::ZERO;
+
+  for (i, line) in lines.enumerate() {
+    let line = parse_line(i + 1, &line?)?;
+    sum += calibrate(&scanner, i + 1, &line)?;
+  }
+
+  Ok(sum.to_string())
+}
+
+fn transform(data: Vec<String>, part: Part) -> Result<Total, Error> {
+  let scanner = scanner(part)?;
+  let mut sum = Total::ZERO;
+
+  for (i, line) in data.iter().enumerate() {
+    sum += calibrate(&scanner, i + 1, line)?;
+  }
+
+  Ok(sum)
+}
+
+/* scanner - reads numeric digits alone in part 1. Part 2 reads the words of
+the `vocabulary` in the parameters as well, a built-in one or a file, English
+unless it says otherwise, matching case unless `case_insensitive` is set. */
+fn scanner(part: Part) -> Result<Scanner, Error> {
+  if part == Part::One {
+    return Ok(Scanner::new(Vocabulary::numeric()));
+  }
+  let params = params::load(PARAMS_PATH, &["vocabulary", "case_insensitive"])?;
+  let case_insensitive = params.get("case_insensitive", false)?;
+  let vocabulary = params.get_with(
+    "vocabulary",
+    Vocabulary::builtin("english", case_insensitive).unwrap(),
+    |spec| Vocabulary::load(spec, case_insensitive),
+  )?;
+  for (short, long) in vocabulary.ambiguities() {
+    match vocabulary.starts(long, short) {
+      true => advent::warn!(
+        "{}: '{short}' starts '{long}', which is read wherever both match",
+        vocabulary.name
+      ),
+      false => advent::warn!(
+        "{}: '{short}' appears within '{long}', so both are read as digits",
+        vocabulary.name
+      ),
+    }
+  }
+
+  Ok(Scanner::new(vocabulary))
+}
+
+/* calibrate - the value of line `number`, made of its first and last digits
+as `scanner` reads them */
+fn calibrate(
+  scanner: &Scanner,
+  number: usize,
+  line: &str,
+) -> Result<u32, Error> {
+  let Some(calibration) = scanner.calibrate(line) else {
+    return Err(
+      Error::missing("no digit to calibrate with").on_line(number, line),
+    );
+  };
+  advent::trace!(
+    "line {number}: {} then {} make {}",
+    calibration.first,
+    calibration.last,
+    calibration.value()
+  );
+
+  Ok(calibration.value())
+}
+
+fn load(result: Result<Total, Error>) -> Result<(), Error> {
+  match result {
+    Ok(result) => println!("result: {result}"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const MALFORMED: [&str; 3] = [
+    "1abc2\n\npqr3stu8vwx",
+    "1abc2\npqr3stü8vwx",
+    "1abc2\na1b2c3d4e5f\u{0}",
+  ];
+
+  fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+  }
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_a_file_into_a_vector_of_strings() {
+    mock_src_provider(Part::One).returns(Ok("a test\nmultiline".to_string()));
+
+    let contents = extract(Part::One);
+    assert!(contents.is_ok());
+    let result = contents.ok().unwrap();
+    assert_eq!(result, lines("a test\nmultiline"));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider(mry::Any)
+      .returns_with(move |_| Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract(Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  fn it_should_sum_encountered_numbers_in_input() {
+    let data = lines("a 1test\nmu2ltil3ine");
+
+
+    let result = transform(data, Part::One);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 34);
+  }
+
+  #[test]
+  fn it_should_return_an_error_when_no_number_encountered_in_input() {
+    let data = lines("a 1test\nno number\nmu2ltil3ine");
+
+
+    let result = transform(data, Part::One);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn it_should_sum_spelled_out_numbers_in_part_two() {
+    let data = lines("two1nine\neightwothree\n7pqrstsixteen");
+
+    let error = transform(data.clone(), Part::One).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Missing);
+    assert_eq!(error.position, Some(Position { line: 2, column: 1 }));
+    assert_eq!(error.snippet.as_deref(), Some("eightwothree"));
+    assert_eq!(transform(data, Part::Two), Ok(Total::from(29 + 83 + 76)));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_stream_the_same_sums() {
+    let input = "two1nine\neightwothree\n7pqrstsixteen";
+    mock_src_provider(mry::Any).returns(Ok(input.to_string()));
+
+    for part in [Part::One, Part::Two] {
+      let solved = extract(part).and_then(|data| transform(data, part));
+      let streamed = stream(Lines::new(input.as_bytes()), part);
+      assert_eq!(streamed, solved.map(|sum| sum.to_string()));
+    }
+    let error = stream(Lines::new("1\n\n2".as_bytes()), Part::One);
+    assert_eq!(
+      error.unwrap_err().position,
+      Some(Position { line: 2, column: 1 })
+    );
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider(mry::Any).returns_with(move |_| input.input());
+
+    cases::assert_solves::<Day1>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
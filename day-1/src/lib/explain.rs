@@ -0,0 +1,121 @@
+use advent::measure::{json_object, json_string};
+use advent::Format;
+
+use super::scanner::{Calibration, Scanner, Token};
+
+
+const HEADER: [&str; 4] = ["line", "first", "last", "value"];
+
+/* report - every line's calibration: its number, the first and last digits
+found in it with the byte offsets they start at, and the value they make,
+with none for a line without digits. Text is a table, JSON an object per
+line; either way a line of input is a line of the report, so the reports of
+both parts can be diffed. */
+pub fn report(scanner: &Scanner, lines: &[String], format: Format) -> String {
+  let calibrations = lines.iter().map(|line| scanner.calibrate(line));
+  let rows: Vec<_> = (1..).zip(calibrations).collect();
+
+  match format {
+    Format::Json => rows.iter().map(|row| json(row) + "\n").collect(),
+    _ => table(&rows),
+  }
+}
+
+/* table - the rows under a header, in columns as wide as their widest
+cell, numbers to the right */
+fn table(rows: &[(usize, Option<Calibration>)]) -> String {
+  let cells: Vec<[String; 4]> = rows
+    .iter()
+    .map(|(number, calibration)| {
+      let token = |token: Token| token.to_string();
+      [
+        number.to_string(),
+        calibration.map_or("-".to_string(), |c| token(c.first)),
+        calibration.map_or("-".to_string(), |c| token(c.last)),
+        calibration.map_or("-".to_string(), |c| c.value().to_string()),
+      ]
+    })
+    .collect();
+  let width = |column: usize| {
+    let widths = cells.iter().map(|row| row[column].len());
+    widths
+      .chain([HEADER[column].len()])
+      .max()
+      .unwrap_or_default()
+  };
+  let widths = [width(0), width(1), width(2), width(3)];
+
+  let header = HEADER.map(String::from);
+  let mut table = String::new();
+  for [number, first, last, value] in [header].iter().chain(&cells) {
+    table += &format!(
+      "{number:>0$}  {first:<1$}  {last:<2$}  {value:>3$}\n",
+      widths[0], widths[1], widths[2], widths[3]
+    );
+  }
+
+  table
+}
+
+/* json - a row as `{"line":2,"first":{"token":"eight","offset":0,
+"value":8},"last":{"token":"three","offset":7,"value":3},"value":83}` */
+fn json((number, calibration): &(usize, Option<Calibration>)) -> String {
+  let token = |token: Token| {
+    json_object(&[
+      ("token", json_string(token.text)),
+      ("offset", token.offset.to_string()),
+      ("value", token.value.to_string()),
+    ])
+  };
+  let null = || "null".to_string();
+
+  json_object(&[
+    ("line", number.to_string()),
+    ("first", calibration.map_or_else(null, |c| token(c.first))),
+    ("last", calibration.map_or_else(null, |c| token(c.last))),
+    (
+      "value",
+      calibration.map_or_else(null, |c| c.value().to_string()),
+    ),
+  ])
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::lib::vocabulary::Vocabulary;
+
+  fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+  }
+
+  #[test]
+  fn it_should_tabulate_every_line() {
+    let scanner = Scanner::new(Vocabulary::load("english", false).unwrap());
+    let report = report(&scanner, &lines("two1nine\nabc\n7"), Format::Text);
+
+    let expected = [
+      r#"line  first       last         value"#,
+      r#"   1  "two" at 0  "nine" at 4     29"#,
+      r#"   2  -           -                -"#,
+      r#"   3  "7" at 0    "7" at 0        77"#,
+    ];
+    assert_eq!(report, expected.join("\n") + "\n");
+  }
+
+  #[test]
+  fn it_should_write_a_json_object_per_line() {
+    let scanner = Scanner::new(Vocabulary::numeric());
+    let report = report(&scanner, &lines("eightwo3\nnone"), Format::Json);
+
+    let expected = [
+      concat!(
+        r#"{"line":1,"first":{"token":"3","offset":7,"value":3},"#,
+        r#""last":{"token":"3","offset":7,"value":3},"value":33}"#
+      ),
+      r#"{"line":2,"first":null,"last":null,"value":null}"#,
+    ];
+    assert_eq!(report, expected.join("\n") + "\n");
+  }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+
+use super::vocabulary::Vocabulary;
+
+/* Token - a digit found in a line: the text it was written as, the byte
+offset it starts at and the value it stands for */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+  pub text: &'a str,
+  pub offset: usize,
+  pub value: u32,
+}
+
+/* Calibration - the first and last digits of a line, which may be the same
+token when the line holds only one */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Calibration<'a> {
+  pub first: Token<'a>,
+  pub last: Token<'a>,
+}
+
+/* Scanner - finds the digits of a line in a single pass, trying every token
+of its vocabulary at every offset. Tokens may overlap, as in `eightwo`: each
+is found where it starts, whatever the previous one used up. */
+#[derive(Clone, Debug)]
+pub struct Scanner {
+  vocabulary: Vocabulary,
+}
+
+impl Scanner {
+  pub fn new(vocabulary: Vocabulary) -> Self {
+    Scanner { vocabulary }
+  }
+
+  /* tokens - every digit in `line`, in order, overlapping ones included.
+  When two tokens start at the same offset, the longer one is taken. */
+  pub fn tokens<'s, 'a: 's>(
+    &'s self,
+    line: &'a str,
+  ) -> impl Iterator<Item = Token<'a>> + 's {
+    (0..line.len()).filter_map(move |offset| self.token_at(line, offset))
+  }
+
+  /* calibrate - the first and last digits of `line`, if it has any */
+  pub fn calibrate<'a>(&self, line: &'a str) -> Option<Calibration<'a>> {
+    self.tokens(line).fold(None, |calibration, token| {
+      Some(match calibration {
+        None => Calibration { first: token, last: token },
+        Some(calibration) => Calibration { last: token, ..calibration },
+      })
+    })
+  }
+
+  fn token_at<'a>(&self, line: &'a str, offset: usize) -> Option<Token<'a>> {
+    let rest = line.get(offset..)?;
+    self
+      .vocabulary
+      .tokens()
+      .filter(|(text, _)| self.vocabulary.starts(rest, text))
+      .max_by_key(|(text, _)| text.len())
+      .map(|(text, value)| Token { text: &rest[..text.len()], offset, value })
+  }
+}
+
+impl Calibration<'_> {
+  /* value - the two-digit number the first and last digits make */
+  pub fn value(&self) -> u32 {
+    self.first.value * 10 + self.last.value
+  }
+}
+
+impl fmt::Display for Token<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?} at {}", self.text, self.offset)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_find_overlapping_tokens_in_one_pass() {
+    let scanner = Scanner::new(Vocabulary::load("english", false).unwrap());
+    let tokens: Vec<_> = scanner.tokens("xeightwo3").collect();
+    assert_eq!(
+      tokens,
+      [
+        Token { text: "eight", offset: 1, value: 8 },
+        Token { text: "two", offset: 5, value: 2 },
+        Token { text: "3", offset: 8, value: 3 },
+      ]
+    );
+
+    let calibration = scanner.calibrate("7pqrstsixteen").unwrap();
+    assert_eq!(calibration.first.to_string(), "\"7\" at 0");
+    assert_eq!(calibration.last.to_string(), "\"six\" at 6");
+    assert_eq!(calibration.value(), 76);
+
+    let vocabulary = Vocabulary::load("italian", true).unwrap();
+    let calibration = Scanner::new(vocabulary).calibrate("x1ZEROtt").unwrap();
+    assert_eq!(calibration.last.to_string(), "\"ZERO\" at 2");
+    assert_eq!(calibration.value(), 10);
+  }
+
+  #[test]
+  fn it_should_read_numeric_digits_alone_in_part_one() {
+    let scanner = Scanner::new(Vocabulary::numeric());
+    let calibration = scanner.calibrate("two1nine").unwrap();
+    assert_eq!(calibration.first, calibration.last);
+    assert_eq!(calibration.value(), 11);
+    assert_eq!(scanner.calibrate("eightwothree"), None);
+    assert_eq!(scanner.calibrate(""), None);
+  }
+}
@@ -0,0 +1,268 @@
+use std::fs;
+
+use advent::{Error, ErrorKind};
+
+
+// the digits every vocabulary reads, whatever words it adds
+const DIGITS: [(&str, u32); 9] = [
+  ("1", 1),
+  ("2", 2),
+  ("3", 3),
+  ("4", 4),
+  ("5", 5),
+  ("6", 6),
+  ("7", 7),
+  ("8", 8),
+  ("9", 9),
+];
+
+// the built-in vocabularies, by name; the puzzle's is the first
+const BUILTIN: [(&str, &[&str]); 4] = [
+  (
+    "english",
+    &[
+      "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ],
+  ),
+  (
+    "spanish",
+    &[
+      "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho",
+      "nueve",
+    ],
+  ),
+  (
+    "italian",
+    &[
+      "zero", "uno", "due", "tre", "quattro", "cinque", "sei", "sette", "otto",
+      "nove",
+    ],
+  ),
+  (
+    "dutch",
+    &[
+      "nul", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht",
+      "negen",
+    ],
+  ),
+];
+
+/* Vocabulary - the tokens a line's digits may be written as, each with the
+value it stands for, on top of the numeric digits. Matching ignores case when
+`case_insensitive` is set. No two tokens may stand for different values, but
+one may start another, in which case the longer one wins. */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vocabulary {
+  pub name: String,
+  pub case_insensitive: bool,
+  tokens: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+  /* numeric - the vocabulary of part 1, the numeric digits alone */
+  pub fn numeric() -> Vocabulary {
+    Vocabulary {
+      name: "digits".to_string(),
+      case_insensitive: false,
+      tokens: Vec::new(),
+    }
+  }
+
+  /* builtin - the built-in vocabulary called `name`. Those other than the
+  puzzle's spell out zero too. */
+  pub fn builtin(name: &str, case_insensitive: bool) -> Option<Vocabulary> {
+    let (name, words) = BUILTIN.iter().find(|(n, _)| *n == name)?;
+    let first = if words.len() == 10 { 0 } else { 1 };
+    let words = words.iter().map(|w| w.to_string()).zip(first..);
+
+    Some(Vocabulary {
+      name: name.to_string(),
+      case_insensitive,
+      tokens: words.collect(),
+    })
+  }
+
+  /* load - the built-in vocabulary called `spec`, or else the vocabulary
+  file at that path */
+  pub fn load(spec: &str, case_insensitive: bool) -> Result<Vocabulary, Error> {
+    if let Some(vocabulary) = Vocabulary::builtin(spec, case_insensitive) {
+      return Ok(vocabulary);
+    }
+    let text = fs::read_to_string(spec).map_err(|e| {
+      let names: Vec<_> = BUILTIN.iter().map(|(name, _)| *name).collect();
+      Error::new(
+        ErrorKind::Input,
+        format!(
+          "'{spec}' is neither a built-in vocabulary ({}) nor a readable \
+           file: {e}",
+          names.join(", ")
+        ),
+      )
+    })?;
+
+    Vocabulary::parse(spec, &text, case_insensitive)
+  }
+
+  /* parse - a vocabulary file, named `name`: one `<token> = <value>` per
+  line, skipping blank lines and `#` comments. A token is printable ASCII, as
+  the lines it is found in are, and a value a single digit. */
+  pub fn parse(
+    name: &str,
+    text: &str,
+    case_insensitive: bool,
+  ) -> Result<Vocabulary, Error> {
+    let mut vocabulary = Vocabulary {
+      name: name.to_string(),
+      case_insensitive,
+      tokens: Vec::new(),
+    };
+    for (y, line) in text.lines().enumerate() {
+      let content = line.split('#').next().unwrap_or_default().trim();
+      if content.is_empty() {
+        continue;
+      }
+      let Some((token, value)) = content.split_once('=') else {
+        return Err(
+          Error::syntax(format!("{name}: expected '<token> = <value>'"))
+            .on_line(y + 1, line),
+        );
+      };
+      let (token, value) = (token.trim(), value.trim());
+      if token.is_empty() || !token.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(
+          Error::value(format!("{name}: a token is printable ASCII"))
+            .near(token)
+            .on_line(y + 1, line),
+        );
+      }
+      let value = match value.parse::<u32>() {
+        Ok(value) if value <= 9 => value,
+        _ => {
+          return Err(
+            Error::value(format!("{name}: a value is a digit, 0 to 9"))
+              .near(value)
+              .on_line(y + 1, line),
+          );
+        }
+      };
+      vocabulary
+        .check(token, value)
+        .map_err(|e| e.on_line(y + 1, line))?;
+      vocabulary.tokens.push((token.to_string(), value));
+    }
+
+    Ok(vocabulary)
+  }
+
+  /* tokens - every token to look for: the numeric digits, then the words
+  of the vocabulary */
+  pub fn tokens(&self) -> impl Iterator<Item = (&str, u32)> {
+    let words = self
+      .tokens
+      .iter()
+      .map(|(token, value)| (&token[..], *value));
+    DIGITS.into_iter().chain(words)
+  }
+
+  /* ambiguities - the pairs of tokens where the first appears within the
+  second and they stand for different values. Where both start at the same
+  offset the longer is read, but one found further in is read as a digit of
+  its own, as `even` would be in `seven`. */
+  pub fn ambiguities(&self) -> Vec<(&str, &str)> {
+    let mut pairs = Vec::new();
+    for (short, a) in self.tokens() {
+      for (long, b) in self.tokens() {
+        if a != b && long.len() > short.len() && self.contains(long, short) {
+          pairs.push((short, long));
+        }
+      }
+    }
+
+    pairs
+  }
+
+  /* contains - whether `token` appears anywhere in `text`, as this
+  vocabulary matches */
+  fn contains(&self, text: &str, token: &str) -> bool {
+    (0..text.len())
+      .filter_map(|offset| text.get(offset..))
+      .any(|rest| self.starts(rest, token))
+  }
+
+  /* starts - whether `text` starts with `token`, as this vocabulary
+  matches */
+  pub fn starts(&self, text: &str, token: &str) -> bool {
+    match text.get(..token.len()) {
+      Some(start) if self.case_insensitive => start.eq_ignore_ascii_case(token),
+      Some(start) => start == token,
+      None => false,
+    }
+  }
+
+  /* check - refuses `token` when it is already read as another value */
+  fn check(&self, token: &str, value: u32) -> Result<(), Error> {
+    match self.tokens().find(|(known, _)| {
+      known.len() == token.len() && self.starts(known, token)
+    }) {
+      Some((known, other)) if other != value => Err(
+        Error::value(format!(
+          "{}: '{token}' stands for {value} but '{known}' already for {other}",
+          self.name
+        ))
+        .near(token),
+      ),
+      Some((known, _)) => Err(
+        Error::value(format!("{}: '{known}' is given twice", self.name))
+          .near(token),
+      ),
+      None => Ok(()),
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_ship_the_builtin_vocabularies() {
+    let english = Vocabulary::load("english", false).unwrap();
+    assert_eq!(english.tokens().count(), 18);
+    assert!(english.tokens().any(|t| t == ("nine", 9)));
+    assert!(english.ambiguities().is_empty());
+
+    let dutch = Vocabulary::load("dutch", true).unwrap();
+    assert!(dutch.tokens().any(|t| t == ("nul", 0)));
+    assert!(dutch.starts("EENtwee", "een"));
+    assert!(!english.starts("ONE", "one"));
+    assert_eq!(
+      Vocabulary::load("klingon", false).unwrap_err().kind,
+      ErrorKind::Input
+    );
+  }
+
+  #[test]
+  fn it_should_report_conflicting_and_ambiguous_tokens() {
+    let text = "# a log's own words\nnil = 0\nnill = 1\nONE = 1\n";
+    let vocabulary = Vocabulary::parse("log.txt", text, false).unwrap();
+    assert_eq!(vocabulary.ambiguities(), [("nil", "nill")]);
+    let text = "seven = 7\neven = 2\nTWO = 2\nTWOFOLD = 2\n";
+    let vocabulary = Vocabulary::parse("log.txt", text, false).unwrap();
+    assert_eq!(vocabulary.ambiguities(), [("even", "seven")]);
+    let text = "sev7n = 7\n";
+    let vocabulary = Vocabulary::parse("log.txt", text, false).unwrap();
+    assert_eq!(vocabulary.ambiguities(), []);
+
+    let conflict = "one = 1\nOne = 2\n";
+    assert!(Vocabulary::parse("log.txt", conflict, false).is_ok());
+    let error = Vocabulary::parse("log.txt", conflict, true).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position.map(|p| p.line), Some(2));
+    let error = Vocabulary::parse("log.txt", "7 = 8\n", false).unwrap_err();
+    assert!(error.message.contains("'7' already for 7"), "{error}");
+    for malformed in ["one 1", "one = 10", "één = 1", "= 1"] {
+      assert!(Vocabulary::parse("log.txt", malformed, false).is_err());
+    }
+  }
+}
@@ -0,0 +1,463 @@
+use advent::{Cell, Check, Error, Part, Renderer, Rgb, Solution};
+use grid::{Coord, Direction, Grid, Neighbourhood};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(feature = "sample")]
+const PART2_DATA_PATH: &str =
+  concat!(env!("CARGO_MANIFEST_DIR"), "/sample_3.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+#[cfg(not(feature = "sample"))]
+const PART2_DATA_PATH: &str = DATA_PATH;
+
+/* connections - the directions a pipe tile leads in */
+fn connections(tile: char) -> &'static [Direction] {
+  match tile {
+    '|' => &[Direction::North, Direction::South],
+    '-' => &[Direction::East, Direction::West],
+    'L' => &[Direction::North, Direction::East],
+    'J' => &[Direction::North, Direction::West],
+    '7' => &[Direction::South, Direction::West],
+    'F' => &[Direction::South, Direction::East],
+    _ => &[],
+  }
+}
+
+/* connected - the coords the pipe at `coord` leads to on the board */
+fn connected(
+  board: &Grid<char>,
+  coord: Coord,
+) -> impl Iterator<Item = Coord> + '_ {
+  connections(board[coord])
+    .iter()
+    .filter_map(move |&direction| board.step(coord, direction))
+}
+
+type Consequent = usize;
+
+pub struct ProblemDefinition {
+  board: Grid<char>,
+  start: Coord,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 10;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(part)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    part: Part,
+  ) -> Result<(), Error> {
+    load(result, part)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, _part: Part) -> Vec<Check> {
+    check(data)
+  }
+
+  fn render(
+    data: &Self::ProblemDefinition,
+    part: Part,
+    renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    render(data, part, renderer)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider(part: Part) -> Result<String, Error> {
+  advent::input::read(match part {
+    Part::One => DATA_PATH,
+    Part::Two => PART2_DATA_PATH,
+  })
+}
+
+/* resolve_start_character - the pipe hidden under the start tile, which
+connects to exactly the two neighbours leading back into it */
+fn resolve_start_character(board: &Grid<char>, start: Coord) -> Option<char> {
+  let leads_back = |&direction: &Direction| {
+    board
+      .step(start, direction)
+      .is_some_and(|n| connections(board[n]).contains(&direction.opposite()))
+  };
+  let directions: Vec<Direction> =
+    Direction::ALL.into_iter().filter(leads_back).collect();
+
+  "|-LJ7F".chars().find(|&tile| {
+    let pipe = connections(tile);
+    pipe.len() == directions.len()
+      && pipe.iter().all(|d| directions.contains(d))
+  })
+}
+
+fn extract(part: Part) -> Result<ProblemDefinition, Error> {
+  let src = src_provider(part)?;
+  let mut board = Grid::parse(&src, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+
+  let row = |y: usize| src.lines().nth(y).unwrap_or_default();
+  let starts: Vec<Coord> = board
+    .iter()
+    .filter(|&(_, &c)| c == 'S')
+    .map(|(c, _)| c)
+    .collect();
+  let Some(&start) = starts.first() else {
+    return Err(Error::missing("no start tile 'S'"));
+  };
+  if let Some(other) = starts.get(1) {
+    return Err(
+      Error::value("more than one start tile")
+        .at(other.y + 1, other.x + 1)
+        .snippet(row(other.y)),
+    );
+  }
+  let Some(c) = resolve_start_character(&board, start) else {
+    return Err(
+      Error::value("no pipe fits the start tile")
+        .at(start.y + 1, start.x + 1)
+        .snippet(row(start.y)),
+    );
+  };
+  board[start] = c;
+
+  Ok(ProblemDefinition { board, start })
+}
+
+/* traverse - the cells that are neither on the loop nor reachable from the
+edge of the board without crossing it */
+fn traverse(on_loop: &Grid<bool>) -> HashSet<Coord> {
+  let (width, height) = (on_loop.width(), on_loop.height());
+  let is_at_edge =
+    |c: &Coord| c.x == 0 || c.y == 0 || c.x == width - 1 || c.y == height - 1;
+  let mut outside: HashSet<Coord> = on_loop
+    .coords()
+    .filter(|c| !on_loop[*c] && is_at_edge(c))
+    .collect();
+
+  let mut queue: VecDeque<_> = outside.iter().copied().collect();
+  while let Some(curr) = queue.pop_front() {
+    for neighbor in on_loop.neighbours(curr, Neighbourhood::Eight) {
+      if !on_loop[neighbor] && outside.insert(neighbor) {
+        queue.push_back(neighbor);
+      }
+    }
+  }
+
+  on_loop
+    .coords()
+    .filter(|c| !on_loop[*c] && !outside.contains(c))
+    .collect()
+}
+
+/* check - both parts follow the pipes from the start as a loop; one that
+leads off the board, or into a pipe that does not lead back, leaves an open
+outline with no far point and no inside */
+fn check(data: &ProblemDefinition) -> Vec<Check> {
+  let board = &data.board;
+  let mut seen = HashSet::from([data.start]);
+  let mut queue = VecDeque::from([data.start]);
+  let mut open = None;
+  while let Some(coord) = queue.pop_front() {
+    let next: Vec<Coord> = connected(board, coord).collect();
+    let leads_back = |n: &Coord| connected(board, *n).any(|c| c == coord);
+    if next.len() != 2 || !next.iter().all(leads_back) {
+      open = Some(coord);
+      break;
+    }
+    queue.extend(next.into_iter().filter(|&n| seen.insert(n)));
+  }
+
+  vec![Check::of(
+    "the pipes from the start close into a loop",
+    open.map_or(Ok(()), |c| {
+      Err(format!(
+        "the pipe at line {}, column {} is open",
+        c.y + 1,
+        c.x + 1
+      ))
+    }),
+  )]
+}
+
+/* distances - how many steps along the pipes each tile of the loop is from
+the start */
+fn distances(data: &ProblemDefinition) -> HashMap<Coord, usize> {
+  let mut distances = HashMap::from([(data.start, 0)]);
+  let mut queue = VecDeque::from([data.start]);
+  while let Some(coord) = queue.pop_front() {
+    let step = distances[&coord] + 1;
+    for c in connected(&data.board, coord) {
+      if let Entry::Vacant(entry) = distances.entry(c) {
+        entry.insert(step);
+        queue.push_back(c);
+      }
+    }
+  }
+
+  distances
+}
+
+/* enclosed - the tiles inside the loop, squeezing between pipes included */
+fn enclosed(board: &Grid<char>, on_loop: &HashSet<Coord>) -> HashSet<Coord> {
+  let height = board.height();
+  let width = board.width();
+
+  // We will expand the grid, so it now contains a virtual coord between/beside each coord
+
+  // Adjust loop coordinates to include virtual '.' cells
+  let mut new_largest_loop = Grid::new(width * 2 + 1, height * 2 + 1, false);
+  for original_coord in on_loop {
+    let coord =
+      Coord { y: original_coord.y * 2 + 1, x: original_coord.x * 2 + 1 };
+    new_largest_loop[coord] = true; // Add original loop coordinates
+
+    connected(board, *original_coord).for_each(|oc| {
+      let c = Coord { y: oc.y * 2 + 1, x: oc.x * 2 + 1 };
+      let space_between =
+        Coord { y: (c.y + coord.y) / 2, x: (c.x + coord.x) / 2 };
+      new_largest_loop[space_between] = true;
+    });
+  }
+  advent::debug!(
+    "loop of {} tiles, {} on the enlarged board",
+    on_loop.len(),
+    new_largest_loop
+      .iter()
+      .filter(|(_, &on_loop)| on_loop)
+      .count()
+  );
+
+  // find all points in the larger board that are inside
+  let enlarged_inside = traverse(&new_largest_loop);
+  enlarged_inside
+    .iter()
+    .filter(|coord| coord.x % 2 != 0 && coord.y % 2 != 0) // remove the virtual coords
+    .map(|c| Coord { y: (c.y - 1) / 2, x: (c.x - 1) / 2 }) // get the original coordinate
+    .collect::<HashSet<_>>()
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  let distances = distances(&data);
+  if distances.len() == 1 {
+    return Err(Error::no_solution("no loop from the start tile"));
+  }
+  match part {
+    Part::One => Ok(distances.values().copied().max().unwrap_or_default()),
+    Part::Two => {
+      let on_loop: HashSet<Coord> = distances.into_keys().collect();
+
+      Ok(enclosed(&data.board, &on_loop).len())
+    }
+  }
+}
+
+/* render - the loop in box-drawing pipes as it is followed out from the
+start, a step a frame, then the farthest tiles (part 1) or the tiles inside
+(part 2). Pipes off the loop are dimmed. */
+fn render(
+  data: &ProblemDefinition,
+  part: Part,
+  renderer: &mut Renderer,
+) -> Result<(), Error> {
+  let distances = distances(data);
+  let farthest = distances.values().copied().max().unwrap_or_default();
+  let picture = |reached: usize| {
+    data.board.frame(|c, &tile| match distances.get(&c) {
+      Some(0) => Cell::new('S', Rgb::RED),
+      Some(&d) if d <= reached => Cell::new(pipe(tile), Rgb::YELLOW),
+      _ => Cell::new(tile, Rgb::DARK),
+    })
+  };
+  if renderer.animating() {
+    for reached in 0..farthest {
+      renderer.frame(picture(reached))?;
+    }
+  }
+
+  let mut frame = picture(farthest);
+  let highlighted: Vec<Coord> = match part {
+    Part::One => distances
+      .iter()
+      .filter(|&(_, &d)| d == farthest)
+      .map(|(&c, _)| c)
+      .collect(),
+    Part::Two => {
+      let on_loop: HashSet<Coord> = distances.keys().copied().collect();
+      enclosed(&data.board, &on_loop).into_iter().collect()
+    }
+  };
+  for c in highlighted {
+    frame.set(c.x, c.y, Cell::new('█', Rgb::GREEN));
+  }
+
+  renderer.frame(frame)
+}
+
+/* pipe - a pipe tile as the box-drawing character it stands for */
+fn pipe(tile: char) -> char {
+  match tile {
+    '|' => '│',
+    '-' => '─',
+    'L' => '└',
+    'J' => '┘',
+    '7' => '┐',
+    'F' => '┌',
+    other => other,
+  }
+}
+
+fn load(result: Result<usize, Error>, part: Part) -> Result<(), Error> {
+  match result {
+    Ok(value) => match part {
+      Part::One => println!("{value} steps"),
+      Part::Two => println!("{value} inside"),
+    },
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::render::Target;
+  use advent::{ErrorKind, Frame};
+  use std::num::NonZeroU32;
+
+  const SAMPLE: &str = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...";
+  const SAMPLE_3: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+  const MALFORMED: [&str; 5] = [
+    "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ.",
+    "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ.S.",
+    "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ.#.",
+    "..F7.\n.FJ|.\n.J.L7\n|F--J\nLJ...",
+    "..F7.\n.FJ|.\nS..L7\n.F--J\nLJ...",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_resolve_the_start_pipe() {
+    mock_src_provider(mry::Any).returns(Ok(SAMPLE.to_string()));
+
+    let data = extract(Part::One).unwrap();
+    assert_eq!(data.start, Coord::new(0, 2));
+    assert_eq!(data.board[data.start], 'F');
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_report_a_missing_start() {
+    mock_src_provider(mry::Any).returns(Ok("-L|F7\n7.-7|".to_string()));
+
+    let error = extract(Part::One).err().unwrap();
+    assert_eq!(error.kind, ErrorKind::Missing);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider(mry::Any)
+      .returns_with(move |_| Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract(Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_find_the_farthest_point_and_the_enclosed_tiles() {
+    mock_src_provider(Part::One).returns(Ok(SAMPLE.to_string()));
+    mock_src_provider(Part::Two).returns(Ok(SAMPLE_3.to_string()));
+
+    assert_eq!(transform(extract(Part::One).unwrap(), Part::One), Ok(8));
+    assert_eq!(transform(extract(Part::Two).unwrap(), Part::Two), Ok(4));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_the_pipes_close_into_a_loop() -> Result<(), Error> {
+    mock_src_provider(Part::One).returns(Ok(SAMPLE.to_string()));
+    mock_src_provider(Part::Two).returns(Ok("S-7\n|.|\nL-.".to_string()));
+
+    assert!(check(&extract(Part::One)?)[0].holds());
+    assert_eq!(
+      check(&extract(Part::Two)?)[0].violation.as_deref(),
+      Some("the pipe at line 3, column 2 is open")
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_draw_the_loop_growing_then_its_inside() -> Result<(), Error> {
+    mock_src_provider(Part::Two).returns(Ok(SAMPLE_3.to_string()));
+    let mut renderer = Renderer::new(Target::Memory, NonZeroU32::new(1000));
+    render(&extract(Part::Two)?, Part::Two, &mut renderer)?;
+    let frames = renderer.frames();
+    let count = |frame: &Frame, glyph| {
+      (0..frame.height())
+        .flat_map(|y| (0..frame.width()).map(move |x| (x, y)))
+        .filter(|&(x, y)| frame.get(x, y).map(|c| c.glyph) == Some(glyph))
+        .count()
+    };
+
+    // a frame a step out to the far side of the 46-pipe loop, then the last
+    assert_eq!(frames.len(), 24);
+    assert_eq!(count(&frames[0], 'S'), 1);
+    assert_eq!(count(&frames[0], '─'), 0);
+    assert_eq!(count(&frames[23], '█'), 4);
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider(mry::Any).returns_with(move |_| input.input());
+
+    cases::assert_solves::<Day10>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,219 @@
+use advent::{params, Error, Part, Solution, Total};
+use grid::{Coord, Grid};
+use itertools::Itertools;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+// part 2's expansion unless `hubble_constant` in the parameters says otherwise
+const HUBBLE_CONSTANT: usize = 999_999;
+
+type ProblemDefinition = Grid<bool>;
+type Consequent = Vec<Total>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 11;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(&data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<Total>().to_string())
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  Grid::parse(&src_provider()?, |c| match c {
+    '.' => Some(false),
+    '#' => Some(true),
+    _ => None,
+  })
+}
+
+fn get_expanding_rows(data: &ProblemDefinition) -> Vec<usize> {
+  data
+    .rows()
+    .enumerate()
+    .filter_map(|(y, row)| row.iter().all(|item| !item).then_some(y))
+    .collect()
+}
+
+fn get_expanding_cols(data: &ProblemDefinition) -> Vec<usize> {
+  (0..data.width())
+    .filter(|&x| data.column(x).all(|item| !item))
+    .collect()
+}
+
+fn count_items_between(indices: &[usize], left: usize, right: usize) -> usize {
+  let (lower, higher) = if left < right {
+    (left, right)
+  } else {
+    (right, left)
+  };
+
+  indices
+    .iter()
+    .filter(|&&index| index > lower && index < higher)
+    .count()
+}
+
+/* the image is never expanded; each empty row or column between two stars
+adds the width it would have grown by (one in part 1, the hubble constant in
+part 2) to their distance */
+fn transform(
+  data: &ProblemDefinition,
+  part: Part,
+) -> Result<Consequent, Error> {
+  let expansion = match part {
+    Part::One => 1,
+    Part::Two => params::load(PARAMS_PATH, &["hubble_constant"])?
+      .get("hubble_constant", HUBBLE_CONSTANT)?,
+  };
+  let stars: Vec<Coord> = data
+    .iter()
+    .filter(|(_, &star)| star)
+    .map(|(c, _)| c)
+    .collect();
+  let expanding_rows = get_expanding_rows(data);
+  let expanding_cols = get_expanding_cols(data);
+  advent::debug!(
+    "{} stars; empty rows {expanding_rows:?} and columns {expanding_cols:?} \
+     each add {expansion}",
+    stars.len()
+  );
+  let distances = stars
+    .into_iter()
+    .combinations(2)
+    .map(|v| {
+      let rows_between = count_items_between(&expanding_rows, v[0].y, v[1].y);
+      let cols_between = count_items_between(&expanding_cols, v[0].x, v[1].x);
+      let empty = Total::from(rows_between + cols_between);
+      empty * expansion + v[0].manhattan(v[1])
+    })
+    .collect();
+
+  Ok(distances)
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(items) => {
+      println!("{} steps", items.iter().sum::<Total>());
+    }
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+  const MALFORMED: [&str; 3] = [
+    "...#......\n.......#..\n#...",
+    "...#......\n\n#.........",
+    "...#......\n.......#.@",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_the_image() {
+    mock_src_provider().returns(Ok(".#\n..".to_string()));
+
+    assert_eq!(
+      extract(),
+      Grid::from_rows(vec![vec![false, true], vec![false, false]])
+    );
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_an_unknown_tile() {
+    mock_src_provider().returns(Ok(".#\n.*".to_string()));
+
+    let error = extract().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 2 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_sum_distances_in_the_expanded_universe() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let distances = transform(&extract().unwrap(), Part::One).unwrap();
+    assert_eq!(distances.iter().sum::<Total>(), 374);
+    let distances = transform(&extract().unwrap(), Part::Two).unwrap();
+    assert_eq!(distances.iter().sum::<Total>(), 82000210);
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day11>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
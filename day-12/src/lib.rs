@@ -0,0 +1,320 @@
+use advent::input::{self, Lines};
+use advent::{parallel, Error, Part, Solution, Total};
+use std::collections::HashMap;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+type ProblemDefinition = Vec<(Vec<u8>, Vec<usize>)>;
+type Consequent = Vec<Total>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 12;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<Total>().to_string())
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(DATA_PATH)?, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  src_provider()?
+    .lines()
+    .enumerate()
+    .map(|(i, input_string)| parse_row(i + 1, input_string))
+    .collect()
+}
+
+fn parse_row(
+  number: usize,
+  input_string: &str,
+) -> Result<(Vec<u8>, Vec<usize>), Error> {
+  let (prefix, suffix) = input_string
+    .split_once(' ')
+    .ok_or(Error::syntax("expected '<springs> <groups>'"))
+    .map_err(|e| e.on_line(number, input_string))?;
+  if let Some(x) = prefix.find(|c| !matches!(c, '.' | '#' | '?')) {
+    return Err(
+      Error::value("springs are '.', '#' or '?'")
+        .at(number, prefix[..x].chars().count() + 1)
+        .snippet(input_string),
+    );
+  }
+  let points = prefix.as_bytes().to_vec();
+  let mut constraints = Vec::new();
+  // groups start after the springs and the space between them
+  let mut column = prefix.len() + 2;
+  for n in suffix.split(',') {
+    let invalid = |reason: String| {
+      Error::value(format!("invalid group size '{n}': {reason}"))
+        .at(number, column)
+        .snippet(input_string)
+    };
+    let constraint = match n.parse::<usize>() {
+      Ok(0) => return Err(invalid("groups are never empty".to_string())),
+      Ok(size) => size,
+      Err(e) => return Err(invalid(e.to_string())),
+    };
+    constraints.push(constraint);
+    column += n.len() + 1;
+  }
+
+  Ok((points, constraints))
+}
+
+/* stream - each row's arrangements counted as soon as it is read, keeping
+only the running sum */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let mut sum = Total::ZERO;
+
+  for (i, line) in lines.enumerate() {
+    let line = line?;
+    let (points, constraints) = parse_row(i + 1, &line)?;
+    sum += solve(&points, &constraints, part);
+  }
+
+  Ok(sum.to_string())
+}
+
+/* solve - create a cache and prefix sum of elements up to known counts of
+broken springs and start a recursive search. In part 2 the row is unfolded to
+five copies joined by '?' first, after which the count of arrangements can
+outgrow 64 bits. */
+fn solve(points: &[u8], constraints: &[usize], part: Part) -> Total {
+  let mut springs = Vec::new();
+  let mut consecutive_broken = Vec::new();
+  if part == Part::Two {
+    for _ in 0..4 {
+      springs.extend_from_slice(points);
+      springs.push(b'?');
+      consecutive_broken.extend_from_slice(constraints);
+    }
+  }
+
+  springs.extend(points);
+  springs.push(b'.');
+
+  consecutive_broken.extend(constraints);
+
+  /* calculate the sum of all elements from index `0` up to `i` in
+  consecutive_broken. */
+  let mut sum = 0;
+  let mut ps = vec![0; consecutive_broken.len()];
+  for i in (1..consecutive_broken.len()).rev() {
+    sum += consecutive_broken[i] + 1;
+    ps[i - 1] = sum;
+  }
+
+  let mut cache = HashMap::new();
+
+  recurse(&springs, &consecutive_broken, &ps, &mut cache)
+}
+
+/* recurse - checks all possible positions from which a new segment can start.
+If this position does not have any broken elements or the next element is
+marked as working ('.'), then it makes a recursive call for the remaining string
+and counts (skipping the current count). */
+fn recurse(
+  springs: &[u8],
+  consecutive_broken: &[usize],
+  ps: &[usize],
+  cache: &mut HashMap<(usize, usize), Total>,
+) -> Total {
+  /* Create a key for this specific slice and counts. This will be used to store
+  and retrieve computed results in the cache map. */
+  let key = (springs.len(), consecutive_broken.len());
+  // Check if we've already calculated the result for this combination of
+  // springs and consecutive_broken, i.e., return it from cache if available.
+  if let Some(prev) = cache.get(&key) {
+    return prev.clone();
+  }
+
+  /* If there are no counts left in 'consecutive_broken', check if all elements
+  in slice are working. If so it was satisfied. Cache success or failure and
+  return result */
+  if consecutive_broken.is_empty() {
+    let satisfied = springs.iter().all(|&b| b == b'.' || b == b'?');
+    let result = Total::from(satisfied as u8);
+    cache.insert(key, result.clone());
+    return result;
+  }
+
+  // 'size' is the current count of consecutive broken elements to look for.
+  let size = consecutive_broken[0];
+  /* The wiggle room is the maximum position we can slide to next in 'slice',
+  considering the remaining counts in 'consecutive_broken'. */
+  let mut result = Total::ZERO;
+  // groups that cannot fit in what is left have no arrangement at all
+  let Some(wiggle) = springs.len().checked_sub(ps[0] + size) else {
+    cache.insert(key, result.clone());
+    return result;
+  };
+
+  for offset in 0..wiggle {
+    // if we have reached a '#' then we break because the next segment covers it
+    if offset > 0 && springs[offset - 1] == b'#' {
+      break;
+    }
+    /* If the next 'size' elements after 'offset' are broken or '?' and the
+    following element is not, then we have found our match */
+    if springs[offset + size] != b'#'
+      && springs[offset..offset + size]
+        .iter()
+        .all(|&b| b == b'#' || b == b'?')
+    {
+      result += recurse(
+        &springs[offset + size + 1..],
+        &consecutive_broken[1..],
+        &ps[1..],
+        cache,
+      );
+    }
+  }
+
+  cache.insert(key, result.clone());
+  result
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  Ok(parallel::map(&data, |(points, constraints)| {
+    solve(points, constraints, part)
+  }))
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(items) => {
+      println!(
+        "{} sum of possible satsifying allocations",
+        items.iter().sum::<Total>()
+      );
+    }
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+  const MALFORMED: [&str; 5] = [
+    "???.### 1,1,3\n.??..??...?##.",
+    "???.### 1,1,3\n.??..??...?##. 1,,3",
+    "???.### 1,1,3\n.??..??...?##. 1,0,3",
+    "???.### 1,1,3\n.??x.??...?##. 1,1,3",
+    "? 18446744073709551616",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_springs_and_groups() {
+    mock_src_provider().returns(Ok("?#. 1,2".to_string()));
+
+    assert_eq!(extract(), Ok(vec![(b"?#.".to_vec(), vec![1, 2])]));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_an_invalid_group_size() {
+    mock_src_provider().returns(Ok("#.#.### 1,1,3\n.#?. 1,x".to_string()));
+
+    let error = extract().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 8 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_count_arrangements_folded_and_unfolded() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let counts = transform(extract().unwrap(), Part::One).unwrap();
+    assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+    let counts = transform(extract().unwrap(), Part::Two).unwrap();
+    assert_eq!(counts.iter().sum::<Total>(), 525152);
+  }
+
+  #[test]
+  fn it_should_stream_the_same_counts() {
+    let stream =
+      |input: &'static str, part| stream(Lines::new(input.as_bytes()), part);
+
+    assert_eq!(stream(SAMPLE, Part::One), Ok("21".to_string()));
+    assert_eq!(stream(SAMPLE, Part::Two), Ok("525152".to_string()));
+    for input in MALFORMED {
+      assert!(stream(input, Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day12>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,340 @@
+use advent::{parallel, Check, Error, Part, Solution};
+use grid::Grid;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct ProblemDefinition {
+  patterns: Vec<Reflection>,
+}
+type Reflection = Grid<bool>;
+type Consequent = Vec<(usize, Symmetry)>;
+
+#[derive(Debug)]
+pub enum Symmetry {
+  Horizontal,
+  Vertical,
+}
+
+
+pub struct Day13;
+
+impl Solution for Day13 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 13;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(summarize(consequent).to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, part: Part) -> Vec<Check> {
+    check(data, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  let src = src_provider()?;
+  let lines: Vec<&str> = src.lines().collect();
+  let mut patterns = Vec::new();
+  let mut first_line = 0;
+  for block in lines.split(|line| line.is_empty()) {
+    if block.is_empty() {
+      return Err(Error::missing("empty pattern").at(first_line + 1, 1));
+    }
+    let pattern = Grid::parse(&block.join("\n"), |c| match c {
+      '#' => Some(true),
+      '.' => Some(false),
+      _ => None,
+    })
+    .map_err(|mut e| {
+      // positions are relative to the pattern, not the whole input
+      if let Some(position) = e.position.as_mut() {
+        position.line += first_line;
+      }
+      e
+    })?;
+    patterns.push(pattern);
+    first_line += block.len() + 1;
+  }
+
+  Ok(ProblemDefinition { patterns })
+}
+
+/* get_reflection_point - the row after which the pattern mirrors. Part 1
+looks for a perfect reflection, part 2 for one with exactly one smudge (a
+single differing cell) */
+fn get_reflection_point(matrix: &Reflection, part: Part) -> Option<usize> {
+  let rows: Vec<&[bool]> = matrix.rows().collect();
+  let nrows = rows.len();
+
+  (0..nrows.saturating_sub(1)).find(|&i| {
+    let mut symmetry_found;
+    let j = i + 1;
+
+    match part {
+      Part::One => {
+        symmetry_found = true;
+        for k in 0..=i {
+          if j + k >= nrows {
+            break;
+          }
+          if rows[i - k].iter().zip(rows[j + k]).any(|(a, b)| a != b) {
+            symmetry_found = false;
+            break;
+          }
+        }
+      }
+      Part::Two => {
+        symmetry_found = false;
+        let mut diff_count = 0;
+
+        for k in 0..=i {
+          if j + k >= nrows {
+            break;
+          }
+          let current_difference = rows[i - k]
+            .iter()
+            .zip(rows[j + k])
+            .fold(0, |acc, (&a, &b)| if a != b { acc + 1 } else { acc });
+          diff_count += current_difference;
+          match diff_count {
+            1 => symmetry_found = true,
+            c if c > 1 => {
+              symmetry_found = false;
+              break;
+            }
+            _ => {} // No action for other cases
+          }
+        }
+      }
+    }
+
+    symmetry_found
+  })
+}
+
+/* a vertical line of reflection is a horizontal one of the transposed
+pattern */
+fn get_inflection(
+  matrix: &Reflection,
+  part: Part,
+) -> Option<(usize, Symmetry)> {
+  if let Some(point) = get_reflection_point(matrix, part) {
+    return Some((point, Symmetry::Horizontal));
+  }
+  if let Some(point) = get_reflection_point(&matrix.transpose(), part) {
+    return Some((point, Symmetry::Vertical));
+  }
+
+  None
+}
+
+/* reflections - how many lines, across and down, the pattern mirrors about:
+perfectly in part 1, and but for a single smudge in part 2 */
+fn reflections(matrix: &Reflection, part: Part) -> usize {
+  let smudges = match part {
+    Part::One => 0,
+    Part::Two => 1,
+  };
+  let count = |matrix: &Reflection| {
+    let rows: Vec<&[bool]> = matrix.rows().collect();
+    (1..rows.len())
+      .filter(|&i| {
+        let pairs = rows[..i].iter().rev().zip(&rows[i..]);
+        let differences: usize = pairs
+          .map(|(a, b)| a.iter().zip(b.iter()).filter(|(a, b)| a != b).count())
+          .sum();
+        differences == smudges
+      })
+      .count()
+  };
+
+  count(matrix) + count(&matrix.transpose())
+}
+
+/* check - the first line of reflection found is the one summarised, which is
+only right when no pattern has another */
+fn check(data: &ProblemDefinition, part: Part) -> Vec<Check> {
+  let ambiguous = data
+    .patterns
+    .iter()
+    .map(|pattern| reflections(pattern, part))
+    .enumerate()
+    .find(|&(_, lines)| lines != 1);
+
+  vec![Check::of(
+    "every pattern has exactly one line of reflection",
+    ambiguous.map_or(Ok(()), |(i, lines)| {
+      Err(format!("pattern {} has {lines}", i + 1))
+    }),
+  )]
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  let inflections =
+    parallel::map(&data.patterns, |pattern| get_inflection(pattern, part));
+
+  let mut results = Vec::new();
+  for (i, inflection) in inflections.into_iter().enumerate() {
+    let Some((inflection_point, symmetry)) = inflection else {
+      return Err(Error::no_solution(format!(
+        "no line of reflection in pattern {}",
+        i + 1
+      )));
+    };
+    results.push((inflection_point, symmetry));
+  }
+
+  Ok(results)
+}
+
+fn summarize(items: &[(usize, Symmetry)]) -> usize {
+  items.iter().fold(0, |acc, (p, symmetry)| match symmetry {
+    Symmetry::Horizontal => acc + 100 * (p + 1),
+    Symmetry::Vertical => acc + p + 1,
+  })
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(items) => {
+      println!("summary: {}", summarize(&items));
+    }
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::Position;
+
+  const SAMPLE: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+  const MALFORMED: [&str; 4] = [
+    "#.##..##.\n..#.##.#.\n##......#\n\n\n#...##..#",
+    "#.##..##.\n..#.##.#.\n##....",
+    "#.##..##.\n..#.##.#.\n##..O...#",
+    "#.##..##.\n..#.##.#.\n\n",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_patterns() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let data = extract().unwrap();
+    assert_eq!(data.patterns.len(), 2);
+    assert_eq!(data.patterns[1].height(), 7);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_position_errors_within_the_whole_input() {
+    mock_src_provider().returns(Ok("#.\n..\n\n#.\n.O".to_string()));
+
+    let error = extract().err().unwrap();
+    assert_eq!(error.position, Some(Position { line: 5, column: 2 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_find_reflections_with_and_without_smudges() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let items = transform(extract().unwrap(), Part::One).unwrap();
+    assert_eq!(summarize(&items), 405);
+    let items = transform(extract().unwrap(), Part::Two).unwrap();
+    assert_eq!(summarize(&items), 400);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_each_pattern_has_one_reflection() -> Result<(), Error> {
+    let mut inputs = [SAMPLE, "##\n##"].into_iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or("").to_string()));
+
+    let data = extract()?;
+    assert!(check(&data, Part::One)[0].holds());
+    assert!(check(&data, Part::Two)[0].holds());
+    assert_eq!(
+      check(&extract()?, Part::One)[0].violation.as_deref(),
+      Some("pattern 1 has 2")
+    );
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day13>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
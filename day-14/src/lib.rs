@@ -0,0 +1,357 @@
+use advent::{
+  params, Cell, Check, Error, Frame, Part, Renderer, Rgb, Solution,
+};
+use grid::{Direction, Grid};
+use indexmap::IndexMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+
+use lib::rotator::prelude::*;
+use lib::rotator::rotate_board;
+mod lib {
+  pub mod rotator;
+}
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+// part 2's tilts (four to a spin cycle) unless `steps` says otherwise
+const STEPS: usize = 4_000_000_000;
+
+// the tilts of a spin cycle, in order
+const CYCLE: [Direction; 4] = [
+  Direction::North,
+  Direction::West,
+  Direction::South,
+  Direction::East,
+];
+// the tilts within which part 2's boards must start to repeat
+const SETTLE_LIMIT: usize = 100_000;
+
+type ProblemDefinition = Grid<u8>;
+type Consequent = usize;
+
+
+pub struct Day14;
+
+impl Solution for Day14 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 14;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, part: Part) -> Vec<Check> {
+    check(data, part)
+  }
+
+  fn render(
+    data: &Self::ProblemDefinition,
+    part: Part,
+    renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    render(data, part, renderer)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  Grid::parse(&src_provider()?, |c| {
+    matches!(c, 'O' | '#' | '.').then_some(c as u8)
+  })
+}
+
+fn score(data: ProblemDefinition) -> usize {
+  let rocks = find_positions(&data, b'O');
+  let len = data.height();
+
+  rocks.iter().fold(0, |acc, rock| acc + len - rock.y)
+}
+
+fn hash_array(array: &Grid<u8>) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  array.hash(&mut hasher);
+  hasher.finish()
+}
+
+/* check - part 2 only reaches its billions of tilts by skipping ahead once
+the boards start to repeat, so they have to within a number of tilts that
+can be played out. Part 1 tilts once. */
+fn check(data: &ProblemDefinition, part: Part) -> Vec<Check> {
+  if part == Part::One {
+    return Vec::new();
+  }
+  let mut board = data.clone();
+  let mut seen = HashSet::new();
+  let mut repeats = false;
+  for step in 0..SETTLE_LIMIT {
+    let direction = CYCLE[step % CYCLE.len()];
+    if !seen.insert((direction, hash_array(&board))) {
+      repeats = true;
+      break;
+    }
+    board = rotate_board(board, direction);
+  }
+
+  vec![Check::that(
+    format!("the boards repeat within {SETTLE_LIMIT} tilts"),
+    repeats,
+    || "they are all different".to_string(),
+  )]
+}
+
+/* steps - part 1 is a single tilt north, part 2 a billion full spin
+cycles */
+fn steps(part: Part) -> Result<usize, Error> {
+  match part {
+    Part::One => Ok(1),
+    Part::Two => Ok(
+      params::load(PARAMS_PATH, &["steps"])?
+        .get("steps", NonZeroUsize::new(STEPS).unwrap())?
+        .get(),
+    ),
+  }
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  Ok(score(tilt(data, steps(part)?)))
+}
+
+/* tilt - the platform after `steps` tilts, north, west, south, east and round
+again, skipping ahead once the boards start to repeat */
+fn tilt(data: ProblemDefinition, steps: usize) -> Grid<u8> {
+  let mut break_point = steps;
+
+  let mut board = data.clone();
+  let mut iterations = 0;
+  let mut memo: IndexMap<(Direction, u64), Grid<u8>> = IndexMap::new();
+  loop {
+    for direction in &CYCLE {
+      // consider memoization
+      let h = hash_array(&board);
+      let key = (*direction, h);
+      if memo.contains_key(&key) {
+        let k = memo.keys().position(|&k| k == key).unwrap();
+        let loop_len = iterations - k;
+        // memo entry i is the board after i + 1 tilts and repeats from entry
+        // k; counting within the loop from there keeps the offset in range
+        // even when `steps` lands on the loop's last tilt
+        let loop_offset = (steps - k - 1) % loop_len;
+        let final_entry = memo.get_index(k + loop_offset).unwrap();
+
+        board = final_entry.1.to_owned();
+        break_point = iterations;
+        break;
+      }
+      // else solve manually
+      board = rotate_board(board, *direction);
+      memo.insert(key, board.clone());
+      iterations += 1;
+      if iterations >= break_point {
+        break;
+      }
+    }
+    if iterations >= break_point {
+      break;
+    }
+  }
+
+  board
+}
+
+/* render - the platform after its last tilt; animating, the platform before
+the first and after every tilt until the boards start to repeat, as `tilt`
+skips ahead from there */
+fn render(
+  data: &ProblemDefinition,
+  part: Part,
+  renderer: &mut Renderer,
+) -> Result<(), Error> {
+  let steps = steps(part)?;
+  if renderer.animating() {
+    let mut board = data.clone();
+    let mut seen = HashSet::new();
+    renderer.frame(picture(&board))?;
+    for step in 0..steps {
+      let direction = CYCLE[step % CYCLE.len()];
+      if !seen.insert((direction, hash_array(&board))) {
+        break;
+      }
+      board = rotate_board(board, direction);
+      renderer.frame(picture(&board))?;
+    }
+    // every tilt was drawn before the boards could repeat
+    if seen.len() == steps {
+      return Ok(());
+    }
+  }
+
+  renderer.frame(picture(&tilt(data.clone(), steps)))
+}
+
+/* picture - round rocks bright on the dark floor, cube rocks grey */
+fn picture(board: &Grid<u8>) -> Frame {
+  board.frame(|_, &tile| match tile {
+    b'O' => Cell::new('O', Rgb::ORANGE),
+    b'#' => Cell::new('#', Rgb::GREY),
+    _ => Cell::new('.', Rgb::DARK),
+  })
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(score) => println!("Score: {score}"),
+    Err(e) => eprintln!("error: {e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::render::Target;
+  use advent::{ErrorKind, Position};
+  use grid::Coord;
+  use std::num::NonZeroU32;
+
+  const SAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+  const MALFORMED: [&str; 3] = [
+    "O....#....\nO.OO#....#\n.....##",
+    "O....#....\nO.OO#....#\n.....##..X",
+    "O....#....\n\nO.OO#....#",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_the_platform() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let data = extract().unwrap();
+    assert_eq!((data.height(), data.width()), (10, 10));
+    assert_eq!(data[Coord::new(0, 0)], b'O');
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_a_ragged_platform() {
+    mock_src_provider().returns(Ok("O.#\n.O\n#..".to_string()));
+
+    let error = extract().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position, Some(Position { line: 2, column: 1 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_score_the_load_after_tilting_and_spinning() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    assert_eq!(transform(extract().unwrap(), Part::One), Ok(136));
+    assert_eq!(transform(extract().unwrap(), Part::Two), Ok(64));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_skip_ahead_to_any_number_of_steps() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract().unwrap();
+    let mut board = data.clone();
+    for steps in 1..=60 {
+      board = rotate_board(board, CYCLE[(steps - 1) % 4]);
+      assert_eq!(tilt(data.clone(), steps), board, "{steps} steps");
+    }
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_that_the_boards_repeat() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract().unwrap();
+
+    assert!(check(&data, Part::One).is_empty());
+    let checks = check(&data, Part::Two);
+    assert_eq!(checks.len(), 1);
+    assert!(checks[0].holds());
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_draw_each_tilt_until_the_boards_repeat() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract().unwrap();
+    let mut renderer = Renderer::new(Target::Memory, NonZeroU32::new(1000));
+    render(&data, Part::One, &mut renderer).unwrap();
+
+    assert_eq!(renderer.frames(), [picture(&data), picture(&tilt(data, 1))]);
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day14>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
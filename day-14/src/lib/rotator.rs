@@ -1,20 +1,12 @@
 pub mod prelude {
-  use ndarray::Array2;
+  use grid::{Coord, Grid};
 
-  #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-  pub enum CardinalDirection {
-    North,
-    East,
-    South,
-    West,
-  }
-
-  pub fn find_positions(arr: &Array2<u8>, target: u8) -> Vec<(usize, usize)> {
+  pub fn find_positions(arr: &Grid<u8>, target: u8) -> Vec<Coord> {
     let mut positions = Vec::new();
 
-    for (index, &value) in arr.indexed_iter() {
+    for (coord, &value) in arr.iter() {
       if value == target {
-        positions.push(index);
+        positions.push(coord);
       }
     }
 
@@ -22,107 +14,51 @@ pub mod prelude {
   }
 }
 
-use ndarray::{s, Array2, Slice};
+use grid::{Coord, Direction, Grid};
+use std::cmp::Reverse;
 
 use prelude::*;
 
 
-fn sort_positions(
-  positions: &mut [(usize, usize)],
-  direction: CardinalDirection,
-) {
+fn sort_positions(positions: &mut [Coord], direction: Direction) {
   match direction {
-    CardinalDirection::North => positions.sort_by(|a, b| a.0.cmp(&b.0)),
-    CardinalDirection::East => positions.sort_by(|a, b| b.1.cmp(&a.1)),
-    CardinalDirection::South => positions.sort_by(|a, b| b.0.cmp(&a.0)),
-    CardinalDirection::West => positions.sort_by(|a, b| a.1.cmp(&b.1)),
+    Direction::North => positions.sort_by_key(|a| a.y),
+    Direction::East => positions.sort_by_key(|a| Reverse(a.x)),
+    Direction::South => positions.sort_by_key(|a| Reverse(a.y)),
+    Direction::West => positions.sort_by_key(|a| a.x),
   }
 }
 
-fn get_slice(
-  board: &Array2<u8>,
-  (y, x): (usize, usize),
-  direction: CardinalDirection,
-) -> Vec<u8> {
-  match direction {
-    CardinalDirection::North => {
-      if y == 0 {
-        vec![]
-      } else {
-        board
-          .slice(s![Slice::from(0..y), x])
-          .iter()
-          .rev()
-          .cloned()
-          .collect()
-      }
-    }
-    CardinalDirection::East => {
-      let slice = Slice::from(x + 1..board.shape()[1]);
-
-      board.slice(s![y, slice]).iter().cloned().collect()
-    }
-    CardinalDirection::South => {
-      let slice = Slice::from(y + 1..board.shape()[0]);
-      board.slice(s![slice, x]).iter().cloned().collect()
-    }
-    CardinalDirection::West => {
-      if x == 0 {
-        Vec::new()
-      } else {
-        board
-          .slice(s![y, Slice::from(0..x)])
-          .iter()
-          .rev()
-          .cloned()
-          .collect()
-      }
+/* roll - where a rock at `from` comes to rest, the last empty cell before a
+wall, another rock or the edge of the board */
+fn roll(board: &Grid<u8>, from: Coord, direction: Direction) -> Coord {
+  let mut at = from;
+  while let Some(next) = board.step(at, direction) {
+    if board[next] != b'.' {
+      break;
     }
+    at = next;
   }
-}
 
-fn get_offset(
-  distance: usize,
-  (y, x): (usize, usize),
-  direction: CardinalDirection,
-) -> Option<(usize, usize)> {
-  match direction {
-    CardinalDirection::North => Some((y - distance, x)),
-    CardinalDirection::East => Some((y, x + distance)),
-    CardinalDirection::South => Some((y + distance, x)),
-    CardinalDirection::West => Some((y, x - distance)),
-  }
+  at
 }
 
-fn move_rock(
-  (y, x): (usize, usize),
-  (new_y, new_x): (usize, usize),
-  board: &mut Array2<u8>,
-) {
-  board[(y, x)] = b'.';
-  board[(new_y, new_x)] = b'O';
+fn move_rock(from: Coord, to: Coord, board: &mut Grid<u8>) {
+  board[from] = b'.';
+  board[to] = b'O';
 }
 
-pub fn rotate_board(
-  board: Array2<u8>,
-  direction: CardinalDirection,
-) -> Option<Array2<u8>> {
+pub fn rotate_board(board: Grid<u8>, direction: Direction) -> Grid<u8> {
   let mut new_board = board.clone();
   let mut rocks = find_positions(&board, b'O');
+  // the rocks nearest the edge settle first, so the others pile up behind
   sort_positions(&mut rocks, direction);
-  for (y, x) in rocks.iter() {
-    let slice = get_slice(&new_board, (*y, *x), direction);
-    let pos = slice
-      .iter()
-      .position(|c| c != &b'.')
-      .unwrap_or(if !slice.is_empty() { slice.len() } else { 0 });
-    if pos > 0 {
-      let Some(new_pos) = get_offset(pos, (*y, *x), direction) else {
-        return None;
-      };
-      move_rock((*y, *x), new_pos, &mut new_board);
+  for rock in rocks {
+    let to = roll(&new_board, rock, direction);
+    if to != rock {
+      move_rock(rock, to, &mut new_board);
     }
   }
 
-  Some(new_board)
+  new_board
 }
@@ -0,0 +1,259 @@
+use advent::{Error, Part, Solution};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+type ProblemDefinition = Vec<String>;
+type Consequent = Vec<usize>;
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 15;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<usize>().to_string())
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  let input = src_provider()?;
+  let mut steps = Vec::new();
+  let mut offset = 0;
+  for word in input.split(',') {
+    let step = word.chars().filter(|c| c != &'\n').collect::<String>();
+    get_operation(&step).map_err(|e| e.at_offset(&input, offset))?;
+    steps.push(step);
+    offset += word.len() + 1;
+  }
+
+  Ok(steps)
+}
+
+/*
+To run the HASH algorithm on a string, start with a current value of 0. Then, for each character in the string starting from the beginning:
+- Determine the ASCII code for the current character of the string.
+- Increase the current value by the ASCII code you just determined.
+- Set the current value to itself multiplied by 17.
+- Set the current value to the remainder of dividing itself by 256.
+*/
+fn get_hash(string: &str) -> usize {
+  string.chars().fold(0, |mut acc, c| {
+    acc += c as usize;
+    acc *= 17;
+    acc %= 256;
+
+    acc
+  })
+}
+
+/*
+The focusing power of a single lens is the result of multiplying together:
+- One plus the box number of the lens in question.
+- The slot number of the lens within the box: 1 for the first lens, 2 for the second lens, and so on.
+- The focal length of the lens.
+*/
+fn get_focal_power(
+  box_number: usize,
+  slot_number: usize,
+  focal_length: usize,
+) -> usize {
+  box_number * slot_number * focal_length
+}
+
+enum Operation {
+  Add((String, u8)),
+  Subtract(String),
+}
+
+/* The label will be immediately followed by a character that indicates the operation to perform: either an equals sign (=) or a dash (-). */
+fn get_operation(word: &str) -> Result<Operation, Error> {
+  let (label, operation) = match (word.split_once('='), word.strip_suffix('-'))
+  {
+    (Some((label, f)), _) => {
+      let Some(focal_length) =
+        f.parse::<u8>().ok().filter(|f| (1..=9).contains(f))
+      else {
+        return Err(
+          Error::value(format!("invalid focal length '{f}'")).snippet(word),
+        );
+      };
+      (label, Operation::Add((label.to_string(), focal_length)))
+    }
+    (None, Some(label)) => (label, Operation::Subtract(label.to_string())),
+    (None, None) => {
+      return Err(
+        Error::syntax("expected '<label>=<focal length>' or '<label>-'")
+          .snippet(word),
+      )
+    }
+  };
+  if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+    return Err(Error::value(format!("invalid label '{label}'")).snippet(word));
+  }
+
+  Ok(operation)
+}
+
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  match part {
+    Part::One => Ok(data.iter().map(|word| get_hash(word)).collect()),
+    Part::Two => {
+      let mut boxes = vec![Vec::<(String, u8)>::new(); 256];
+      for word in data {
+        let result = get_operation(&word);
+        match result {
+          Ok(Operation::Add((label, focal_length))) => {
+            let hash = get_hash(&label);
+            if let Some(i) = boxes[hash].iter().position(|(l, _)| *l == label) {
+              boxes[hash][i] = (label, focal_length);
+            } else {
+              boxes[hash].push((label, focal_length));
+            }
+          }
+          Ok(Operation::Subtract(label)) => {
+            let hash = get_hash(&label);
+            if let Some(item) =
+              boxes[hash].iter().position(|(l, _)| *l == label)
+            {
+              boxes[hash].remove(item);
+            }
+          }
+          Err(e) => {
+            return Err(e);
+          }
+        }
+      }
+
+      Ok(
+        boxes
+          .iter()
+          .enumerate()
+          .map(|(i, bx)| {
+            bx.iter().enumerate().fold(0, |acc, (j, (_, fl))| {
+              acc + get_focal_power(i + 1, j + 1, *fl as usize)
+            })
+          })
+          .collect(),
+      )
+    }
+  }
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(values) => {
+      advent::debug!("values {values:?}");
+      println!("sum {}", values.iter().sum::<usize>());
+    }
+    Err(err) => eprintln!("{err}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::ErrorKind;
+
+  const SAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+  const MALFORMED: [&str; 6] = [
+    "rn=1,cm-,qp=",
+    "rn=1,,qp=3",
+    "rn=1,cm-,qp=10",
+    "rn=1,cm+,qp=3",
+    "rn=1,=4,qp=3",
+    "rn=1,c-m,qp=3",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_steps_without_newlines() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let data = extract().unwrap();
+    assert_eq!(data.len(), 11);
+    assert_eq!(data.last(), Some(&"ot=7".to_string()));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_hash_steps_and_arrange_lenses() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let hashes = transform(extract().unwrap(), Part::One).unwrap();
+    assert_eq!(hashes.iter().sum::<usize>(), 1320);
+    let powers = transform(extract().unwrap(), Part::Two).unwrap();
+    assert_eq!(powers.iter().sum::<usize>(), 145);
+  }
+
+  #[test]
+  fn it_should_reject_an_unknown_operation() {
+    let data = vec!["rn=1".to_string(), "cm*2".to_string()];
+
+    let error = transform(data, Part::Two).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.snippet.as_deref(), Some("cm*2"));
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day15>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,611 @@
+use advent::{parallel, Cell, Error, Part, Renderer, Rgb, Solution};
+use grid::{Coord, Direction, Grid};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+type Consequent = usize;
+
+#[repr(u8)]
+enum Splitter {
+  Horizontal = b'-',
+  Vertical = b'|',
+}
+
+#[repr(u8)]
+enum Mirror {
+  Clockwise = b'\\',
+  CounterClockwise = b'/',
+}
+
+#[derive(Default)]
+struct VisitRecord {
+  east: bool,
+  south: bool,
+  west: bool,
+  north: bool,
+}
+
+impl VisitRecord {
+  fn has_visited(&self, direction: Direction) -> bool {
+    match direction {
+      Direction::East => self.east,
+      Direction::South => self.south,
+      Direction::West => self.west,
+      Direction::North => self.north,
+    }
+  }
+
+  fn visit(&mut self, direction: Direction) {
+    match direction {
+      Direction::East => self.east = true,
+      Direction::South => self.south = true,
+      Direction::West => self.west = true,
+      Direction::North => self.north = true,
+    }
+  }
+}
+
+pub struct BeamMap {
+  map: Grid<u8>,
+}
+
+impl BeamMap {
+  fn beam_dfs(
+    &self,
+    at: Coord,
+    visited: &mut HashMap<Coord, VisitRecord>,
+    heading: Direction,
+  ) {
+    // visit
+    let entry = visited.entry(at).or_default();
+    if entry.has_visited(heading) {
+      /* if we don't have a new entry has_visited will be true for at least one direction. if it is true for ours, we've been down this path before */
+      return;
+    }
+    entry.visit(heading);
+
+    // beams leaving the contraption are dismissed
+    for heading in self.headings(at, heading) {
+      if let Some(next) = self.map.step(at, heading) {
+        self.beam_dfs(next, visited, heading);
+      }
+    }
+  }
+
+  /* headings - where a beam entering `at` heading `heading` goes on to */
+  fn headings(&self, at: Coord, heading: Direction) -> Vec<Direction> {
+    match self.map[at] {
+      c if c == Mirror::CounterClockwise as u8 => vec![match heading {
+        Direction::East => Direction::North,
+        Direction::North => Direction::East,
+        Direction::West => Direction::South,
+        Direction::South => Direction::West,
+      }],
+      c if c == Mirror::Clockwise as u8 => vec![match heading {
+        Direction::East => Direction::South,
+        Direction::North => Direction::West,
+        Direction::West => Direction::North,
+        Direction::South => Direction::East,
+      }],
+      c if c == Splitter::Horizontal as u8 => match heading {
+        Direction::East | Direction::West => vec![heading],
+        Direction::North | Direction::South => {
+          vec![Direction::East, Direction::West]
+        }
+      },
+      c if c == Splitter::Vertical as u8 => match heading {
+        Direction::South | Direction::North => vec![heading],
+        Direction::East | Direction::West => {
+          vec![Direction::South, Direction::North]
+        }
+      },
+      _ => vec![heading],
+    }
+  }
+
+  fn count_visited_tiles(&self, start: Coord, direction: Direction) -> usize {
+    let mut visited = HashMap::new();
+    self.beam_dfs(start, &mut visited, direction);
+
+    visited.len()
+  }
+
+  /* reached - how many tiles the beam from `start` crosses before it first
+  energises each tile it gets to, following every branch a tile at a time */
+  fn reached(
+    &self,
+    start: Coord,
+    direction: Direction,
+  ) -> HashMap<Coord, usize> {
+    let mut reached = HashMap::new();
+    let mut seen = HashSet::from([(start, direction)]);
+    let mut queue = VecDeque::from([(start, direction, 0)]);
+    while let Some((at, heading, steps)) = queue.pop_front() {
+      reached.entry(at).or_insert(steps);
+      for heading in self.headings(at, heading) {
+        if let Some(next) = self.map.step(at, heading) {
+          if seen.insert((next, heading)) {
+            queue.push_back((next, heading, steps + 1));
+          }
+        }
+      }
+    }
+
+    reached
+  }
+
+  /* entries - every cell of the top and bottom edge, then of the left and
+  right, heading into the contraption */
+  fn entries(&self) -> Vec<(Coord, Direction)> {
+    let rows = self.map.height();
+    let cols = self.map.width();
+
+    let mut entries = Vec::new();
+    for x in 0..cols {
+      entries.push((Coord::new(x, 0), Direction::South));
+      entries.push((Coord::new(x, rows - 1), Direction::North));
+    }
+    for y in 0..rows {
+      entries.push((Coord::new(0, y), Direction::East));
+      entries.push((Coord::new(cols - 1, y), Direction::West));
+    }
+
+    entries
+  }
+
+  /* best_entry - the entry that energises the most tiles, with their
+  count */
+  fn best_entry(&self) -> Option<((Coord, Direction), usize)> {
+    let entries = self.entries();
+    let counts = parallel::map(&entries, |&(start, direction)| {
+      self.count_visited_tiles(start, direction)
+    });
+
+    entries
+      .into_iter()
+      .zip(counts)
+      .max_by_key(|&(_, count)| count)
+  }
+}
+
+impl FromStr for BeamMap {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let map = Grid::parse(s, |c| {
+      matches!(c, '.' | '/' | '\\' | '|' | '-').then_some(c as u8)
+    })?;
+
+    Ok(BeamMap { map })
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<BeamMap, Error> {
+  src_provider()?.parse::<BeamMap>()
+}
+
+fn transform(data: BeamMap, part: Part) -> Result<Consequent, Error> {
+  match part {
+    Part::One => {
+      Ok(data.count_visited_tiles(Coord::new(0, 0), Direction::East))
+    }
+    Part::Two => Ok(data.best_entry().map_or(0, |(_, count)| count)),
+  }
+}
+
+/* render - the tiles the beam energises, lit up a tile's crossing at a time
+when animating; part 2 follows the beam from the best entry */
+fn render(
+  data: &BeamMap,
+  part: Part,
+  renderer: &mut Renderer,
+) -> Result<(), Error> {
+  let (start, direction) = match part {
+    Part::One => (Coord::new(0, 0), Direction::East),
+    Part::Two => data
+      .best_entry()
+      .map(|(entry, _)| entry)
+      .ok_or(Error::missing("no entries into the contraption"))?,
+  };
+  let reached = data.reached(start, direction);
+  let last = reached.values().copied().max().unwrap_or_default();
+  let picture = |steps: usize| {
+    data.map.frame(|c, &tile| match reached.get(&c) {
+      Some(&at) if at <= steps && tile == b'.' => Cell::new('#', Rgb::YELLOW),
+      Some(&at) if at <= steps => Cell::new(tile as char, Rgb::ORANGE),
+      _ => Cell::new(tile as char, Rgb::GREY),
+    })
+  };
+  if renderer.animating() {
+    for steps in 0..last {
+      renderer.frame(picture(steps))?;
+    }
+  }
+
+  renderer.frame(picture(last))
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(count) => println!("count: {count}"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+  type ProblemDefinition = BeamMap;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 16;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn render(
+    data: &Self::ProblemDefinition,
+    part: Part,
+    renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    render(data, part, renderer)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::render::Target;
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+  const MALFORMED: [&str; 3] = [
+    ".|...\\....\n|.-.\\.....\n.....",
+    ".|...\\....\n|.-.\\..x..",
+    ".|...\\....\n\n|.-.\\.....",
+  ];
+
+  // MARK extract
+  #[test]
+  fn it_should_reject_a_ragged_contraption() {
+    let error = ".|.\n\\.\n..-".parse::<BeamMap>().err().unwrap();
+
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position, Some(Position { line: 2, column: 1 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_energize_tiles_for_each_part() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    assert_eq!(transform(extract()?, Part::One)?, 46);
+    assert_eq!(transform(extract()?, Part::Two)?, 51);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_draw_the_beam_from_the_best_entry() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract()?;
+    for (start, direction) in data.entries() {
+      assert_eq!(
+        data.reached(start, direction).len(),
+        data.count_visited_tiles(start, direction)
+      );
+    }
+
+    let mut renderer = Renderer::new(Target::Memory, None);
+    render(&data, Part::Two, &mut renderer)?;
+    let frame = &renderer.frames()[0];
+    let lit = (0..frame.height())
+      .flat_map(|y| (0..frame.width()).map(move |x| (x, y)))
+      .filter(|&(x, y)| frame.get(x, y).unwrap().colour != Rgb::GREY)
+      .count();
+    assert_eq!(lit, 51);
+    assert_eq!(frame.get(3, 0), Some(&Cell::new('#', Rgb::YELLOW)));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_empty_space_right() {
+    let mut visited = HashMap::new();
+    let map = BeamMap { map: Grid::new(5, 5, b'.') };
+    map.beam_dfs(Coord::new(0, 0), &mut visited, Direction::East);
+
+    assert!(visited.contains_key(&Coord::new(4, 0)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_empty_space_down() {
+    let mut visited = HashMap::new();
+    let map = BeamMap { map: Grid::new(5, 5, b'.') };
+    map.beam_dfs(Coord::new(0, 0), &mut visited, Direction::South);
+
+    assert!(visited.contains_key(&Coord::new(0, 4)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_empty_space_left() {
+    let mut visited = HashMap::new();
+    let map = BeamMap { map: Grid::new(5, 5, b'.') };
+    map.beam_dfs(Coord::new(4, 0), &mut visited, Direction::West);
+
+    assert!(visited.contains_key(&Coord::new(0, 0)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_empty_space_up() {
+    let mut visited = HashMap::new();
+    let map = BeamMap { map: Grid::new(5, 5, b'.') };
+    map.beam_dfs(Coord::new(0, 4), &mut visited, Direction::North);
+
+    assert!(visited.contains_key(&Coord::new(0, 0)));
+    assert_eq!(visited.len(), 5);
+  }
+
+  #[test]
+  fn test_clockwise_mirror_right() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::Clockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(0, 2), &mut visited, Direction::East);
+
+    assert!(visited.contains_key(&Coord::new(2, 4)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_clockwise_mirror_down() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::Clockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 0), &mut visited, Direction::South);
+
+    assert!(visited.contains_key(&Coord::new(2, 0)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_clockwise_mirror_left() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::Clockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(4, 2), &mut visited, Direction::West);
+
+    assert!(visited.contains_key(&Coord::new(2, 0)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_clockwise_mirror_up() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::Clockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 4), &mut visited, Direction::North);
+
+    assert!(visited.contains_key(&Coord::new(2, 4)));
+    assert_eq!(visited.len(), 5);
+  }
+
+  #[test]
+  fn test_counterclockwise_mirror_right() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::CounterClockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(0, 2), &mut visited, Direction::East);
+
+    assert!(visited.contains_key(&Coord::new(0, 2)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_counterclockwise_mirror_down() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::CounterClockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 0), &mut visited, Direction::South);
+
+    assert!(visited.contains_key(&Coord::new(0, 2)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_counterclockwise_mirror_left() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::CounterClockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(4, 2), &mut visited, Direction::West);
+
+    assert!(visited.contains_key(&Coord::new(4, 2)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_counterclockwise_mirror_up() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Mirror::CounterClockwise as u8;
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 4), &mut visited, Direction::North);
+
+    assert!(visited.contains_key(&Coord::new(4, 2)));
+    assert_eq!(visited.len(), 5);
+  }
+
+  #[test]
+  fn test_horizontal_splitter_right() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Horizontal as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(0, 2), &mut visited, Direction::East);
+
+    assert!(visited.contains_key(&Coord::new(4, 2)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_horizontal_splitter_down() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Horizontal as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 0), &mut visited, Direction::South);
+
+    assert!(visited.contains_key(&Coord::new(4, 2)));
+    assert!(visited.contains_key(&Coord::new(0, 2)));
+    assert_eq!(visited.len(), 7);
+  }
+  #[test]
+  fn test_horizontal_splitter_left() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Horizontal as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(4, 2), &mut visited, Direction::West);
+
+    assert!(visited.contains_key(&Coord::new(0, 2)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_horizontal_splitter_up() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Horizontal as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 4), &mut visited, Direction::North);
+
+    assert!(visited.contains_key(&Coord::new(4, 2)));
+    assert!(visited.contains_key(&Coord::new(0, 2)));
+    assert_eq!(visited.len(), 7);
+  }
+
+  #[test]
+  fn test_vertical_splitter_right() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Vertical as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(0, 2), &mut visited, Direction::East);
+
+    assert!(visited.contains_key(&Coord::new(2, 0)));
+    assert!(visited.contains_key(&Coord::new(2, 4)));
+    assert_eq!(visited.len(), 7);
+  }
+  #[test]
+  fn test_vertical_splitter_down() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Vertical as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 0), &mut visited, Direction::South);
+
+    assert!(visited.contains_key(&Coord::new(2, 4)));
+    assert_eq!(visited.len(), 5);
+  }
+  #[test]
+  fn test_vertical_splitter_left() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Vertical as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(4, 2), &mut visited, Direction::West);
+
+    assert!(visited.contains_key(&Coord::new(2, 0)));
+    assert!(visited.contains_key(&Coord::new(2, 4)));
+    assert_eq!(visited.len(), 7);
+  }
+  #[test]
+  fn test_vertical_splitter_up() {
+    let mut visited = HashMap::new();
+    let mut map = Grid::new(5, 5, b'.');
+    map[Coord::new(2, 2)] = Splitter::Vertical as u8;
+
+    let beam_map = BeamMap { map };
+    beam_map.beam_dfs(Coord::new(2, 4), &mut visited, Direction::North);
+
+    assert!(visited.contains_key(&Coord::new(2, 0)));
+    assert_eq!(visited.len(), 5);
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day16>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,353 @@
+use advent::{params, Cell, Error, Part, Renderer, Rgb, Solution};
+use grid::{Coord, Direction, Grid};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+// the moves allowed unless `crucible_moves` or `ultra_crucible_moves` in the
+// parameters say otherwise
+const CRUCIBLE_MOVES: LegalMoves = LegalMoves { min: 1, max: 3 };
+const ULTRA_CRUCIBLE_MOVES: LegalMoves = LegalMoves { min: 4, max: 10 };
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct LegalMoves {
+  pub min: usize,
+  pub max: usize,
+}
+
+impl LegalMoves {
+  pub fn for_part(part: Part) -> Result<LegalMoves, Error> {
+    let params =
+      params::load(PARAMS_PATH, &["crucible_moves", "ultra_crucible_moves"])?;
+    match part {
+      Part::One => params.get("crucible_moves", CRUCIBLE_MOVES),
+      Part::Two => params.get("ultra_crucible_moves", ULTRA_CRUCIBLE_MOVES),
+    }
+  }
+
+  pub fn range(&self) -> RangeInclusive<usize> {
+    self.min..=self.max
+  }
+}
+
+/* a parameter gives the moves as `<min>-<max>`: `4-10` for the ultra crucible */
+impl FromStr for LegalMoves {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let bound = |n: &str| {
+      n.trim()
+        .parse::<usize>()
+        .map_err(|e| Error::value(format!("invalid move count '{n}': {e}")))
+    };
+    let Some((min, max)) = s.split_once('-') else {
+      return Err(Error::syntax("expected <min>-<max>"));
+    };
+    let (min, max) = (bound(min)?, bound(max)?);
+    if min == 0 || min > max {
+      return Err(Error::value(format!(
+        "{min} to {max} blocks is not a range of moves"
+      )));
+    }
+
+    Ok(LegalMoves { min, max })
+  }
+}
+
+pub struct ProblemDefinition {
+  grid: Grid<u64>,
+}
+
+/* a route never enters a (block, heading) state twice and loses at most 9 a
+block, so its heat loss is bounded by the map's size: 64 bits hold it for any
+map that fits in memory, where 32 wrapped on large generated ones */
+type Consequent = u64;
+
+// a block and the heading the crucible arrived in it with
+type State = (Coord, Direction);
+
+/* Route - the least heat loss on the way to the end, and every block the
+crucible enters on it with the heading it enters in */
+pub struct Route {
+  heat_loss: u64,
+  blocks: Vec<State>,
+}
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract(data: &str) -> Result<ProblemDefinition, Error> {
+  let grid = Grid::parse(data, |c| c.to_digit(10).map(u64::from))?;
+
+  Ok(ProblemDefinition { grid })
+}
+
+/* find_shortest_path - Dijkstra over (block, heading) states. From each block
+the crucible turns left or right and moves a legal number of blocks in a
+straight line, paying the heat loss of every block it enters. Each state
+settled remembers the one it was reached from, to retrace the route. */
+fn find_shortest_path(
+  end_point: Coord,
+  grid: &Grid<u64>,
+  legal_moves: &LegalMoves,
+) -> Option<Route> {
+  let mut todo: BinaryHeap<Reverse<(u64, State, Option<State>)>> =
+    BinaryHeap::new();
+  // Starting from the top left corner, heading either right or down
+  todo.push(Reverse((0, (Coord::new(0, 0), Direction::East), None)));
+  todo.push(Reverse((0, (Coord::new(0, 0), Direction::South), None)));
+
+
+  let mut visited: HashMap<State, Option<State>> = HashMap::new();
+
+  while let Some(Reverse((value, state, from))) = todo.pop() {
+    if visited.contains_key(&state) {
+      continue;
+    }
+    visited.insert(state, from);
+
+    let (position, direction) = state;
+    if position == end_point {
+      let blocks = retrace(&visited, state);
+      return Some(Route { heat_loss: value, blocks });
+    }
+
+    for heading in [direction.turn_left(), direction.turn_right()] {
+      let mut new_position = position;
+      let mut step_values = 0;
+      for steps in 1..=legal_moves.max {
+        let Some(next) = grid.step(new_position, heading) else {
+          break;
+        };
+        new_position = next;
+        step_values += grid[next];
+        if legal_moves.range().contains(&steps) {
+          let next = (new_position, heading);
+          todo.push(Reverse((value + step_values, next, Some(state))));
+        }
+      }
+    }
+  }
+
+  None
+}
+
+/* retrace - the blocks entered on the way to `end`, in order, filling in
+those passed between the states a move joins */
+fn retrace(visited: &HashMap<State, Option<State>>, end: State) -> Vec<State> {
+  let mut blocks = Vec::new();
+  let mut state = end;
+  while let Some(&Some(from)) = visited.get(&state) {
+    let (mut at, heading) = state;
+    while at != from.0 {
+      blocks.push((at, heading));
+      at = at
+        .step(heading.opposite())
+        .expect("a move stays on the map");
+    }
+    state = from;
+  }
+  blocks.reverse();
+
+  blocks
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  let end_point = Coord::new(data.grid.width() - 1, data.grid.height() - 1);
+
+  let legal_moves = LegalMoves::for_part(part)?;
+
+  let route = find_shortest_path(end_point, &data.grid, &legal_moves)
+    .ok_or(Error::no_solution("no path found"))?;
+
+  Ok(route.heat_loss)
+}
+
+/* render - the heat loss of every block, cooler to hotter, with the route
+the crucible takes drawn over it in arrows, a block at a time when
+animating */
+fn render(
+  data: &ProblemDefinition,
+  part: Part,
+  renderer: &mut Renderer,
+) -> Result<(), Error> {
+  let end_point = Coord::new(data.grid.width() - 1, data.grid.height() - 1);
+  let legal_moves = LegalMoves::for_part(part)?;
+  let route = find_shortest_path(end_point, &data.grid, &legal_moves)
+    .ok_or(Error::no_solution("no path found"))?;
+  let picture = |entered: usize| {
+    let mut frame = data.grid.frame(|_, &heat| {
+      let shade = 60 + heat as u8 * 20;
+      let glyph = char::from_digit(heat as u32, 10).unwrap_or('?');
+      Cell::new(glyph, Rgb(shade, shade / 2, shade / 3))
+    });
+    for &(at, heading) in &route.blocks[..entered] {
+      frame.set(at.x, at.y, Cell::new(arrow(heading), Rgb::CYAN));
+    }
+
+    frame
+  };
+  if renderer.animating() {
+    for entered in 0..route.blocks.len() {
+      renderer.frame(picture(entered))?;
+    }
+  }
+
+  renderer.frame(picture(route.blocks.len()))
+}
+
+fn arrow(heading: Direction) -> char {
+  match heading {
+    Direction::North => '^',
+    Direction::East => '>',
+    Direction::South => 'v',
+    Direction::West => '<',
+  }
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(heat_loss) => println!("heat_loss {heat_loss}"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 17;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(&src_provider()?)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn render(
+    data: &Self::ProblemDefinition,
+    part: Part,
+    renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    render(data, part, renderer)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+  const MALFORMED: [&str; 4] = [
+    "2413432311323\n3215453",
+    "24134\n32154\n\n32552",
+    "24134\n3215-",
+    "",
+  ];
+
+  // MARK extract
+  #[test]
+  fn it_should_reject_malformed_input() {
+    for input in MALFORMED {
+      assert!(extract(input).is_err(), "accepted {input:?}");
+    }
+  }
+
+  #[test]
+  fn it_should_point_at_an_invalid_heat_loss() {
+    let error = extract("241\n3x5").err().unwrap();
+
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 2 }));
+  }
+
+  // MARK transform
+  #[test]
+  fn it_should_minimize_heat_loss_for_each_part() -> Result<(), Error> {
+    assert_eq!(transform(extract(SAMPLE)?, Part::One)?, 102);
+    assert_eq!(transform(extract(SAMPLE)?, Part::Two)?, 94);
+
+    Ok(())
+  }
+
+  #[test]
+  fn it_should_retrace_the_route_it_costs() -> Result<(), Error> {
+    let data = extract(SAMPLE)?;
+    let end_point = Coord::new(12, 12);
+    for moves in [CRUCIBLE_MOVES, ULTRA_CRUCIBLE_MOVES] {
+      let route = find_shortest_path(end_point, &data.grid, &moves).unwrap();
+      let heat: u64 = route.blocks.iter().map(|&(at, _)| data.grid[at]).sum();
+
+      assert_eq!(heat, route.heat_loss);
+      assert_eq!(route.blocks.last().map(|&(at, _)| at), Some(end_point));
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn it_should_read_legal_moves() {
+    assert_eq!("4-10".parse(), Ok(ULTRA_CRUCIBLE_MOVES));
+    assert_eq!(" 1 - 3 ".parse(), Ok(CRUCIBLE_MOVES));
+    for bad in ["4", "4-x", "0-3", "5-4"] {
+      assert!(bad.parse::<LegalMoves>().is_err(), "accepted {bad:?}");
+    }
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day17>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,502 @@
+use advent::input::{self, Lines};
+use advent::{Cell, Check, Error, Frame, Part, Renderer, Rgb, Solution, Total};
+use sscanf::sscanf;
+use std::str::FromStr;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+// the longer side of a drawn lagoon, in cells; larger ones are scaled down
+const LAGOON_SIDE: isize = 120;
+const TRENCH: Cell = Cell::new('#', Rgb::ORANGE);
+const LAGOON: Cell = Cell::new('~', Rgb::BLUE);
+const GROUND: Cell = Cell::new('.', Rgb::DARK);
+
+enum Direction {
+  Right,
+  Down,
+  Left,
+  Up,
+}
+
+impl Direction {
+  fn coordinate_rotation(&self) -> (isize, isize) {
+    match self {
+      Direction::Right => (0, 1),
+      Direction::Down => (1, 0),
+      Direction::Left => (0, -1),
+      Direction::Up => (-1, 0),
+    }
+  }
+}
+
+impl FromStr for Direction {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    /* part one spells the direction out, part two encodes it as the last hex digit of the color */
+    match s {
+      "R" | "0" => Ok(Direction::Right),
+      "D" | "1" => Ok(Direction::Down),
+      "L" | "2" => Ok(Direction::Left),
+      "U" | "3" => Ok(Direction::Up),
+      _ => Err(Error::value(format!("invalid direction '{s}'")).near(s)),
+    }
+  }
+}
+
+struct Traversal {
+  direction: Direction,
+  distance: isize,
+}
+
+pub struct ProblemDefinition {
+  trench: Vec<Traversal>,
+}
+
+impl ProblemDefinition {
+  fn calculate_area(&self) -> Result<Total, Error> {
+    let mut dig = Dig::default();
+    for traversal in &self.trench {
+      dig.follow(traversal);
+    }
+
+    Ok(dig.area())
+  }
+}
+
+/* Dig - the shoelace sum and perimeter of the trench dug so far, which is all
+the area needs, however long the trench. The sum multiplies distances, so it
+outgrows them on long trenches. */
+#[derive(Default)]
+struct Dig {
+  total_row: Total,
+  perimeter: Total,
+  area: Total,
+}
+
+impl Dig {
+  fn follow(&mut self, traversal: &Traversal) {
+    let (dy, dx) = traversal.direction.coordinate_rotation();
+    let dy = dy * traversal.distance;
+    let dx = dx * traversal.distance;
+
+    self.total_row += dx;
+    self.perimeter += traversal.distance;
+    self.area += self.total_row.clone() * dy;
+  }
+
+  fn area(&self) -> Total {
+    self.area.clone() + self.perimeter.clone() / 2 + 1
+  }
+}
+
+type Consequent = Total;
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  input::read(DATA_PATH)
+}
+
+fn extract(part: Part) -> Result<ProblemDefinition, Error> {
+  let trench = src_provider()?
+    .lines()
+    .enumerate()
+    .map(|(i, line)| parse_traversal(i + 1, line, part))
+    .collect::<Result<_, _>>()?;
+
+  Ok(ProblemDefinition { trench })
+}
+
+/* stream - the trench dug as each instruction is read, keeping only what
+the area needs */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let mut dig = Dig::default();
+
+  for (i, line) in lines.enumerate() {
+    let line = line?;
+    dig.follow(&parse_traversal(i + 1, &line, part)?);
+  }
+
+  Ok(dig.area().to_string())
+}
+
+fn parse_traversal(
+  number: usize,
+  line: &str,
+  part: Part,
+) -> Result<Traversal, Error> {
+  let (direction_str, distance, color_str) =
+    sscanf!(line, "{} {} (#{})", String, isize, String).map_err(|_| {
+      Error::syntax("expected '<direction> <distance> (#<color>)'")
+        .on_line(number, line)
+    })?;
+
+  if distance <= 0 {
+    return Err(
+      Error::value(format!("invalid distance '{distance}'"))
+        .near(&distance.to_string())
+        .on_line(number, line),
+    );
+  }
+  if color_str.len() != 6 || !color_str.chars().all(|c| c.is_ascii_hexdigit()) {
+    return Err(
+      Error::value(format!("invalid color '#{color_str}'"))
+        .near(&color_str)
+        .on_line(number, line),
+    );
+  }
+
+  let (direction_str, distance) = match part {
+    Part::One => (direction_str, distance),
+    Part::Two => {
+      let (hex_distance, hex_direction) =
+        color_str.split_at(color_str.len().saturating_sub(1));
+      let distance = u64::from_str_radix(hex_distance, 16).map_err(|e| {
+        Error::value(format!("invalid distance '{hex_distance}': {e}"))
+          .near(&color_str)
+          .on_line(number, line)
+      })? as isize;
+
+      (hex_direction.to_string(), distance)
+    }
+  };
+
+  let direction =
+    Direction::from_str(&direction_str).map_err(|e| e.on_line(number, line))?;
+
+  Ok(Traversal { direction, distance })
+}
+
+/* check - the area is the shoelace sum over the trench plus half its
+perimeter, which only counts the lagoon when the trench closes where it
+started and runs clockwise, as in the sample */
+fn check(data: &ProblemDefinition) -> Vec<Check> {
+  let (mut y, mut x, mut area) = (Total::ZERO, Total::ZERO, Total::ZERO);
+  for traversal in &data.trench {
+    let (dy, dx) = traversal.direction.coordinate_rotation();
+    y += dy * traversal.distance;
+    x += dx * traversal.distance;
+    area += x.clone() * dy * traversal.distance;
+  }
+
+  vec![
+    Check::that(
+      "the trench closes where it started",
+      y == 0 && x == 0,
+      || format!("it ends {y} down and {x} right of the start"),
+    ),
+    Check::that("the trench runs clockwise", area > 0, || {
+      "it runs anticlockwise".to_string()
+    }),
+  ]
+}
+
+fn transform(data: ProblemDefinition) -> Result<Consequent, Error> {
+  data.calculate_area()
+}
+
+/* corners - the (row, column) the digger starts from and each instruction
+leaves it at */
+fn corners(trench: &[Traversal]) -> Vec<(isize, isize)> {
+  let mut corner = (0, 0);
+  let mut corners = vec![corner];
+  for traversal in trench {
+    let (dy, dx) = traversal.direction.coordinate_rotation();
+    corner = (
+      corner.0 + dy * traversal.distance,
+      corner.1 + dx * traversal.distance,
+    );
+    corners.push(corner);
+  }
+
+  corners
+}
+
+/* inside - whether (y, x), off the trench, is inside it: a ray to the right
+crosses the trench's vertical runs an odd number of times */
+fn inside(corners: &[(isize, isize)], y: isize, x: isize) -> bool {
+  let crossings = corners
+    .windows(2)
+    .filter(|run| {
+      let ((y1, x1), (y2, x2)) = (run[0], run[1]);
+      x1 == x2 && x1 > x && (y1 <= y) != (y2 <= y)
+    })
+    .count();
+
+  crossings % 2 == 1
+}
+
+/* render - the trench dug an instruction a frame when animating, then the
+lagoon it holds. A part 2 trench runs for millions of metres, so a lagoon
+longer than `LAGOON_SIDE` is drawn with each cell standing for a square of
+them, dug if the trench passes through it and filled if its middle is
+inside. */
+fn render(
+  data: &ProblemDefinition,
+  renderer: &mut Renderer,
+) -> Result<(), Error> {
+  let corners = corners(&data.trench);
+  let (ys, xs): (Vec<isize>, Vec<isize>) = corners.iter().copied().unzip();
+  let (min_y, max_y) = (ys.iter().min(), ys.iter().max());
+  let (min_x, max_x) = (xs.iter().min(), xs.iter().max());
+  let (Some(&min_y), Some(&max_y), Some(&min_x), Some(&max_x)) =
+    (min_y, max_y, min_x, max_x)
+  else {
+    return Err(Error::missing("no trench to draw"));
+  };
+  let scale = (max_y - min_y).max(max_x - min_x) / LAGOON_SIDE + 1;
+  let cell = |(y, x): (isize, isize)| {
+    (
+      ((y - min_y) / scale) as usize,
+      ((x - min_x) / scale) as usize,
+    )
+  };
+  let (height, width) = cell((max_y, max_x));
+  let mut frame = Frame::new(width + 1, height + 1, GROUND);
+
+  for run in corners.windows(2) {
+    let ((y1, x1), (y2, x2)) = (cell(run[0]), cell(run[1]));
+    for y in y1.min(y2)..=y1.max(y2) {
+      for x in x1.min(x2)..=x1.max(x2) {
+        frame.set(x, y, TRENCH);
+      }
+    }
+    if renderer.animating() {
+      renderer.frame(frame.clone())?;
+    }
+  }
+  for y in 0..=height {
+    for x in 0..=width {
+      let middle = (
+        min_y + y as isize * scale + scale / 2,
+        min_x + x as isize * scale + scale / 2,
+      );
+      if frame.get(x, y) == Some(&GROUND)
+        && inside(&corners, middle.0, middle.1)
+      {
+        frame.set(x, y, LAGOON);
+      }
+    }
+  }
+
+  renderer.frame(frame)
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(area) => println!("area: {area}"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 18;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(part)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    _part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, _part: Part) -> Vec<Check> {
+    check(data)
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(DATA_PATH)?, part)
+  }
+
+  fn render(
+    data: &Self::ProblemDefinition,
+    _part: Part,
+    renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    render(data, renderer)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::render::Target;
+  use advent::{ErrorKind, Position};
+  use std::num::NonZeroU32;
+
+  const SAMPLE: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+  const MALFORMED: [&str; 5] = [
+    "R 6 (#70c710)\nD 5 (#0dc5",
+    "R 6 (#70c710)\nD -5 (#0dc571)",
+    "R 6 (#70c710)\nD 5 (#0dx571)",
+    "R 6 (#70c710)\nX 5 (#0dc571)",
+    "R 6 (#70c710)\nD 5 #0dc571",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_decode_the_color_in_part_two() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let data = extract(Part::Two)?;
+    assert_eq!(data.trench[0].distance, 461937);
+    assert!(matches!(data.trench[0].direction, Direction::Right));
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_an_invalid_direction() {
+    mock_src_provider().returns(Ok("R 6 (#70c710)\nX 5 (#0dc571)".to_string()));
+
+    let error = extract(Part::One).err().unwrap();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 1 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract(Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_calculate_the_lagoon_area_for_each_part() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    assert_eq!(transform(extract(Part::One)?)?, 62);
+    assert_eq!(transform(extract(Part::Two)?)?, 952408144115_u64);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_draw_the_trench_dug_and_the_lagoon() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let mut renderer = Renderer::new(Target::Memory, NonZeroU32::new(1000));
+    render(&extract(Part::One)?, &mut renderer)?;
+    let frames = renderer.frames();
+    let count = |frame: &Frame, cell| {
+      (0..frame.height())
+        .flat_map(|y| (0..frame.width()).map(move |x| (x, y)))
+        .filter(|&(x, y)| frame.get(x, y) == Some(&cell))
+        .count()
+    };
+
+    // a frame an instruction, then the lagoon: every cell of the area dug
+    assert_eq!(frames.len(), 15);
+    assert_eq!(count(&frames[0], TRENCH), 7);
+    assert_eq!(count(&frames[14], TRENCH) + count(&frames[14], LAGOON), 62);
+
+    let mut renderer = Renderer::new(Target::Memory, None);
+    render(&extract(Part::Two)?, &mut renderer)?;
+    let frame = &renderer.frames()[0];
+    assert!(frame.width().max(frame.height()) <= LAGOON_SIDE as usize);
+
+    Ok(())
+  }
+
+  #[test]
+  fn it_should_stream_the_same_area() {
+    let stream =
+      |input: &'static str, part| stream(Lines::new(input.as_bytes()), part);
+
+    assert_eq!(stream(SAMPLE, Part::One), Ok("62".to_string()));
+    assert_eq!(stream(SAMPLE, Part::Two), Ok("952408144115".to_string()));
+    for input in MALFORMED {
+      assert!(stream(input, Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_the_trench_is_a_clockwise_loop() -> Result<(), Error> {
+    let turns = "D 2 (#000000)\nR 2 (#000000)\nU 2 (#000000)";
+    let inputs = [SAMPLE.to_string(), format!("{turns}\nL 2 (#000000)")];
+    let mut inputs = inputs.into_iter().chain([turns.to_string()]);
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or_default()));
+    let violations = |data: ProblemDefinition| -> Vec<Option<String>> {
+      check(&data)
+        .into_iter()
+        .map(|check| check.violation)
+        .collect()
+    };
+
+    assert_eq!(violations(extract(Part::Two)?), [None, None]);
+    assert_eq!(
+      violations(extract(Part::One)?),
+      [None, Some("it runs anticlockwise".to_string())]
+    );
+    assert_eq!(
+      violations(extract(Part::One)?)[0].as_deref(),
+      Some("it ends 0 down and 2 right of the start")
+    );
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day18>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
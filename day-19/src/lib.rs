@@ -0,0 +1,449 @@
+use advent::{Error, Part, Solution, Total};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct ProblemDefinition {
+  workflows: HashMap<String, Node>,
+  parts: Vec<Parts>,
+}
+
+struct Node {
+  rules: Vec<BooleanEvaluation>,
+  default: String,
+}
+
+impl Node {
+  fn evaluate(&self, parts: &Parts) -> &str {
+    self
+      .rules
+      .iter()
+      .find(|rule| rule.evaluate(parts))
+      .map_or(&self.default, |rule| &rule.goal)
+  }
+}
+
+struct BooleanEvaluation {
+  closure_name: char,
+  operand: bool,
+  value: usize,
+  goal: String,
+}
+
+impl BooleanEvaluation {
+  fn evaluate(&self, parts: &Parts) -> bool {
+    let val = parts.get(self.closure_name);
+    if self.operand {
+      val > self.value
+    } else {
+      val < self.value
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct XMASBounds {
+  x: (usize, usize),
+  m: (usize, usize),
+  a: (usize, usize),
+  s: (usize, usize),
+}
+
+#[derive(Debug)]
+struct Parts {
+  x: usize,
+  m: usize,
+  a: usize,
+  s: usize,
+}
+
+impl Parts {
+  fn get(&self, elem: char) -> usize {
+    match elem {
+      'x' => self.x,
+      'm' => self.m,
+      'a' => self.a,
+      's' => self.s,
+      _ => unreachable!(),
+    }
+  }
+}
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  let reg_workflow = Regex::new(r"^(\w+)\{(.*)\}$").unwrap();
+  let reg_rule = Regex::new(r"^([xmas])([<>])(\d+):(\w+)$").unwrap();
+  let reg_part = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)}$").unwrap();
+
+  let input = src_provider()?;
+  let mut lines = input.lines().enumerate();
+
+  let mut workflows = HashMap::new();
+  for (i, line) in lines.by_ref() {
+    if line.is_empty() {
+      break;
+    }
+    let (name, node) = parse_workflow(line, &reg_workflow, &reg_rule)
+      .map_err(|e| e.on_line(i + 1, line))?;
+    if workflows.contains_key(&name) {
+      return Err(
+        Error::value(format!("workflow '{name}' is defined twice"))
+          .on_line(i + 1, line),
+      );
+    }
+    workflows.insert(name, node);
+  }
+
+  let mut parts = Vec::new();
+  for (i, line) in lines {
+    let Some(cap) = reg_part.captures(line) else {
+      return Err(
+        Error::syntax("expected '{x=<n>,m=<n>,a=<n>,s=<n>}'")
+          .on_line(i + 1, line),
+      );
+    };
+    let rating = |n: usize| {
+      cap[n].parse().map_err(|e| {
+        Error::value(format!("invalid rating '{}': {e}", &cap[n]))
+          .near(&cap[n])
+          .on_line(i + 1, line)
+      })
+    };
+    parts.push(Parts {
+      x: rating(1)?,
+      m: rating(2)?,
+      a: rating(3)?,
+      s: rating(4)?,
+    });
+  }
+  if parts.is_empty() {
+    return Err(Error::missing(
+      "expected workflows and parts separated by a blank line",
+    ));
+  }
+  check_workflows(&workflows)?;
+
+  Ok(ProblemDefinition { workflows, parts })
+}
+
+/* parse_workflow - `px{a<2006:qkq,m>2090:A,rfg}`, whose rules compare against
+ratings between 1 and 4000 */
+fn parse_workflow(
+  line: &str,
+  reg_workflow: &Regex,
+  reg_rule: &Regex,
+) -> Result<(String, Node), Error> {
+  let Some(workflow) = reg_workflow.captures(line) else {
+    return Err(Error::syntax("expected '<name>{<rules>,<default>}'"));
+  };
+  let mut specs: Vec<&str> = workflow[2].split(',').collect();
+  let default = specs.pop().unwrap_or_default();
+  if default.is_empty() || !default.chars().all(char::is_alphanumeric) {
+    return Err(
+      Error::syntax("expected a default workflow").near(&workflow[2]),
+    );
+  }
+
+  let rules = specs
+    .into_iter()
+    .map(|spec| {
+      let Some(rule) = reg_rule.captures(spec) else {
+        return Err(
+          Error::syntax("expected '<category><op><rating>:<workflow>'")
+            .near(spec),
+        );
+      };
+      let Some(value) = rule[3]
+        .parse()
+        .ok()
+        .filter(|value| (1..=4000).contains(value))
+      else {
+        return Err(
+          Error::value(format!("invalid rating '{}'", &rule[3])).near(spec),
+        );
+      };
+
+      Ok(BooleanEvaluation {
+        // the category is one of `xmas`, so it is a single byte
+        closure_name: spec.as_bytes()[0] as char,
+        operand: &rule[2] == ">",
+        value,
+        goal: rule[4].to_string(),
+      })
+    })
+    .collect::<Result<_, Error>>()?;
+
+  Ok((
+    workflow[1].to_string(),
+    Node { rules, default: default.to_string() },
+  ))
+}
+
+/* check_workflows - rating starts at `in` and must always end up in `A` or
+`R`: every workflow on the way has to exist and none may send a part back to
+one it already passed through */
+fn check_workflows(workflows: &HashMap<String, Node>) -> Result<(), Error> {
+  fn visit<'a>(
+    workflows: &'a HashMap<String, Node>,
+    name: &'a str,
+    path: &mut Vec<&'a str>,
+    checked: &mut HashSet<&'a str>,
+  ) -> Result<(), Error> {
+    if name == "A" || name == "R" || checked.contains(name) {
+      return Ok(());
+    }
+    if path.contains(&name) {
+      return Err(Error::value(format!(
+        "workflow '{name}' sends parts round in a loop"
+      )));
+    }
+    let Some(node) = workflows.get(name) else {
+      return Err(Error::missing(format!("unknown workflow '{name}'")));
+    };
+
+    path.push(name);
+    let goals = node.rules.iter().map(|rule| rule.goal.as_str());
+    for goal in goals.chain([node.default.as_str()]) {
+      visit(workflows, goal, path, checked)?;
+    }
+    path.pop();
+    checked.insert(name);
+
+    Ok(())
+  }
+
+  visit(workflows, "in", &mut Vec::new(), &mut HashSet::new())
+}
+
+fn dfs(
+  map: &HashMap<String, Node>,
+  current: String,
+  range: XMASBounds,
+) -> Total {
+  // contradictory rules along the way can leave no ratings at all
+  let bounds = [range.x, range.m, range.a, range.s];
+  if bounds.iter().any(|(low, high)| low > high) {
+    return Total::ZERO;
+  } else if &current == "A" {
+    return bounds.iter().map(|(low, high)| high - low + 1).product();
+  } else if &current == "R" {
+    return Total::ZERO;
+  }
+  let mut total = Total::ZERO;
+  let node = map.get(&current).unwrap();
+  let mut range_no = range;
+
+  for rule in node.rules.iter() {
+    let mut range_yes = range_no;
+    // narrow rather than overwrite, so earlier rules on the path still hold
+    let (yes, no) = match rule.closure_name {
+      'x' => (&mut range_yes.x, &mut range_no.x),
+      'm' => (&mut range_yes.m, &mut range_no.m),
+      'a' => (&mut range_yes.a, &mut range_no.a),
+      's' => (&mut range_yes.s, &mut range_no.s),
+      _ => unreachable!(),
+    };
+    if rule.operand {
+      yes.0 = yes.0.max(rule.value + 1);
+      no.1 = no.1.min(rule.value);
+    } else {
+      yes.1 = yes.1.min(rule.value - 1);
+      no.0 = no.0.max(rule.value);
+    }
+    total += dfs(map, rule.goal.clone(), range_yes);
+  }
+  total += dfs(map, node.default.clone(), range_no);
+
+  total
+}
+
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Total, Error> {
+  match part {
+    Part::One => {
+      let mut total_rating_number = Total::ZERO;
+
+      for parts in data.parts.iter() {
+        let mut workflow_id = "in";
+        while workflow_id != "A" && workflow_id != "R" {
+          let workflow =
+            data
+              .workflows
+              .get(workflow_id)
+              .ok_or(Error::missing(format!(
+                "unknown workflow '{workflow_id}'"
+              )))?;
+          workflow_id = workflow.evaluate(parts);
+        }
+        if workflow_id == "A" {
+          total_rating_number += [parts.x, parts.m, parts.a, parts.s]
+            .into_iter()
+            .sum::<Total>();
+        }
+      }
+
+      Ok(total_rating_number)
+    }
+    Part::Two => {
+      let total_combination = dfs(
+        &data.workflows,
+        String::from("in"),
+        XMASBounds { x: (1, 4000), m: (1, 4000), a: (1, 4000), s: (1, 4000) },
+      );
+
+      Ok(total_combination)
+    }
+  }
+}
+
+fn load(result: Result<Total, Error>) -> Result<(), Error> {
+  match result {
+    Ok(value) => println!("{value}"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Total;
+
+  const DAY: u8 = 19;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+
+  const SAMPLE: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+  const MALFORMED: [&str; 10] = [
+    "in{s<1351:A,R}\n\n{x=787,m=2655,a=1222,s=2876",
+    "in{s<1351:A,R}\n{x=787,m=2655,a=1222,s=2876}",
+    "in{s<1351:px,R}\n\n{x=787,m=2655,a=1222,s=2876}",
+    "in{s<1351:px,R}\npx{a<2006:in,A}\n\n{x=787,m=2655,a=1222,s=2876}",
+    "in{s<0:A,R}\n\n{x=787,m=2655,a=1222,s=2876}",
+    "in{s<1351:A,R\n\n{x=787,m=2655,a=1222,s=2876}",
+    "in{q<1351:A,R}\n\n{x=787,m=2655,a=1222,s=2876}",
+    "in{s<1351:A,R}\nin{s<1351:A,R}\n\n{x=787,m=2655,a=1222,s=2876}",
+    "px{s<1351:A,R}\n\n{x=787,m=2655,a=1222,s=2876}",
+    "in{s<1351:A,}\n\n{x=787,m=2655,a=1222,s=2876}",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_workflows_and_parts() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let data = extract()?;
+    assert_eq!(data.workflows.len(), 11);
+    assert_eq!(data.parts.len(), 5);
+    assert_eq!(data.workflows["in"].default, "qqz");
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_rate_parts_and_count_combinations() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    assert_eq!(transform(extract()?, Part::One)?, 19114);
+    assert_eq!(transform(extract()?, Part::Two)?, 167409079868000_u64);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_count_no_combinations_past_contradictory_rules(
+  ) -> Result<(), Error> {
+    mock_src_provider().returns(Ok(
+      "in{x<10:px,A}\npx{x>3000:A,x>20:A,R}\n\n{x=1,m=1,a=1,s=1}".to_string(),
+    ));
+
+    assert_eq!(
+      transform(extract()?, Part::Two)?,
+      3991 * 4000 * 4000 * 4000_u64
+    );
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day19>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
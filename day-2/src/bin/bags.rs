@@ -0,0 +1,126 @@
+use advent::input::{self, Source};
+use advent::{Error, ErrorKind, Format};
+use day_2::query::{self, Query};
+use std::process::ExitCode;
+
+
+/* bags - `bags [--bag <SET>]... [--smallest <ID,...>]... [--json]
+[PATH | -]` checks which games of the input are feasible under each bag,
+naming the draws that rule out the rest, and finds the smallest bag under
+which each list of games is feasible. Without either it checks the bag part 1
+judges by. */
+fn main() -> ExitCode {
+  match parse(std::env::args().skip(1)).and_then(run) {
+    Ok(report) => {
+      print!("{report}");
+      ExitCode::SUCCESS
+    }
+    Err(e) => {
+      eprintln!("{e}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+type Args = (Query, Format, Option<Source>);
+
+fn parse<I>(args: I) -> Result<Args, Error>
+where
+  I: IntoIterator<Item = String>,
+{
+  let usage = |message: String| Error::new(ErrorKind::Usage, message);
+  let (mut query, mut format, mut source) =
+    (Query::default(), Format::Text, None);
+  let mut args = args.into_iter();
+  while let Some(arg) = args.next() {
+    let mut value = |arg: &str| {
+      args
+        .next()
+        .ok_or(usage(format!("[bags] missing value for '{arg}'")))
+    };
+    match arg.as_str() {
+      "--bag" => {
+        let value = value(&arg)?;
+        let bag = value.parse().map_err(|e: Error| {
+          usage(format!("[bags] invalid bag '{value}': {}", e.message))
+        })?;
+        query.bags.push(bag);
+      }
+      "--smallest" => {
+        let value = value(&arg)?;
+        let ids = value
+          .split(',')
+          .map(|id| id.trim().parse())
+          .collect::<Result<_, _>>()
+          .map_err(|_| usage(format!("[bags] invalid game ids '{value}'")))?;
+        query.subsets.push(ids);
+      }
+      "--json" => format = Format::Json,
+      flag if flag.starts_with("--") => {
+        return Err(usage(format!("[bags] unknown option '{flag}'")));
+      }
+      path => {
+        if source.replace(Source::from(path)).is_some() {
+          return Err(usage(format!(
+            "[bags] more than one input given ('{path}')"
+          )));
+        }
+      }
+    }
+  }
+
+  Ok((query, format, source))
+}
+
+fn run((mut query, format, source): Args) -> Result<String, Error> {
+  input::select(source);
+  let games = query::games()?;
+  if query.bags.is_empty() && query.subsets.is_empty() {
+    query.bags.push(query::conditions()?);
+  }
+
+  query::answer(&games, &query, format)
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::path::PathBuf;
+
+  fn parse(list: &[&str]) -> Result<Args, Error> {
+    super::parse(list.iter().map(|s| s.to_string()))
+  }
+
+  #[test]
+  fn it_should_take_several_bags_and_lists_of_games() {
+    let args = ["--bag", "1 red", "--smallest", "1, 3", "--bag", "2 blue"];
+    let (query, format, source) = parse(&args).unwrap();
+
+    assert_eq!(query.bags.len(), 2);
+    assert_eq!(query.bags[1].to_string(), "2 blue");
+    assert_eq!(query.subsets, [vec![1, 3]]);
+    assert_eq!(format, Format::Text);
+    assert_eq!(source, None);
+
+    let (_, format, source) = parse(&["--json", "games.txt"]).unwrap();
+    assert_eq!(format, Format::Json);
+    assert_eq!(source, Some(Source::File(PathBuf::from("games.txt"))));
+  }
+
+  #[test]
+  fn it_should_reject_bad_arguments() {
+    for args in [
+      &["--bag"][..],
+      &["--bag", "red"],
+      &["--smallest", "1,x"],
+      &["a.txt", "b.txt"],
+      &["--bogus"],
+    ] {
+      assert_eq!(
+        parse(args).map(|_| ()).map_err(|e| e.kind),
+        Err(ErrorKind::Usage)
+      );
+    }
+  }
+}
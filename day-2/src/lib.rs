@@ -0,0 +1,560 @@
+mod lib {
+  pub mod query;
+}
+use advent::input::{self, Lines};
+use advent::params;
+use advent::{Error, Part, Solution, Total};
+use std::collections::{BTreeMap, BTreeSet};
+use std::{cmp, fmt, str::FromStr};
+
+pub use lib::query;
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+/* Set - the cubes of each color drawn at once, or held in a bag. A color
+drawn none of is as good as absent. */
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Set(BTreeMap<String, usize>);
+
+/* Palette - the colors a game is played with: those declared in the
+parameters, or else every color found in the input */
+type Palette = BTreeSet<String>;
+
+impl FromStr for Set {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Set::parse(s, None)
+  }
+}
+
+impl Set {
+  /* parse - a set as it is written, `3 blue, 4 red`, in any of the colors of
+  `palette`, or in any color at all without one */
+  pub fn parse(s: &str, palette: Option<&Palette>) -> Result<Set, Error> {
+    let mut set = Set::default();
+
+    for color_spec in s.trim().split(", ") {
+      let (value, color) = Set::get_color_and_value(color_spec)?;
+
+      if palette.is_some_and(|palette| !palette.contains(color)) {
+        return Err(
+          Error::value(format!("unknown color '{color}'")).near(color_spec),
+        );
+      }
+      if set.0.insert(color.to_string(), value).is_some() {
+        return Err(
+          Error::value(format!("'{color}' is drawn twice")).near(color_spec),
+        );
+      }
+    }
+
+    Ok(set)
+  }
+
+  fn get_color_and_value(spec: &str) -> Result<(usize, &str), Error> {
+    let mut iter = spec.split(' ');
+    let Some(value_str) = iter.next().filter(|value| !value.is_empty()) else {
+      return Err(Error::syntax("no color value").near(spec));
+    };
+    let value: usize = value_str.parse().map_err(|e| {
+      Error::value(format!("invalid color value '{value_str}': {e}")).near(spec)
+    })?;
+    let Some(color) = iter.next().filter(|color| !color.is_empty()) else {
+      return Err(Error::syntax("no color after the value").near(spec));
+    };
+    if iter.next().is_some() {
+      return Err(Error::syntax("unexpected data after the color").near(spec));
+    }
+
+    Ok((value, color))
+  }
+
+  /* get - the cubes of `color` in the set */
+  pub fn get(&self, color: &str) -> usize {
+    self.0.get(color).copied().unwrap_or_default()
+  }
+
+  pub fn colors(&self) -> impl Iterator<Item = &String> {
+    self.0.keys()
+  }
+
+  /* iter - each color of the set with its cubes, in the colors' order */
+  pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+    self.0.iter().map(|(color, &value)| (&color[..], value))
+  }
+
+  /* is_ge_strict - whether the set holds more of any color than `other` */
+  pub fn is_ge_strict(&self, other: &Self) -> bool {
+    self
+      .0
+      .iter()
+      .any(|(color, &value)| value > other.get(color))
+  }
+
+  /* union - the smallest set holding both sets */
+  pub fn union(mut self, other: &Set) -> Set {
+    for (color, &value) in &other.0 {
+      let most = self.0.entry(color.clone()).or_default();
+      *most = value.max(*most);
+    }
+
+    self
+  }
+
+  /* power - the product of the set's cubes of each color of `palette` */
+  pub fn power(&self, palette: &Palette) -> Total {
+    palette.iter().map(|color| self.get(color)).product()
+  }
+}
+
+impl fmt::Display for Set {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let cubes: Vec<String> = self
+      .iter()
+      .map(|(color, value)| format!("{value} {color}"))
+      .collect();
+
+    write!(f, "{}", cubes.join(", "))
+  }
+}
+
+// the bag's contents unless `conditions` in the parameters says otherwise
+const CONDITIONS: &str = "12 red, 13 green, 14 blue";
+// the parameters the day takes
+const PARAMS: [&str; 2] = ["conditions", "palette"];
+
+/* conditions - the bag's contents, as a set is written:
+`12 red, 13 green, 14 blue` */
+fn conditions() -> Result<Set, Error> {
+  params::load(PARAMS_PATH, &PARAMS)?.get_with(
+    "conditions",
+    Set::from_str(CONDITIONS)?,
+    Set::from_str,
+  )
+}
+
+/* palette - the colors declared in the parameters, `red, green, blue`, if
+any. A color outside them is refused when it is drawn. */
+fn palette() -> Result<Option<Palette>, Error> {
+  params::load(PARAMS_PATH, &PARAMS)?.get_with("palette", None, |spec| {
+    let mut palette = Palette::new();
+    for color in spec.split(',').map(str::trim) {
+      if color.is_empty() || color.contains(' ') {
+        return Err(Error::syntax(format!("invalid color '{color}'")));
+      }
+      if !palette.insert(color.to_string()) {
+        return Err(Error::value(format!("'{color}' is declared twice")));
+      }
+    }
+
+    Ok(Some(palette))
+  })
+}
+
+/* discover - the colors drawn in any of `games` */
+fn discover(games: &[Game]) -> Palette {
+  let sets = games.iter().flat_map(|game| &game.sets);
+  sets.flat_map(Set::colors).cloned().collect()
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Game {
+  id: usize,
+  sets: Vec<Set>,
+}
+
+impl Clone for Game {
+  fn clone(&self) -> Self {
+    Self { id: self.id, sets: self.sets.clone() }
+  }
+}
+
+impl Ord for Game {
+  fn cmp(&self, other: &Self) -> cmp::Ordering {
+    self.id.cmp(&other.id)
+  }
+}
+impl PartialOrd for Game {
+  fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Eq for Game {}
+
+impl Game {
+  fn get_id_from_string(s: &str) -> Result<usize, Error> {
+    // Assuming the format is "Game n:" where n is a number
+    let id_parts: Vec<&str> = s.split_whitespace().collect();
+    if id_parts.len() != 2 {
+      return Err(Error::syntax("expected 'Game <id>'").near(s));
+    }
+
+    id_parts[1].parse::<usize>().map_err(|_| {
+      Error::value(format!("invalid game id '{}'", id_parts[1]))
+        .near(id_parts[1])
+    })
+  }
+
+  fn get_sets_from_string(
+    s: &str,
+    palette: Option<&Palette>,
+  ) -> Result<Vec<Set>, Error> {
+    s.split(';').map(|set| Set::parse(set, palette)).collect()
+  }
+
+  /* parse - a game, `Game <id>: <sets>`, drawn from the colors of `palette`
+  when there is one */
+  fn parse(s: &str, palette: Option<&Palette>) -> Result<Game, Error> {
+    let Some((id, sets)) = s.split_once(':') else {
+      return Err(Error::syntax("expected 'Game <id>: <sets>'"));
+    };
+
+    let id = Game::get_id_from_string(id)?;
+    let sets: Vec<Set> = Game::get_sets_from_string(sets, palette)?;
+
+    Ok(Game { id, sets })
+  }
+
+  /* minimum - the smallest bag the game could have been played with */
+  fn minimum(&self) -> Set {
+    self
+      .sets
+      .iter()
+      .fold(Set::default(), |acc, set| acc.union(set))
+  }
+}
+
+impl FromStr for Game {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Game::parse(s, None)
+  }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+  type ProblemDefinition = Vec<Game>;
+  type Consequent = Vec<Total>;
+
+  const DAY: u8 = 2;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    get_index_sum(consequent).map(|sum| sum.to_string())
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(DATA_PATH)?, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  input::read(DATA_PATH)
+}
+
+fn extract() -> Result<Vec<Game>, Error> {
+  parse_games(&src_provider()?, palette()?.as_ref())
+}
+
+fn parse_games(
+  input: &str,
+  palette: Option<&Palette>,
+) -> Result<Vec<Game>, Error> {
+  input
+    .lines()
+    .enumerate()
+    .map(|(i, line)| {
+      Game::parse(line, palette).map_err(|e| e.on_line(i + 1, line))
+    })
+    .collect::<Result<Vec<_>, _>>()
+}
+
+/* stream - each game transformed on its own as soon as it is read, keeping
+only the running sum. Without a declared palette a game's power depends on
+the colors of every other game, so part 2 keeps a sum for each combination of
+colors drawn instead, and only those with every color count in the end. */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let conditions = conditions()?;
+  let declared = palette()?;
+  let mut sum = Total::ZERO;
+  let mut powers: BTreeMap<Palette, Total> = BTreeMap::new();
+
+  for (i, line) in lines.enumerate() {
+    let line = line?;
+    let game = Game::parse(&line, declared.as_ref())
+      .map_err(|e| e.on_line(i + 1, &line))?;
+    match part {
+      Part::One => sum += get_index_sum(&possible(&[game], &conditions))?,
+      Part::Two => {
+        let minimum = game.minimum();
+        let colors: Palette = minimum.colors().cloned().collect();
+        *powers.entry(colors.clone()).or_default() += minimum.power(&colors);
+      }
+    }
+  }
+  if part == Part::Two {
+    let palette = declared.unwrap_or_else(|| {
+      powers
+        .keys()
+        .flat_map(|colors| colors.iter().cloned())
+        .collect()
+    });
+    sum = powers
+      .into_iter()
+      .filter(|(colors, _)| colors.len() == palette.len())
+      .map(|(_, power)| power)
+      .sum();
+  }
+
+  Ok(sum.to_string())
+}
+
+/* transform - the ids of the games possible under the conditions in part
+1, the power of each game's smallest bag in part 2 */
+fn transform(data: Vec<Game>, part: Part) -> Result<Vec<Total>, Error> {
+  Ok(match part {
+    Part::One => possible(&data, &conditions()?),
+    Part::Two => powers(&data, &palette()?.unwrap_or_else(|| discover(&data))),
+  })
+}
+
+/* possible - the ids of the games no draw of which holds more of a color
+than `conditions` */
+fn possible(data: &[Game], conditions: &Set) -> Vec<Total> {
+  data
+    .iter()
+    .filter(|game| !game.sets.iter().any(|set| set.is_ge_strict(conditions)))
+    .map(|game| Total::from(game.id))
+    .collect()
+}
+
+/* powers - the power of each game's smallest bag over the colors of
+`palette`, zero for a game that never draws one of them */
+fn powers(data: &[Game], palette: &Palette) -> Vec<Total> {
+  data
+    .iter()
+    .map(|game| game.minimum().power(palette))
+    .collect()
+}
+
+fn load(result: Result<Vec<Total>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(indices) => println!("result: {}", get_index_sum(&indices)?),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+fn get_index_sum(indices: &[Total]) -> Result<Total, Error> {
+  Ok(indices.iter().sum())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const MALFORMED: [&str; 5] = [
+    "Game 1: 3 blue, 4",
+    "Game 1 3 blue, 4 red",
+    "Game x: 3 blue",
+    "Game 1: 3 blue;; 2 red",
+    "Game 1: 3 blue\r\nGame",
+  ];
+
+  fn set(s: &str) -> Set {
+    Set::from_str(s).unwrap()
+  }
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract() {
+    // Test that the extract function correctly reads from a string and converts it to a vector of Game structs.
+
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame \
+                 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+    mock_src_provider().returns(Ok(input.to_string()));
+
+    let mut expected = vec![
+      Game {
+        id: 1,
+        sets: vec![
+          set("4 red, 3 blue"),
+          set("1 red, 2 green, 6 blue"),
+          set("2 green"),
+        ],
+      },
+      Game {
+        id: 2,
+        sets: vec![
+          set("2 green, 1 blue"),
+          set("1 red, 3 green, 4 blue"),
+          set("1 green, 1 blue"),
+        ],
+      },
+    ];
+
+    let mut result = extract().unwrap();
+    result.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(&result, &expected);
+  }
+
+  #[test]
+  fn it_should_position_an_unknown_color() {
+    let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple; 3 green";
+    let palette = ["red", "green", "blue"].map(String::from).into();
+
+    assert!(parse_games(input, None).is_ok());
+    let error = parse_games(input, Some(&palette)).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 17 }));
+    assert_eq!(
+      error.snippet.as_deref(),
+      Some("Game 2: 1 blue, 2 purple; 3 green")
+    );
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  fn it_should_transform() {
+    // Test that the transform function correctly filters a vector of Game structs based on a condition.
+
+    let input = vec![
+      Game {
+        id: 1,
+        sets: vec![
+          set("4 red, 3 blue"),
+          set("1 red, 2 green, 16 blue"),
+          set("2 green"),
+        ],
+      },
+      Game {
+        id: 2,
+        sets: vec![
+          set("2 green, 1 blue"),
+          set("1 red, 3 green, 4 blue"),
+          set("1 green, 1 blue"),
+        ],
+      },
+    ];
+
+    let mut expected = vec![2]; // Game with id 2 is the only one that meets the condition.
+
+    let mut result = transform(input.clone(), Part::One).unwrap();
+    result.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(&result, &expected);
+
+    // Part two yields the power of the minimum set of each game instead.
+    let result = transform(input.clone(), Part::Two).unwrap();
+    assert_eq!(result, vec![128, 12]);
+
+    // a bag given in the parameters holds enough blue for game 1 too
+    let bag = set("12 red, 13 green, 16 blue");
+    assert_eq!(possible(&input, &bag), vec![1, 2]);
+  }
+
+  #[test]
+  fn it_should_play_with_any_palette() {
+    let input = "Game 1: 2 teal, 1 red, 3 gold; 4 teal\nGame 2: 5 red, 1 \
+                 teal, 2 gold, 7 plum\nGame 3: 2 red, 2 teal, 2 gold";
+    let games = parse_games(input, None).unwrap();
+    let palette = discover(&games);
+    assert_eq!(palette.len(), 4);
+
+    // only game 2 draws plum, so the others' smallest bags hold none
+    assert_eq!(powers(&games, &palette), vec![0, 70, 0]);
+    let palette = ["red", "teal", "gold"].map(String::from).into();
+    assert_eq!(powers(&games, &palette), vec![12, 10, 8]);
+    assert_eq!(possible(&games, &set("5 red, 4 teal, 3 gold")), vec![1, 3]);
+
+    assert!(Set::from_str("1 red, 2 red").is_err());
+    assert!(Set::from_str("1 red, 2 green, 3 blue, 4 plum").is_ok());
+  }
+
+  #[test]
+  fn it_should_stream_the_same_sums() {
+    let inputs = [
+      "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue\nGame 2: 1 blue, 20 \
+       green\nGame 3: 8 green, 6 blue, 2 red",
+      "Game 1: 2 teal, 1 red\nGame 2: 5 red, 1 plum; 2 teal\nGame 3: 3 plum, \
+       1 red, 0 teal\nGame 4: 2 red, 2 plum, 4 teal",
+    ];
+
+    for input in inputs {
+      for part in [Part::One, Part::Two] {
+        let games = parse_games(input, None);
+        let solved = games.and_then(|data| transform(data, part));
+        let streamed = stream(Lines::new(input.as_bytes()), part);
+        assert_eq!(streamed, solved.and_then(|ids| Day2::answer(&ids)));
+      }
+    }
+  }
+
+  // MARK load
+  #[test]
+  fn it_should_get_index_sum() {
+    // Test that the load function correctly prints the sum of the totals.
+
+    let input = [2, 3, 4].map(Total::from);
+    let expected = 9;
+
+    let result = get_index_sum(&input);
+    assert!(result.is_ok_and(|suma| suma == expected));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day2>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,270 @@
+use std::collections::BTreeSet;
+
+use advent::measure::{json_object, json_string};
+use advent::{Error, Format, Part, Solution};
+
+use crate::{Day2, Game, Set};
+
+
+/* Violation - a draw of a game showing more of a color than a bag holds;
+draws count from 1 */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+  pub draw: usize,
+  pub color: String,
+  pub drawn: usize,
+  pub held: usize,
+}
+
+/* Feasibility - whether a game could have been played with a bag: it could
+unless some draw violates it, and then every violation is listed */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Feasibility {
+  pub game: usize,
+  pub violations: Vec<Violation>,
+}
+
+/* Query - what to ask of the games: which are feasible under each of
+`bags`, and the smallest bag each of `subsets` of game ids is feasible
+under */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Query {
+  pub bags: Vec<Set>,
+  pub subsets: Vec<Vec<usize>>,
+}
+
+impl Feasibility {
+  pub fn is_feasible(&self) -> bool {
+    self.violations.is_empty()
+  }
+}
+
+/* games - the games of the selected input, as the day extracts them */
+pub fn games() -> Result<Vec<Game>, Error> {
+  Day2::extract(Part::One)
+}
+
+/* conditions - the bag part 1 judges the games by */
+pub fn conditions() -> Result<Set, Error> {
+  crate::conditions()
+}
+
+/* feasibility - each game checked against `bag`, in order */
+pub fn feasibility(games: &[Game], bag: &Set) -> Vec<Feasibility> {
+  games
+    .iter()
+    .map(|game| {
+      let draws = (1..).zip(&game.sets);
+      let violations = draws
+        .flat_map(|(draw, set)| {
+          set.iter().filter_map(move |(color, drawn)| {
+            let held = bag.get(color);
+            (drawn > held).then(|| Violation {
+              draw,
+              color: color.to_string(),
+              drawn,
+              held,
+            })
+          })
+        })
+        .collect();
+
+      Feasibility { game: game.id, violations }
+    })
+    .collect()
+}
+
+/* smallest_bag - the least of every color that makes all the games with
+`ids` feasible, each id naming a game of `games` */
+pub fn smallest_bag(games: &[Game], ids: &[usize]) -> Result<Set, Error> {
+  ids.iter().try_fold(Set::default(), |bag, id| {
+    match games.iter().find(|game| game.id == *id) {
+      Some(game) => Ok(bag.union(&game.minimum())),
+      None => Err(Error::missing(format!("there is no game {id}"))),
+    }
+  })
+}
+
+/* answer - the query run on `games`, reported in `format`: as text, a
+paragraph per bag, listing every violation, then a line per subset, or as
+JSON, an object per bag and per subset, a line each */
+pub fn answer(
+  games: &[Game],
+  query: &Query,
+  format: Format,
+) -> Result<String, Error> {
+  let mut report = String::new();
+  for bag in &query.bags {
+    let feasibility = feasibility(games, bag);
+    report += &match format {
+      Format::Json => bag_json(bag, &feasibility),
+      _ => bag_text(bag, &feasibility),
+    };
+  }
+  for ids in &query.subsets {
+    let bag = smallest_bag(games, ids)?;
+    report += &match format {
+      Format::Json => json_object(&[
+        ("games", json_list(ids.iter())),
+        ("smallest_bag", json_string(&bag.to_string())),
+      ]),
+      _ => format!("smallest bag for games {}: {bag}", list(ids.iter())),
+    };
+    report.push('\n');
+  }
+
+  Ok(report)
+}
+
+fn bag_text(bag: &Set, feasibility: &[Feasibility]) -> String {
+  let feasible = feasible(feasibility);
+  let mut text = match feasible.is_empty() {
+    true => format!("bag {bag}: no feasible game\n"),
+    false => format!(
+      "bag {bag}: feasible games {}, their ids summing to {}\n",
+      list(feasible.iter()),
+      feasible.iter().sum::<usize>()
+    ),
+  };
+  for game in feasibility.iter().filter(|game| !game.is_feasible()) {
+    for violation in &game.violations {
+      text += &format!(
+        "  game {}: draw {} shows {} {}, the bag holds {}\n",
+        game.game,
+        violation.draw,
+        violation.drawn,
+        violation.color,
+        violation.held
+      );
+    }
+  }
+
+  text
+}
+
+fn bag_json(bag: &Set, feasibility: &[Feasibility]) -> String {
+  let infeasible = feasibility.iter().filter(|game| !game.is_feasible());
+  let infeasible: Vec<String> = infeasible
+    .map(|game| {
+      let violations: Vec<String> = game
+        .violations
+        .iter()
+        .map(|violation| {
+          json_object(&[
+            ("draw", violation.draw.to_string()),
+            ("color", json_string(&violation.color)),
+            ("drawn", violation.drawn.to_string()),
+            ("held", violation.held.to_string()),
+          ])
+        })
+        .collect();
+
+      json_object(&[
+        ("game", game.game.to_string()),
+        ("violations", format!("[{}]", violations.join(","))),
+      ])
+    })
+    .collect();
+
+  json_object(&[
+    ("bag", json_string(&bag.to_string())),
+    ("feasible", json_list(feasible(feasibility).iter())),
+    ("infeasible", format!("[{}]", infeasible.join(","))),
+  ]) + "\n"
+}
+
+fn feasible(feasibility: &[Feasibility]) -> Vec<usize> {
+  let feasible = feasibility.iter().filter(|game| game.is_feasible());
+  let ids: BTreeSet<usize> = feasible.map(|game| game.game).collect();
+
+  ids.into_iter().collect()
+}
+
+fn list<'a>(ids: impl Iterator<Item = &'a usize>) -> String {
+  ids.map(usize::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn json_list<'a>(ids: impl Iterator<Item = &'a usize>) -> String {
+  format!(
+    "[{}]",
+    ids.map(usize::to_string).collect::<Vec<_>>().join(",")
+  )
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::parse_games;
+  use advent::ErrorKind;
+
+  const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green";
+
+  fn bag(s: &str) -> Set {
+    s.parse().unwrap()
+  }
+
+  #[test]
+  fn it_should_name_the_draws_that_rule_a_game_out() {
+    let games = parse_games(GAMES, None).unwrap();
+    let feasibility = feasibility(&games, &bag("12 red, 13 green, 5 blue"));
+
+    assert!(feasibility[1].is_feasible());
+    assert_eq!(feasibility[0].violations.len(), 1);
+    assert_eq!(
+      feasibility[2].violations,
+      [
+        Violation { draw: 1, color: "blue".into(), drawn: 6, held: 5 },
+        Violation { draw: 1, color: "red".into(), drawn: 20, held: 12 },
+      ]
+    );
+  }
+
+  #[test]
+  fn it_should_find_the_smallest_bag_for_some_games() {
+    let games = parse_games(GAMES, None).unwrap();
+
+    assert_eq!(
+      smallest_bag(&games, &[1, 2]),
+      Ok(bag("4 red, 3 green, 6 blue"))
+    );
+    assert_eq!(smallest_bag(&games, &[]), Ok(Set::default()));
+    let error = smallest_bag(&games, &[1, 9]).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Missing);
+  }
+
+  #[test]
+  fn it_should_answer_as_text_or_json() {
+    let games = parse_games(GAMES, None).unwrap();
+    let query = Query {
+      bags: vec![bag("12 red, 13 green, 14 blue")],
+      subsets: vec![vec![1, 2]],
+    };
+
+    let text = [
+      "bag 14 blue, 13 green, 12 red: feasible games 1, 2, their ids summing \
+       to 3",
+      "  game 3: draw 1 shows 20 red, the bag holds 12",
+      "smallest bag for games 1, 2: 6 blue, 3 green, 4 red",
+    ];
+    assert_eq!(
+      answer(&games, &query, Format::Text),
+      Ok(text.join("\n") + "\n")
+    );
+
+    let json = [
+      concat!(
+        r#"{"bag":"14 blue, 13 green, 12 red","feasible":[1,2],"#,
+        r#""infeasible":[{"game":3,"violations":[{"draw":1,"#,
+        r#""color":"red","drawn":20,"held":12}]}]}"#
+      ),
+      r#"{"games":[1,2],"smallest_bag":"6 blue, 3 green, 4 red"}"#,
+    ];
+    assert_eq!(
+      answer(&games, &query, Format::Json),
+      Ok(json.join("\n") + "\n")
+    );
+  }
+}
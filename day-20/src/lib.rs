@@ -0,0 +1,309 @@
+mod lib {
+  pub mod machine;
+  pub mod network;
+  pub mod prelude;
+}
+use advent::{Check, Error, Part, Solution, Total};
+use lib::{
+  machine::{
+    Broadcaster, Conjunction, FlipFlop, FromNode, Machine, MachineType, Node,
+    Output,
+  },
+  network::Network,
+  prelude::Address,
+};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample1.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+
+pub struct ProblemDefinition {
+  network: Network,
+}
+type Consequent = (Total, Total);
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  let mut specifications: Vec<(MachineType, Node)> = Vec::new();
+  for (i, line) in src_provider()?.lines().enumerate() {
+    let parts: Vec<&str> = line.split(" -> ").collect();
+    if parts.len() != 2 {
+      return Err(
+        Error::syntax("expected '<module> -> <destinations>'")
+          .on_line(i + 1, line),
+      );
+    }
+
+    let machine_type = match parts[0].chars().next() {
+      Some('%') => MachineType::FlipFlop,
+      Some('&') => MachineType::Conjunction,
+      Some('b') => MachineType::Broadcaster,
+      _ => MachineType::Output,
+    };
+
+    let address = parts[0]
+      .trim_start_matches('%')
+      .trim_start_matches('&')
+      .to_string();
+    let output: Vec<Address> = parts[1]
+      .split(',')
+      .map(|output| output.trim().to_string())
+      .collect();
+
+    let mut names = std::iter::once(&address).chain(&output);
+    if let Some(name) = names
+      .find(|name| name.is_empty() || !name.chars().all(char::is_alphanumeric))
+    {
+      return Err(
+        Error::syntax(format!("invalid module name '{name}'"))
+          .near(name)
+          .on_line(i + 1, line),
+      );
+    }
+    if specifications
+      .iter()
+      .any(|(_, node)| node.address == address)
+    {
+      return Err(
+        Error::value(format!("module '{address}' is defined twice"))
+          .near(&address)
+          .on_line(i + 1, line),
+      );
+    }
+
+    let node = Node {
+      address,
+      input: Vec::new(), // we fill this in later
+      output,
+    };
+
+    specifications.push((machine_type, node));
+  }
+
+  let mut machines: Vec<Box<dyn Machine>> = Vec::new();
+  for (machine_type, node) in specifications.iter() {
+    let mut inputs: Vec<Address> = Vec::new();
+    // fill the input
+    for (_, other_node) in specifications.iter() {
+      if other_node.address == node.address {
+        continue;
+      }
+      if other_node.output.contains(&node.address) {
+        inputs.push(other_node.address.clone());
+      }
+    }
+    let mut updated_node = node.clone();
+    updated_node.input = inputs;
+
+    let machine: Box<dyn Machine> = match machine_type {
+      MachineType::FlipFlop => Box::new(FlipFlop::from_node(&updated_node)),
+      MachineType::Conjunction => {
+        Box::new(Conjunction::from_node(&updated_node))
+      }
+      MachineType::Broadcaster => {
+        Box::new(Broadcaster::from_node(&updated_node))
+      }
+      MachineType::Output => Box::new(Output::from_node(&updated_node)),
+    };
+    machines.push(machine);
+  }
+
+  // Create the network from the machines
+  let network = Network::from(machines);
+
+  Ok(ProblemDefinition { network })
+}
+
+fn transform(
+  mut data: ProblemDefinition,
+  part: Part,
+) -> Result<Consequent, Error> {
+  match part {
+    Part::One => {
+      let (low, high) = data.network.run(1000)?;
+      Ok((low.into(), high.into()))
+    }
+    Part::Two => data.network.run_to_output(),
+  }
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok((low, high)) => {
+      println!("low_{low} × high_{high} = {}", low.clone() * &high)
+    }
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 20;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    let (low, high) = consequent;
+
+    Ok((low.clone() * high).to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, part: Part) -> Vec<Check> {
+    data.network.check(part)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::ErrorKind;
+
+  const SAMPLE_1: &str = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+  const SAMPLE_2: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+  const MALFORMED: [&str; 5] = [
+    "broadcaster -> a, b\n%a -> b\n%b ->",
+    "broadcaster -> a\n%a -> b\n%a -> b\n%b -> a",
+    "broadcaster -> a,,b\n%a -> b\n%b -> a",
+    "broadcaster -> a\n%a b\n%b -> a",
+    "broadcaster -> a\n%a -> b\n%b -> a!",
+  ];
+
+  // MARK extract
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_count_pulses_in_part_one() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE_1.to_string()));
+
+    let (low, high) = transform(extract()?, Part::One)?;
+    assert_eq!(low * high, 32000000);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_count_pulses_through_an_untyped_output() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE_2.to_string()));
+
+    let (low, high) = transform(extract()?, Part::One)?;
+    assert_eq!(low * high, 11687500);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_part_two_without_an_output() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE_1.to_string()));
+
+    let error = transform(extract()?, Part::Two).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Missing);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_refuse_part_two_with_several_outputs() -> Result<(), Error> {
+    let input = "broadcaster -> a\n%a -> x, y";
+    mock_src_provider().returns(Ok(input.to_string()));
+
+    let error = transform(extract()?, Part::Two).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Unsupported);
+    assert!(error.message.starts_with("a sends to more than one"));
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_what_feeds_the_output() -> Result<(), Error> {
+    let mut inputs = [SAMPLE_1, SAMPLE_2].into_iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or("").to_string()));
+    let violations = |checks: Vec<Check>| -> Vec<Option<String>> {
+      checks.into_iter().map(|check| check.violation).collect()
+    };
+
+    assert_eq!(
+      violations(extract()?.network.check(Part::Two)),
+      [
+        None,
+        Some("no module sends to an undefined one".to_string())
+      ]
+    );
+    assert_eq!(
+      violations(extract()?.network.check(Part::Two)),
+      [None, None, None, Some("a is a flip-flop".to_string())]
+    );
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day20>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -12,6 +12,17 @@ pub enum MachineType {
   Output,
 }
 
+impl std::fmt::Display for MachineType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      MachineType::Broadcaster => write!(f, "the broadcaster"),
+      MachineType::FlipFlop => write!(f, "a flip-flop"),
+      MachineType::Conjunction => write!(f, "a conjunction"),
+      MachineType::Output => write!(f, "an untyped module"),
+    }
+  }
+}
+
 #[derive(Clone, Default)]
 pub struct Node {
   pub address: Address,
@@ -27,9 +38,7 @@ pub trait Machine: Stateful {
   fn get_address(&self) -> Address;
   fn get_type(&self) -> MachineType;
   fn on_signal(&mut self, signal: Signal) -> Option<Vec<Signal>>;
-  #[cfg(feature = "part2")]
   fn reports_to(&self, address: &Address) -> bool;
-  #[cfg(feature = "part2")]
   fn get_unique_outputs(&self, addresses: &[Address]) -> Vec<Address>;
 }
 
@@ -47,11 +56,9 @@ impl FromNode for Broadcaster {
 }
 
 impl Machine for Broadcaster {
-  #[cfg(feature = "part2")]
   fn reports_to(&self, address: &Address) -> bool {
     self.0.output.contains(address)
   }
-  #[cfg(feature = "part2")]
   fn get_unique_outputs(&self, addresses: &[Address]) -> Vec<Address> {
     self
       .0
@@ -83,7 +90,7 @@ impl Machine for Broadcaster {
         .map(|address| {
           let to = address.clone();
 
-          // eprintln!("{} -{:?}-> {to}", self.0.address, signal.pulse);
+          advent::trace!("{} -{:?}-> {to}", self.0.address, signal.pulse);
 
           Signal { pulse: signal.pulse, to, ..Signal::default() }
         })
@@ -111,11 +118,9 @@ impl FromNode for FlipFlop {
 }
 
 impl Machine for FlipFlop {
-  #[cfg(feature = "part2")]
   fn reports_to(&self, address: &Address) -> bool {
     self.node.output.contains(address)
   }
-  #[cfg(feature = "part2")]
   fn get_unique_outputs(&self, addresses: &[Address]) -> Vec<Address> {
     self
       .node
@@ -155,7 +160,7 @@ impl Machine for FlipFlop {
         .map(|address| {
           let to = address.clone();
 
-          // eprintln!("%{} -{:?}-> {to}", self.node.address, pulse);
+          advent::trace!("%{} -{:?}-> {to}", self.node.address, pulse);
 
           Signal { pulse, to, ..Signal::default() }
         })
@@ -189,11 +194,9 @@ impl FromNode for Conjunction {
 }
 
 impl Machine for Conjunction {
-  #[cfg(feature = "part2")]
   fn reports_to(&self, address: &Address) -> bool {
     self.node.output.contains(address)
   }
-  #[cfg(feature = "part2")]
   fn get_unique_outputs(&self, addresses: &[Address]) -> Vec<Address> {
     self
       .node
@@ -213,9 +216,7 @@ impl Machine for Conjunction {
   }
 
   fn on_signal(&mut self, signal: Signal) -> Option<Vec<Signal>> {
-    let Some(from) = signal.from else {
-      return None;
-    };
+    let from = signal.from?;
     if !self.node.input.contains(&from) {
       return None;
     }
@@ -235,7 +236,7 @@ impl Machine for Conjunction {
         .map(|address| {
           let to = address.clone();
 
-          // eprintln!("&{} -{:?}-> {to}", self.node.address, pulse);
+          advent::trace!("&{} -{:?}-> {to}", self.node.address, pulse);
 
           Signal { pulse, to, ..Signal::default() }
         })
@@ -266,11 +267,9 @@ impl FromNode for Output {
 }
 
 impl Machine for Output {
-  #[cfg(feature = "part2")]
   fn reports_to(&self, address: &Address) -> bool {
     self.0.output.contains(address)
   }
-  #[cfg(feature = "part2")]
   fn get_unique_outputs(&self, addresses: &[Address]) -> Vec<Address> {
     self
       .0
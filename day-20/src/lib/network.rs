@@ -1,3 +1,4 @@
+use advent::{Check, Error, ErrorKind, Part, Total};
 use indexmap::IndexMap;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, VecDeque};
@@ -12,11 +13,9 @@ pub struct Network {
   signal_queue: VecDeque<Signal>,
   memory: IndexMap<(Signal, u64), (usize, (usize, usize))>,
   cycle_memory: Vec<(usize, (usize, usize))>,
-  #[cfg(feature = "part2")]
   input_memory: HashMap<Address, usize>,
   nodes: HashMap<Address, Box<dyn Machine>>,
   broadcaster: Option<Address>,
-  #[cfg(feature = "part2")]
   outputs: Vec<Address>,
   log: SignalLog,
   current_step: usize,
@@ -35,66 +34,20 @@ impl From<Vec<Box<dyn Machine>>> for Network {
       acc
     });
 
-    #[cfg(not(feature = "part2"))]
-    {
-      Network { nodes, broadcaster, ..Network::default() }
-    }
-    #[cfg(feature = "part2")]
-    {
-      let mut outputs: Vec<Address> = Vec::new();
-      let addresses: Vec<Address> = nodes.keys().cloned().collect();
-      let output = nodes.values().fold(None, |mut acc, node| {
-        let node_outputs = node.get_unique_outputs(&addresses);
-        match node_outputs.len() {
-          1 => acc = Some(node_outputs.first().unwrap().clone()),
-          0 => (),
-          _ => unimplemented!(),
-        }
-
-        acc
-      });
-      if let Some(o) = output {
-        if let Some(interim) = nodes
-          .iter()
-          .filter_map(|(address, node)| {
-            if node.reports_to(&o) {
-              Some(address)
-            } else {
-              None
-            }
-          })
-          .next()
-        {
-          outputs = nodes
-            .iter()
-            .filter_map(|(address, node)| {
-              if node.reports_to(interim) {
-                Some(address)
-              } else {
-                None
-              }
-            })
-            .cloned()
-            .collect();
-        }
-      }
-
-      Network { nodes, broadcaster, outputs, ..Network::default() }
-    }
+    Network { nodes, broadcaster, ..Network::default() }
   }
 }
 
 impl Network {
-  #[cfg(not(feature = "part2"))]
-  pub fn run(&mut self, n: usize) -> Result<(usize, usize), String> {
+  pub fn run(&mut self, n: usize) -> Result<(usize, usize), Error> {
     if self.broadcaster.is_none() {
-      return Err("no broadcaster in network".to_string());
+      return Err(Error::missing("no broadcaster in network"));
     };
 
     for i in 0..n {
       self.current_step = i + 1;
       if let Some(memoized_signal) = self.on_button_press()? {
-        eprintln!("cycle detected!");
+        advent::info!("cycle detected after {} presses", i + 1);
         self.adjust_count_to_cycle(memoized_signal, i + 1, n);
 
         // Return early with adjusted log values
@@ -105,27 +58,29 @@ impl Network {
     Ok((self.log.low, self.log.high))
   }
 
-  #[cfg(feature = "part2")]
-  pub fn run(&mut self) -> Result<(usize, usize), String> {
+  pub fn run_to_output(&mut self) -> Result<(Total, Total), Error> {
     if self.broadcaster.is_none() {
-      return Err("no broadcaster in network".to_string());
+      return Err(Error::missing("no broadcaster in network"));
     };
+    self.outputs = self.find_outputs()?;
+    if self.outputs.is_empty() {
+      return Err(Error::missing("no output to watch in network"));
+    }
 
     for i in 0..10000 {
       self.current_step = i + 1;
       if self.on_button_press()?.is_some() {
         let lcm =
           get_lcm(self.input_memory.values().copied().collect::<Vec<_>>())
-            .unwrap();
+            .ok_or(Error::no_solution("no output was reached"))?;
 
-        return Ok((1, lcm));
+        return Ok((Total::ONE, lcm));
       }
     }
 
-    Err("no cycle found".to_string())
+    Err(Error::no_solution("no cycle found"))
   }
 
-  #[cfg(not(feature = "part2"))]
   fn adjust_count_to_cycle(
     &mut self,
     memoized_signal: Signal,
@@ -148,33 +103,159 @@ impl Network {
     let complete_cycle_count = steps_remaining / step_cycle_length;
     let remaining_after_cycles = steps_remaining % step_cycle_length;
 
-    // let cycle_length = self.memory.len() - j;
-    // dbg!(
-    //   max_steps,
-    //   current_step,
-    //   step_cycle_length,
-    //   cycle_highs,
-    //   cycle_lows,
-    //   complete_cycle_count,
-    //   cycle_length,
-    //   remaining_after_cycles
-    // );
+    advent::debug!(
+      "cycle of {step_cycle_length} presses from press {step}: {cycle_lows} \
+       low and {cycle_highs} high each, {complete_cycle_count} more cycles \
+       and {remaining_after_cycles} presses left of {max_steps}"
+    );
 
     self.log.low += complete_cycle_count * cycle_lows + remaining_after_cycles;
     self.log.high +=
       complete_cycle_count * cycle_highs + remaining_after_cycles;
   }
 
-  fn on_button_press(&mut self) -> Result<Option<Signal>, String> {
+  /* check - part 2 watches the modules feeding the conjunction in front of
+  the final output, each of which fires on its own cycle, so it relies on a
+  single module sending to a single undefined output, and on that module being
+  a conjunction fed only by conjunctions */
+  pub fn check(&self, part: Part) -> Vec<Check> {
+    let mut checks = vec![Check::that(
+      "there is a broadcaster",
+      self.broadcaster.is_some(),
+      || "none is defined".to_string(),
+    )];
+    if part == Part::One {
+      return checks;
+    }
+
+    let addresses: Vec<Address> = self.nodes.keys().cloned().collect();
+    let mut senders: Vec<(&Address, Vec<Address>)> = self
+      .nodes
+      .iter()
+      .map(|(address, node)| (address, node.get_unique_outputs(&addresses)))
+      .filter(|(_, outputs)| !outputs.is_empty())
+      .collect();
+    senders.sort();
+    let last = match &senders[..] {
+      [(sender, outputs)] if outputs.len() == 1 => Some(*sender),
+      _ => None,
+    };
+    checks.push(Check::that(
+      "one module sends to a single undefined output",
+      last.is_some(),
+      || match &senders[..] {
+        [] => "no module sends to an undefined one".to_string(),
+        [(sender, outputs)] => {
+          format!("{sender} sends to {}", outputs.join(", "))
+        }
+        _ => format!(
+          "{} all send to undefined modules",
+          senders
+            .iter()
+            .map(|(sender, _)| sender.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+        ),
+      },
+    ));
+    let Some(last) = last else {
+      return checks;
+    };
+
+    let kind = |address: &Address| self.nodes[address].get_type();
+    checks.push(Check::that(
+      format!("{last} is a conjunction"),
+      kind(last) == MachineType::Conjunction,
+      || format!("it is {}", kind(last)),
+    ));
+    let mut feeders: Vec<&Address> = self
+      .nodes
+      .iter()
+      .filter(|(_, node)| node.reports_to(last))
+      .map(|(address, _)| address)
+      .collect();
+    feeders.sort();
+    let other = feeders
+      .into_iter()
+      .find(|address| kind(address) != MachineType::Conjunction);
+    checks.push(Check::that(
+      format!("{last} is fed only by conjunctions"),
+      other.is_none(),
+      || {
+        other
+          .map_or(String::new(), |other| format!("{other} is {}", kind(other)))
+      },
+    ));
+
+    checks
+  }
+
+  /* the machines feeding the conjunction in front of the final output. A
+  module sending to more than one undefined module leaves no single output to
+  watch, which part 2 cannot solve. */
+  fn find_outputs(&self) -> Result<Vec<Address>, Error> {
+    let nodes = &self.nodes;
+    let addresses: Vec<Address> = nodes.keys().cloned().collect();
+    let mut output = None;
+    for (address, node) in nodes {
+      let node_outputs = node.get_unique_outputs(&addresses);
+      match node_outputs.len() {
+        0 => (),
+        1 => output = node_outputs.first().cloned(),
+        _ => {
+          return Err(Error::new(
+            ErrorKind::Unsupported,
+            format!(
+              "{address} sends to more than one undefined module ({}), so \
+               there is no single output to watch",
+              node_outputs.join(", ")
+            ),
+          ));
+        }
+      }
+    }
+    let Some(o) = output else {
+      return Ok(Vec::new());
+    };
+    let Some(interim) = nodes
+      .iter()
+      .filter_map(|(address, node)| {
+        if node.reports_to(&o) {
+          Some(address)
+        } else {
+          None
+        }
+      })
+      .next()
+    else {
+      return Ok(Vec::new());
+    };
+
+    Ok(
+      nodes
+        .iter()
+        .filter_map(|(address, node)| {
+          if node.reports_to(interim) {
+            Some(address)
+          } else {
+            None
+          }
+        })
+        .cloned()
+        .collect(),
+    )
+  }
+
+  fn on_button_press(&mut self) -> Result<Option<Signal>, Error> {
     let Some(broadcaster) = self.broadcaster.to_owned() else {
-      return Err("no broadcaster in network".to_string());
+      return Err(Error::missing("no broadcaster in network"));
     };
     self.signal_queue.push_back(Signal {
       from: None,
       pulse: Pulse::Low,
       to: broadcaster.to_owned(),
     });
-    // eprintln!("button -Low-> {broadcaster}");
+    advent::trace!("button -Low-> {broadcaster}");
 
     Ok(self.cycle())
   }
@@ -185,15 +266,12 @@ impl Network {
       if self.is_memoized(&signal) {
         return Some(signal);
       }
-      #[cfg(feature = "part2")]
-      {
-        if self.outputs.contains(&signal.to) && signal.pulse == Pulse::Low {
-          self
-            .input_memory
-            .insert(signal.to.to_owned(), self.current_step);
-          if self.input_memory.len() == self.outputs.len() {
-            return Some(signal);
-          }
+      if self.outputs.contains(&signal.to) && signal.pulse == Pulse::Low {
+        self
+          .input_memory
+          .insert(signal.to.to_owned(), self.current_step);
+        if self.input_memory.len() == self.outputs.len() {
+          return Some(signal);
         }
       }
 
@@ -263,7 +341,8 @@ impl SignalLog {
     match pulse {
       Pulse::Low => self.low += 1,
       Pulse::High => self.high += 1,
-      _ => unimplemented!(),
+      // no pulse is ever sent, and there is nothing to count
+      Pulse::None => (),
     }
   }
 }
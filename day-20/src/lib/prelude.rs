@@ -1,5 +1,5 @@
-#[cfg(feature = "part2")]
-use num::integer::lcm;
+use advent::Total;
+use num::{BigInt, Integer};
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Pulse {
@@ -9,6 +9,7 @@ pub enum Pulse {
   High,
 }
 
+#[allow(dead_code)]
 pub trait Signaler {
   fn send_signal(&self);
 }
@@ -24,19 +25,20 @@ pub type Address = String;
 
 pub type State = u64;
 
-#[cfg(feature = "part2")]
-pub fn get_lcm<T: AsRef<[usize]>>(numbers: T) -> Option<usize> {
+/* get_lcm - the presses until every cycle lines up, which outgrows 64 bits
+long before the cycles themselves do */
+pub fn get_lcm<T: AsRef<[usize]>>(numbers: T) -> Option<Total> {
   let slice = numbers.as_ref();
 
   if slice.is_empty() {
     return None;
   }
 
-  let mut result = slice[0];
+  let mut result = BigInt::from(slice[0]);
 
   for &number in slice[1..].iter() {
-    result = lcm(result, number);
+    result = result.lcm(&BigInt::from(number));
   }
 
-  Some(result)
+  Some(Total::from(result))
 }
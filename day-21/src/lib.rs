@@ -0,0 +1,438 @@
+use advent::{
+  params, Cell, Check, Error, ErrorKind, Part, Renderer, Rgb, Solution, Total,
+};
+use grid::{Coord, Grid, Neighbourhood};
+use std::collections::{HashMap, VecDeque};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(feature = "sample")]
+const PART1_STEPS: usize = 6;
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+#[cfg(not(feature = "sample"))]
+const PART1_STEPS: usize = 64;
+const PART2_STEPS: usize = 26501365;
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+pub struct ProblemDefinition {
+  map: Grid<char>,
+  start: Coord,
+}
+type Consequent = Total;
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  let src = src_provider()?;
+  let map = Grid::parse(&src, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+
+  let starts: Vec<Coord> = map
+    .iter()
+    .filter(|&(_, &c)| c == 'S')
+    .map(|(c, _)| c)
+    .collect();
+  let Some(&start) = starts.first() else {
+    return Err(Error::missing("no start tile 'S'"));
+  };
+  if let Some(other) = starts.get(1) {
+    return Err(
+      Error::value("more than one start tile")
+        .at(other.y + 1, other.x + 1)
+        .snippet(src.lines().nth(other.y).unwrap_or_default()),
+    );
+  }
+
+  Ok(ProblemDefinition { map, start })
+}
+
+fn get_location_distance(data: &ProblemDefinition) -> HashMap<Coord, usize> {
+  let mut distances: HashMap<Coord, usize> = HashMap::new();
+  let mut to_visit: VecDeque<(Coord, usize)> = VecDeque::new();
+  to_visit.push_back((data.start, 0));
+  while let Some((position, steps)) = to_visit.pop_front() {
+    if distances.contains_key(&position) {
+      continue;
+    }
+    distances.insert(position, steps);
+
+    for new_pos in data.map.neighbours(position, Neighbourhood::Four) {
+      if data.map[new_pos] != '#' {
+        to_visit.push_back((new_pos, steps + 1));
+      }
+    }
+  }
+
+  distances
+}
+
+fn count_reachable(
+  location_data: &HashMap<Coord, usize>,
+  steps: usize,
+) -> Result<Consequent, Error> {
+  let visitable = location_data
+    .values()
+    .filter(|&&distance| reachable(distance, steps))
+    .count();
+
+  match visitable {
+    0 => Err(Error::no_solution("no visitable locations")),
+    _ => Ok(visitable.into()),
+  }
+}
+
+/* reachable - a plot `distance` steps away can be stood on after exactly
+`steps`, by stepping back and forth on the way */
+fn reachable(distance: usize, steps: usize) -> bool {
+  distance <= steps && (distance % 2) == (steps % 2)
+}
+
+/* render - part 1's garden with the plots the elf can end on marked `O`;
+animating, the plots for every step count up to the walk's. Part 2's walk
+covers copies of the map far past what a picture could hold. */
+fn render(
+  data: &ProblemDefinition,
+  part: Part,
+  renderer: &mut Renderer,
+) -> Result<(), Error> {
+  if part == Part::Two {
+    return Err(Error::new(
+      ErrorKind::Unsupported,
+      "part 2's walk is too large to draw",
+    ));
+  }
+  let steps = steps(part)?;
+  let location_data = get_location_distance(data);
+  let picture = |steps: usize| {
+    data.map.frame(|c, &tile| match location_data.get(&c) {
+      Some(&d) if reachable(d, steps) => Cell::new('O', Rgb::GREEN),
+      _ if tile == '#' => Cell::new('#', Rgb::GREY),
+      _ if tile == 'S' => Cell::new('S', Rgb::RED),
+      _ => Cell::new('.', Rgb::DARK),
+    })
+  };
+  if renderer.animating() {
+    for step in 0..steps {
+      renderer.frame(picture(step))?;
+    }
+  }
+
+  renderer.frame(picture(steps))
+}
+
+fn count_reachable_on_infinite_map(
+  data: &ProblemDefinition,
+  location_data: &HashMap<Coord, usize>,
+  steps: usize,
+) -> Result<Consequent, Error> {
+  // (verified) rows and cols are same in input.
+  let span = data.map.height();
+  // steps in part 2 chosen to evenly compose this many repeticiones, which
+  // `part2_steps` may not be
+  let past_edge = steps.checked_sub(span / 2);
+  let Some(past_edge) = past_edge.filter(|past| past.is_multiple_of(span))
+  else {
+    return Err(Error::value(format!(
+      "part2_steps is {steps}, but the walk only counts when it ends on the \
+       edge of a copy of the map: {} plus a multiple of {span}",
+      span / 2
+    )));
+  };
+  let n = Total::from(past_edge / span);
+  let count = |odd: bool, past_edge: bool| {
+    location_data
+      .values()
+      .filter(|&&distance| distance % 2 == odd as usize)
+      .filter(|&&distance| !past_edge || distance > span)
+      .count()
+  };
+  /* we need n of these pq are an even distance and there are n repetitions of
+  our map in a direction. these were excluded when they aught not to have been
+  (the diamond is not jaggigty at the scale of our whole map, but at tiles) */
+  let excluded_even_positions = n.clone() * count(false, true);
+  // but we need n+ 1 of these because these are an odd distance
+  let excluded_odd_positions = (n.clone() + 1) * count(true, true);
+
+  // we need n^2 here because there are two dimensions of expansion in an area.
+  let evens = n.clone().pow(2) * count(false, false);
+  // n + 1 because it is an odd distance
+  let odds = (n + 1).pow(2) * count(true, false);
+
+  let positions =
+    evens + odds + excluded_even_positions - excluded_odd_positions;
+
+  Ok(positions)
+}
+
+/* steps - how far the elf walks in `part`, the puzzle's own count unless
+`part1_steps` or `part2_steps` in the parameters say otherwise */
+fn steps(part: Part) -> Result<usize, Error> {
+  let params = params::load(PARAMS_PATH, &["part1_steps", "part2_steps"])?;
+  match part {
+    Part::One => params.get("part1_steps", PART1_STEPS),
+    Part::Two => params.get("part2_steps", PART2_STEPS),
+  }
+}
+
+/* check - part 2 counts whole copies of the map the walk covers, which only
+works out when the walk runs straight to the edge of a copy: a square map, the
+start in its centre, clear lines from it to the edges and clear edges to walk
+around the copies by, and a step count that ends exactly on an edge */
+fn check(data: &ProblemDefinition, part: Part) -> Vec<Check> {
+  if part == Part::One {
+    return Vec::new();
+  }
+  let steps = match steps(part) {
+    Ok(steps) => steps,
+    Err(e) => {
+      return vec![Check::of("the step count is valid", Err(e.message))]
+    }
+  };
+  let (map, start) = (&data.map, data.start);
+  let (width, height) = (map.width(), map.height());
+  let span = width;
+  let rocks = |cells: Vec<Coord>| -> Result<(), String> {
+    match cells.into_iter().find(|&c| map[c] == '#') {
+      Some(rock) => Err(format!(
+        "a rock at line {}, column {}",
+        rock.y + 1,
+        rock.x + 1
+      )),
+      None => Ok(()),
+    }
+  };
+  let through_start: Vec<Coord> = (0..width)
+    .map(|x| Coord::new(x, start.y))
+    .chain((0..height).map(|y| Coord::new(start.x, y)))
+    .collect();
+  let edges: Vec<Coord> = map
+    .coords()
+    .filter(|c| c.x == 0 || c.y == 0 || c.x == width - 1 || c.y == height - 1)
+    .collect();
+
+  vec![
+    Check::that("the map is square", width == height, || {
+      format!("{width} columns and {height} rows")
+    }),
+    Check::that(
+      "S is in the centre",
+      width % 2 == 1 && start == Coord::new(width / 2, height / 2),
+      || format!("S at line {}, column {}", start.y + 1, start.x + 1),
+    ),
+    Check::of("S's row and column are clear", rocks(through_start)),
+    Check::of("the edges are clear", rocks(edges)),
+    Check::that(
+      format!("{steps} steps end on the edge of a copy"),
+      steps >= span / 2 && (steps - span / 2).is_multiple_of(span),
+      || match steps.checked_sub(span / 2) {
+        Some(past) => format!("{} steps past an edge", past % span),
+        None => format!("{steps} steps stop short of the edge"),
+      },
+    ),
+  ]
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Consequent, Error> {
+  let location_data = get_location_distance(&data);
+  match part {
+    Part::One => count_reachable(&location_data, steps(part)?),
+    Part::Two => {
+      count_reachable_on_infinite_map(&data, &location_data, steps(part)?)
+    }
+  }
+}
+
+fn load(result: Result<Consequent, Error>) -> Result<(), Error> {
+  match result {
+    Ok(positions) => println!("positions {positions}"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = 21;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, part: Part) -> Vec<Check> {
+    check(data, part)
+  }
+
+  fn render(
+    data: &Self::ProblemDefinition,
+    part: Part,
+    renderer: &mut Renderer,
+  ) -> Result<(), Error> {
+    render(data, part, renderer)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::render::Target;
+
+  const SAMPLE: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+  const MALFORMED: [&str; 4] = [
+    "...........\n.....###.#.\n.###.##..#.\n..#.#...",
+    "...........\n.....###.#.\n.###.##..#.\n..#.#...#..",
+    "...........\n.....##S.#.\n.###.##..#.\n..#.#.S.#..",
+    "...........\n.....###.#.\n.###.##O.#.\n..#.#S..#..",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_locate_the_start() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    assert_eq!(extract()?.start, Coord::new(5, 5));
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_count_reachable_plots() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract()?;
+    let location_data = get_location_distance(&data);
+
+    assert_eq!(count_reachable(&location_data, 6)?, 16);
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_refuse_walks_ending_off_an_edge() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract()?;
+    let location_data = get_location_distance(&data);
+
+    for steps in [1, 4, 26501365] {
+      let error = count_reachable_on_infinite_map(&data, &location_data, steps)
+        .unwrap_err();
+      assert_eq!(error.kind, ErrorKind::Value, "{steps} steps");
+    }
+    assert!(count_reachable_on_infinite_map(&data, &location_data, 27).is_ok());
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_draw_the_plots_reachable_in_each_step() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let data = extract()?;
+    let mut renderer = Renderer::new(Target::Memory, None);
+    render(&data, Part::One, &mut renderer)?;
+    let frame = &renderer.frames()[0];
+    let plots = (0..frame.height())
+      .flat_map(|y| (0..frame.width()).map(move |x| (x, y)))
+      .filter(|&(x, y)| frame.get(x, y).unwrap().glyph == 'O')
+      .count();
+
+    // the sample walks 6 steps under the sample feature, 64 otherwise
+    let location_data = get_location_distance(&data);
+    assert_eq!(count_reachable(&location_data, steps(Part::One)?)?, plots);
+    assert!(render(&data, Part::Two, &mut renderer).is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_the_map_fits_the_infinite_walk() -> Result<(), Error> {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+    let checks = check(&extract()?, Part::Two);
+    let failed: Vec<&str> = checks
+      .iter()
+      .filter(|check| !check.holds())
+      .map(|check| check.assumption.as_str())
+      .collect();
+
+    // the sample is square and centred, but its start is walled in and its
+    // side does not divide the walk
+    assert_eq!(checks.len(), 5);
+    assert_eq!(
+      failed,
+      [
+        "S's row and column are clear",
+        "26501365 steps end on the edge of a copy"
+      ]
+    );
+    assert!(check(&extract()?, Part::One).is_empty());
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day21>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
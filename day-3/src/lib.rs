@@ -0,0 +1,349 @@
+use advent::{Error, Part, Solution, Total};
+use grid::{Coord, Grid};
+use std::collections::{HashSet, VecDeque};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd)]
+struct Range {
+  start: Coord,
+  end: Coord,
+  number: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd)]
+struct Symbol {
+  coord: Coord,
+  symbol: char,
+}
+
+pub struct ProblemRepresentation {
+  ranges: Vec<Range>,
+  symbols: Vec<Symbol>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+  type ProblemDefinition = ProblemRepresentation;
+  type Consequent = Vec<Total>;
+
+  const DAY: u8 = 3;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<Total>().to_string())
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Range>, Vec<Symbol>), Error> {
+  let mut symbols = Vec::new();
+  let mut ranges = Vec::new();
+
+  let schematic = Grid::parse(input, |c| c.is_ascii_graphic().then_some(c))?;
+  for (y, chars) in schematic.rows().enumerate() {
+    let mut x = 0;
+    while x < chars.len() {
+      match chars[x] {
+        '.' => {} // Skip periods
+        _ if chars[x].is_ascii_digit() => {
+          let start = Coord { x, y };
+          let num_str = chars
+            .iter()
+            .skip(x)
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>();
+
+          let end_of_number = x + num_str.len() - 1;
+
+          let end = Coord { x: end_of_number, y };
+          let number = num_str.parse().map_err(|e| {
+            Error::value(format!("invalid part number '{num_str}': {e}"))
+              .at(y + 1, x + 1)
+              .snippet(&chars.iter().collect::<String>())
+          })?;
+          ranges.push(Range { start, end, number });
+          x = end_of_number; // Skip to the end of this number in the next iteration
+        }
+        c => symbols.push(Symbol { coord: Coord { x, y }, symbol: c }), // Symbols are everything else
+      }
+      x += 1;
+    }
+  }
+
+  Ok((ranges, symbols))
+}
+
+fn extract() -> Result<ProblemRepresentation, Error> {
+  let schematic = src_provider()?;
+  let (ranges, symbols) = parse_input(schematic.as_str())?;
+
+  Ok(ProblemRepresentation { ranges, symbols })
+}
+
+fn get_adjacent_ranges(
+  ranges: &[Range],
+  symbol_coords: HashSet<Coord>,
+) -> Result<Vec<Total>, Error> {
+  let mut filtered_ranges = Vec::new();
+  for range in ranges {
+    let is_before_in_line = range.start.x > 0
+      && symbol_coords
+        .contains(&Coord { x: range.start.x - 1, y: range.start.y });
+
+    let is_after_in_line =
+      symbol_coords.contains(&Coord { x: range.end.x + 1, y: range.start.y });
+
+    let is_above_or_below = symbol_coords.iter().any(|s| {
+      s.y.abs_diff(range.start.y) == 1
+        && (s.x >= range.start.x.saturating_sub(1) && s.x <= range.end.x + 1)
+    });
+    if is_before_in_line || is_after_in_line || is_above_or_below {
+      filtered_ranges.push(range);
+    }
+  }
+
+  Ok(
+    filtered_ranges
+      .iter()
+      .map(|range| range.number.into())
+      .collect(),
+  )
+}
+
+fn get_gear_ratios(data: &ProblemRepresentation) -> Result<Vec<Total>, Error> {
+  Ok(
+    get_gear_ranges(data)?
+      .iter()
+      .map(|(a, b)| Total::from(a.number) * b.number)
+      .collect(),
+  )
+}
+
+fn get_gear_ranges(
+  data: &ProblemRepresentation,
+) -> Result<Vec<(&Range, &Range)>, Error> {
+  let mut gears_and_ranges = Vec::new();
+
+  for symbol in data.symbols.iter().filter(|s| s.symbol == '*') {
+    let mut adjacent_ranges: VecDeque<&Range> = VecDeque::new();
+
+    for range in &data.ranges {
+      if (range.start.x.saturating_sub(1)..=range.end.x + 1)
+        .contains(&symbol.coord.x)
+        && (range.start.y.saturating_sub(1)..=range.end.y + 1)
+          .contains(&symbol.coord.y)
+      {
+        adjacent_ranges.push_back(range);
+      }
+    }
+
+    if adjacent_ranges.len() == 2 {
+      gears_and_ranges.push((
+        adjacent_ranges.pop_front().unwrap(),
+        adjacent_ranges.pop_back().unwrap(),
+      ));
+    }
+  }
+
+  Ok(gears_and_ranges)
+}
+
+fn transform(
+  data: ProblemRepresentation,
+  part: Part,
+) -> Result<Vec<Total>, Error> {
+  match part {
+    Part::One => {
+      let symbol_coords: HashSet<Coord> =
+        data.symbols.iter().map(|s| s.coord).collect();
+
+      get_adjacent_ranges(&data.ranges, symbol_coords)
+    }
+    Part::Two => get_gear_ratios(&data),
+  }
+}
+
+fn load(result: Result<Vec<Total>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(values) => println!("{}", values.iter().sum::<Total>()),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::ErrorKind;
+
+  const SAMPLE: &str = r"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+  const MALFORMED: [&str; 4] = [
+    "467..114..\n...*......\n..35",
+    "467..114..\n...*é.....",
+    "467..114..\n...*......\n\n..35..633.",
+    "99999999999999999999999",
+  ];
+
+  // MARK extract
+  #[test]
+  fn test_parse_input() {
+    let input = SAMPLE;
+
+    let expected_symbols = vec![
+      Symbol { coord: Coord { x: 3, y: 1 }, symbol: '*' },
+      Symbol { coord: Coord { x: 6, y: 3 }, symbol: '#' },
+      Symbol { coord: Coord { x: 3, y: 4 }, symbol: '*' },
+      Symbol { coord: Coord { x: 5, y: 5 }, symbol: '+' },
+      Symbol { coord: Coord { x: 3, y: 8 }, symbol: '$' },
+      Symbol { coord: Coord { x: 5, y: 8 }, symbol: '*' },
+    ];
+
+    let expected_ranges = vec![
+      Range {
+        start: Coord { x: 0, y: 0 },
+        end: Coord { x: 2, y: 0 },
+        number: 467,
+      },
+      Range {
+        start: Coord { x: 5, y: 0 },
+        end: Coord { x: 7, y: 0 },
+        number: 114,
+      },
+      Range {
+        start: Coord { x: 2, y: 2 },
+        end: Coord { x: 3, y: 2 },
+        number: 35,
+      },
+      Range {
+        start: Coord { x: 6, y: 2 },
+        end: Coord { x: 8, y: 2 },
+        number: 633,
+      },
+      Range {
+        start: Coord { x: 0, y: 4 },
+        end: Coord { x: 2, y: 4 },
+        number: 617,
+      },
+      Range {
+        start: Coord { x: 7, y: 5 },
+        end: Coord { x: 8, y: 5 },
+        number: 58,
+      },
+      Range {
+        start: Coord { x: 2, y: 6 },
+        end: Coord { x: 4, y: 6 },
+        number: 592,
+      },
+      Range {
+        start: Coord { x: 6, y: 7 },
+        end: Coord { x: 8, y: 7 },
+        number: 755,
+      },
+      Range {
+        start: Coord { x: 1, y: 9 },
+        end: Coord { x: 3, y: 9 },
+        number: 664,
+      },
+      Range {
+        start: Coord { x: 5, y: 9 },
+        end: Coord { x: 7, y: 9 },
+        number: 598,
+      },
+    ];
+
+    let result = parse_input(input);
+    assert!(result.is_ok());
+    let (mut ranges, mut symbols) = result.unwrap();
+    ranges.sort_by_key(|r| (r.start.y, r.start.x));
+    symbols.sort_by_key(|s| (s.coord.y, s.coord.x));
+    assert_eq![symbols, expected_symbols];
+    assert_eq![ranges, expected_ranges];
+  }
+
+  #[test]
+  fn test_parse_input_rejects_blank_lines() {
+    let error = parse_input("467..114..\n\n..35..633.").unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position.map(|p| p.line), Some(2));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  fn test_transform_sums_part_numbers_and_gear_ratios() {
+    let (ranges, symbols) = parse_input(SAMPLE).unwrap();
+    let part_numbers =
+      transform(ProblemRepresentation { ranges, symbols }, Part::One);
+    let sum = |v: Vec<Total>| v.iter().sum::<Total>();
+    assert_eq!(part_numbers.map(sum), Ok(Total::from(4361)));
+
+    let (ranges, symbols) = parse_input(SAMPLE).unwrap();
+    let gear_ratios =
+      transform(ProblemRepresentation { ranges, symbols }, Part::Two);
+    assert_eq!(gear_ratios.map(sum), Ok(Total::from(467835)));
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day3>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,333 @@
+use advent::input::{self, Lines};
+use advent::{Error, Part, Solution, Total};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{space1, u32 as u32_parser};
+use nom::multi::separated_list0;
+use nom::sequence::{pair, tuple};
+use nom::IResult;
+use std::collections::hash_map::Entry::Vacant;
+use std::collections::{HashMap, VecDeque};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+type RecordValue = (Vec<u32>, Vec<u32>);
+type RecordEntry = (u32, RecordValue);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+  type ProblemDefinition = HashMap<u32, RecordValue>;
+  type Consequent = Vec<Total>;
+
+  const DAY: u8 = 4;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<Total>().to_string())
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(DATA_PATH)?, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  input::read(DATA_PATH)
+}
+
+fn parse_line(input: &str) -> IResult<&str, RecordEntry> {
+  let mut parser = pair(
+    pair(tag("Card"), space1),
+    tuple((
+      u32_parser,
+      alt((tag(":  "), tag(": "))),
+      separated_list0(space1, u32_parser),
+      alt((tag(" |  "), tag(" | "))),
+      separated_list0(space1, u32_parser),
+    )),
+  );
+
+  let (remaining, result) = parser(input)?;
+  let (_, (id, _, left, _, right)) = result;
+  Ok((remaining, (id, (left, right))))
+}
+
+/* parse_card - the `number`th line as a card, which has to list as many
+numbers as the first one did, or the input was cut short */
+fn parse_card(
+  number: usize,
+  line: &str,
+  expected_shape: &mut Option<(usize, usize)>,
+) -> Result<RecordEntry, Error> {
+  // the column is wherever the parser stopped making sense of the line
+  let malformed = |rest: &str, message: &str| {
+    Error::syntax(message)
+      .at(number, line.len() - rest.len() + 1)
+      .snippet(line)
+  };
+  match parse_line(line) {
+    Ok(("", (id, (left, right)))) => {
+      let shape = (left.len(), right.len());
+      let (winning, yours) = *expected_shape.get_or_insert(shape);
+      if (winning, yours) != shape {
+        return Err(malformed(
+          "",
+          &format!(
+            "expected {winning} winning numbers and {yours} numbers you have"
+          ),
+        ));
+      }
+
+      Ok((id, (left, right)))
+    }
+    Ok((rest, _)) => Err(malformed(rest, "unexpected text after the numbers")),
+    Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+      Err(malformed(e.input, "expected 'Card <id>: <n>... | <n>...'"))
+    }
+    Err(nom::Err::Incomplete(_)) => Err(malformed("", "incomplete card")),
+  }
+}
+
+fn extract() -> Result<HashMap<u32, RecordValue>, Error> {
+  let mut map: HashMap<u32, (Vec<u32>, Vec<u32>)> = HashMap::new();
+
+  let mut expected_shape = None;
+  for (i, line) in src_provider()?.lines().enumerate() {
+    let (id, numbers) = parse_card(i + 1, line, &mut expected_shape)?;
+    if map.insert(id, numbers).is_some() {
+      return Err(
+        Error::value(format!("card {id} is listed twice"))
+          .near(&id.to_string())
+          .on_line(i + 1, line),
+      );
+    }
+  }
+
+  Ok(map)
+}
+
+/* stream - points and copies counted as each card is read. Copies only ever
+go to the next few cards, so the copies still owed are the only thing kept,
+which needs the cards in order. As in `transform`, copies owed past the last
+card still count. */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let mut total = Total::ZERO;
+  let mut owed: VecDeque<Total> = VecDeque::new();
+  let mut expected_shape = None;
+  let mut next_id = None;
+
+  for (i, line) in lines.enumerate() {
+    let line = line?;
+    let (id, (left, right)) = parse_card(i + 1, &line, &mut expected_shape)?;
+    if next_id.is_some_and(|next| next != id) {
+      return Err(
+        Error::value(format!("expected card {}", next_id.unwrap_or(id)))
+          .near(&id.to_string())
+          .on_line(i + 1, &line),
+      );
+    }
+    next_id = Some(id + 1);
+
+    let cnt = left.iter().filter(|&n| right.contains(n)).count();
+    match part {
+      Part::One if cnt > 0 => total += Total::from(2).pow(cnt as u32 - 1),
+      Part::One => {}
+      Part::Two => {
+        let copies = owed.pop_front().unwrap_or_default() + 1;
+        total += &copies;
+        if owed.len() < cnt {
+          owed.resize(cnt, Total::ZERO);
+        }
+        owed.iter_mut().take(cnt).for_each(|owed| *owed += &copies);
+      }
+    }
+  }
+
+  Ok((total + owed.into_iter().map(|owed| owed + 1).sum::<Total>()).to_string())
+}
+
+fn transform(
+  data: HashMap<u32, RecordValue>,
+  part: Part,
+) -> Result<Vec<Total>, Error> {
+  match part {
+    Part::One => Ok(
+      data
+        .values()
+        .map(|(left, right)| {
+          let cnt = left.iter().filter(|&n| right.contains(n)).count() as u32;
+          if cnt == 0 {
+            return Total::ZERO;
+          }
+
+          Total::from(2).pow(cnt - 1)
+        })
+        .collect(),
+    ),
+    Part::Two => {
+      let mut sorted_keys: Vec<u32> = data.keys().cloned().collect();
+      sorted_keys.sort(); // Sort the keys by id
+
+      Ok(
+        sorted_keys
+          .iter()
+          .fold(HashMap::<u32, Total>::new(), |mut acc, id| {
+            let (left, right) = data.get(id).unwrap();
+            let cnt = left.iter().filter(|&n| right.contains(n)).count() as u32;
+            if !acc.contains_key(id) {
+              acc.insert(*id, Total::ONE);
+            }
+            let times = acc.get(id).unwrap().clone();
+            (id + 1..id + cnt + 1).for_each(|k| {
+              if let Vacant(e) = acc.entry(k) {
+                e.insert(times.clone() + 1);
+              } else {
+                acc.entry(k).and_modify(|e| *e += &times);
+              }
+            });
+
+            acc
+          })
+          .values()
+          .cloned()
+          .collect::<Vec<_>>(),
+      )
+    }
+  }
+}
+
+fn load(result: Result<Vec<Total>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(values) => println!("{}", values.iter().sum::<Total>()),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+  const MALFORMED: [&str; 5] = [
+    "Card 1: 41 48 83 |",
+    "Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61",
+    "Card 1: 41 48 | 83 86\nCard 1: 13 32 | 61 30",
+    "Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 30 x8",
+    "Card 99999999999: 41 | 83",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_cards_by_id() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let cards = extract().unwrap();
+    assert_eq!(cards.len(), 6);
+    assert_eq!(cards[&3].0, vec![1, 21, 53, 59, 44]);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_where_a_card_stops_parsing() {
+    mock_src_provider()
+      .returns(Ok("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61".to_string()));
+
+    let error = extract().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position, Some(Position { line: 2, column: 11 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_score_points_and_count_copies() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let points = transform(extract().unwrap(), Part::One).unwrap();
+    assert_eq!(points.iter().sum::<Total>(), 13);
+    let copies = transform(extract().unwrap(), Part::Two).unwrap();
+    assert_eq!(copies.iter().sum::<Total>(), 30);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_stream_the_same_points_and_copies() {
+    let stream =
+      |input: &'static str, part| stream(Lines::new(input.as_bytes()), part);
+    // copies owed past the last card count, as `transform` counts them
+    let mut inputs = [SAMPLE, SAMPLE, "Card 1: 1 2 | 1 2"].into_iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or("").to_string()));
+
+    for (input, part) in [
+      (SAMPLE, Part::One),
+      (SAMPLE, Part::Two),
+      ("Card 1: 1 2 | 1 2", Part::Two),
+    ] {
+      let solved = extract().and_then(|data| transform(data, part));
+      assert_eq!(stream(input, part), solved.and_then(|c| Day4::answer(&c)));
+    }
+    for input in MALFORMED {
+      assert!(stream(input, Part::Two).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day4>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,446 @@
+use advent::{parallel, params, Check, Error, Part, Solution};
+use nom::{
+  bytes::complete::{tag, take_while1},
+  character::complete::{self, space1},
+  combinator::{map, opt},
+  multi::{many1, separated_list0, separated_list1},
+  sequence::{separated_pair, terminated, tuple},
+  IResult,
+};
+use std::{
+  collections::{HashMap, HashSet},
+  ops::Range,
+};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const PARAMS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+// the categories followed from and to, unless the parameters name others
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+type Record = ((String, String), Vec<(usize, usize, usize)>);
+
+type Destination = Range<usize>;
+type Source = Range<usize>;
+type Transformation = (Destination, Source);
+
+/* the seeds line is kept in order: part 2 reads it as (start, length) pairs */
+type Seeds = Vec<usize>;
+
+pub struct ProblemDefinition {
+  seeds: Seeds,
+  transformations: HashMap<(String, String), Vec<Transformation>>,
+  // the categories the seed numbers are in and the answer is wanted in
+  route: (String, String),
+}
+
+impl ProblemDefinition {
+  fn recurse_transformations(
+    &self,
+    from_value: usize,
+    stop_at: &str,
+    from: &str,
+  ) -> usize {
+    advent::trace!("{from_value}: {from} -> {stop_at}");
+    if from == stop_at {
+      return from_value;
+    }
+
+    for (mapping, transformations) in &self.transformations {
+      if mapping.0 == from {
+        if let Some(transformation) =
+          transformations.iter().find(|t| t.1.contains(&from_value))
+        {
+          let new_seed =
+            transformation.0.start + (from_value - transformation.1.start);
+          return self.recurse_transformations(new_seed, stop_at, &mapping.1);
+        }
+
+        return self.recurse_transformations(from_value, stop_at, &mapping.1);
+      }
+    }
+
+    unreachable!()
+  }
+
+  fn get_transformation(
+    dest: usize,
+    src: usize,
+    range_length: usize,
+  ) -> Transformation {
+    let destination = dest..dest + range_length;
+    let source = src..src + range_length;
+
+    (destination, source)
+  }
+
+  fn from(seeds: Seeds, records: &[Record], route: (String, String)) -> Self {
+    let transformations =
+      records
+        .iter()
+        .fold(HashMap::new(), |mut acc, (k, records)| {
+          let v: Vec<Transformation> = records
+            .iter()
+            .map(|(dest, src, range_length)| {
+              ProblemDefinition::get_transformation(*dest, *src, *range_length)
+            })
+            .collect();
+          acc.insert(k.to_owned(), v);
+
+          acc
+        });
+
+    Self { seeds, transformations, route }
+  }
+
+  /* locate - the number `value` in the first category maps to in the last */
+  fn locate(&self, value: usize) -> usize {
+    let (from, to) = &self.route;
+    self.recurse_transformations(value, to, from)
+  }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Vec<usize>;
+
+  const DAY: u8 = 5;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    consequent
+      .iter()
+      .min()
+      .map(|location| location.to_string())
+      .ok_or(Error::missing("no seeds"))
+  }
+
+  fn check(data: &Self::ProblemDefinition, part: Part) -> Vec<Check> {
+    check(data, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn parse_usize(input: &str) -> IResult<&str, usize> {
+  map(complete::u32, |n| n as usize)(input)
+}
+
+pub fn parse_seeds(input: &str) -> IResult<&str, Seeds> {
+  let (input, (_tag, numbers)) = terminated(
+    separated_pair(tag("seeds:"), space1, separated_list0(space1, parse_usize)),
+    tuple((tag("\n"), tag("\n"))),
+  )(input)?;
+
+
+  Ok((input, numbers))
+}
+
+pub fn parse_record(input: &str) -> IResult<&str, Record> {
+  let (input, ((mapping, _tag), numbers)) = terminated(
+    separated_pair(
+      separated_pair(
+        map(
+          tuple((
+            take_while1(|c| c != '-'),
+            tag("-to-"),
+            take_while1(|c: char| !c.is_whitespace()),
+          )),
+          |(src, _tag, dest): (&str, &str, &str)| {
+            (src.to_string(), dest.to_string())
+          },
+        ),
+        space1,
+        tag("map:"),
+      ),
+      tag("\n"),
+      separated_list1(
+        tag("\n"),
+        map(
+          tuple((parse_usize, space1, parse_usize, space1, parse_usize)),
+          |(dest, _, src, _, range_length)| (dest, src, range_length),
+        ),
+      ),
+    ),
+    tuple((opt(tag("\n")), opt(tag("\n")))), // Expecting two newline sequences after seeds
+  )(input)?;
+
+  Ok((input, (mapping, numbers)))
+}
+
+pub fn parse_records(input: &str) -> IResult<&str, Vec<Record>> {
+  many1(parse_record)(input)
+}
+
+pub fn parse_data(input: &str) -> IResult<&str, (Seeds, Vec<Record>)> {
+  // Parses seeds followed by multiple records
+  let (input, seeds) = parse_seeds(input)?;
+  let (input, records) = parse_records(input)?;
+
+  Ok((input, (seeds, records)))
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  let input = src_provider()?;
+  let at = |rest: &str| input.len() - rest.len();
+  let (rest, (seeds, records)) = parse_data(&input).map_err(|e| match e {
+    nom::Err::Error(e) | nom::Err::Failure(e) => {
+      Error::syntax("expected seeds followed by '<a>-to-<b> map:' sections")
+        .at_offset(&input, at(e.input))
+    }
+    nom::Err::Incomplete(_) => Error::syntax("incomplete almanac"),
+  })?;
+  if !rest.trim().is_empty() {
+    return Err(
+      Error::syntax("unexpected text after the last map")
+        .at_offset(&input, at(rest)),
+    );
+  }
+
+  if seeds.is_empty() {
+    return Err(Error::missing("no seeds").at_offset(&input, 0));
+  }
+  let route = route()?;
+  check_chain(&records, &route)?;
+
+  advent::debug!("seeds {seeds:?}");
+
+  Ok(ProblemDefinition::from(seeds, &records, route))
+}
+
+/* route - the categories to follow from and to, `seed` and `location` in the
+puzzle itself */
+fn route() -> Result<(String, String), Error> {
+  let params = params::load(PARAMS_PATH, &["seed", "location"])?;
+
+  Ok((
+    params.get("seed", SEED.to_string())?,
+    params.get("location", LOCATION.to_string())?,
+  ))
+}
+
+/* check_chain - the maps must lead from the first category to the last
+exactly once, or `recurse_transformations` would get lost (or go round in
+circles) */
+fn check_chain(
+  records: &[Record],
+  (from, to): &(String, String),
+) -> Result<(), Error> {
+  let mut category = from.as_str();
+  let mut visited = HashSet::new();
+  while category != to {
+    if !visited.insert(category) {
+      return Err(Error::value(format!("the maps loop back to '{category}'")));
+    }
+    let mut next = records
+      .iter()
+      .filter(|((src, _), _)| src == category)
+      .map(|((_, dest), _)| dest.as_str());
+    category = match (next.next(), next.next()) {
+      (Some(dest), None) => dest,
+      (None, _) => {
+        return Err(Error::missing(format!("no '{category}-to-...' map")))
+      }
+      (Some(_), Some(_)) => {
+        return Err(Error::value(format!(
+          "more than one '{category}-to-...' map"
+        )))
+      }
+    };
+  }
+
+  Ok(())
+}
+
+/* check - part 2 reads the seeds as (start, length) pairs */
+fn check(data: &ProblemDefinition, part: Part) -> Vec<Check> {
+  match part {
+    Part::One => Vec::new(),
+    Part::Two => vec![Check::that(
+      "the seeds come in (start, length) pairs",
+      data.seeds.len().is_multiple_of(2),
+      || format!("there are {} seed numbers", data.seeds.len()),
+    )],
+  }
+}
+
+fn transform(
+  problem: ProblemDefinition,
+  part: Part,
+) -> Result<Vec<usize>, Error> {
+  let mut locations: HashMap<usize, usize> = HashMap::new();
+  match part {
+    Part::One => {
+      for seed in &problem.seeds {
+        locations.insert(*seed, problem.locate(*seed));
+      }
+    }
+    Part::Two => {
+      if !problem.seeds.len().is_multiple_of(2) {
+        return Err(Error::value("seeds are not (start, length) pairs"));
+      }
+      let seed_ranges: Vec<&[usize]> = problem.seeds.chunks(2).collect();
+      let located = parallel::map(&seed_ranges, |seed_range| {
+        (seed_range[0]..seed_range[0] + seed_range[1])
+          .map(|seed| (seed, problem.locate(seed)))
+          .collect::<Vec<_>>()
+      });
+      locations.extend(located.into_iter().flatten());
+    }
+  }
+
+  Ok(locations.values().cloned().collect::<Vec<_>>())
+}
+
+
+fn load(result: Result<Vec<usize>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(values) => match values.iter().min() {
+      Some(location) => println!("{location}"),
+      None => eprintln!("{}", Error::missing("no seeds")),
+    },
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::ErrorKind;
+
+  const SAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+  const MALFORMED: [&str; 8] = [
+    "seeds: \n\nseed-to-location map:\n50 98 2",
+    "seeds: 79 14\n\nseed-to-soil map:\n50 98 2",
+    "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-seed map:\n1 2 3",
+    "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98",
+    "seeds: 79 14 55 13\nseed-to-soil map:\n50 98 2",
+    "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-fertilizer",
+    "seeds: x\n\nseed-to-soil map:\n50 98 2",
+    "seeds: 79 14\n\nseed-to-soil map:\n",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_seeds_in_order() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let problem = extract().unwrap();
+    assert_eq!(problem.seeds, vec![79, 14, 55, 13]);
+    assert_eq!(problem.transformations.len(), 7);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_position_a_malformed_map_line() {
+    let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 x 48\n";
+    mock_src_provider().returns(Ok(input.to_string()));
+
+    let error = extract().err().unwrap();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position.map(|p| p.line), Some(5));
+    assert_eq!(error.snippet.as_deref(), Some("52 x 48"));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_find_the_lowest_location_for_seeds_and_seed_ranges() {
+    mock_src_provider().returns(Ok(SAMPLE.to_string()));
+
+    let locations = transform(extract().unwrap(), Part::One).unwrap();
+    assert_eq!(locations.iter().min(), Some(&35));
+    let locations = transform(extract().unwrap(), Part::Two).unwrap();
+    assert_eq!(locations.iter().min(), Some(&46));
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day5>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
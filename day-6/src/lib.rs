@@ -0,0 +1,344 @@
+use advent::input::{self, Lines};
+use advent::{Check, Error, Part, Solution, Total};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+
+pub struct Day6;
+
+impl Solution for Day6 {
+  type ProblemDefinition = Vec<(i64, i64)>;
+  type Consequent = Vec<Vec<isize>>;
+
+  const DAY: u8 = 6;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(part)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    _part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(parse_result(consequent).to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, _part: Part) -> Vec<Check> {
+    check(data)
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(DATA_PATH)?, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  input::read(DATA_PATH)
+}
+
+fn parse_input(input: String, part: Part) -> Result<Vec<(i64, i64)>, Error> {
+  let lines: Vec<&str> = input.lines().collect();
+
+  parse_races(&lines, lines.len(), part)
+}
+
+/* parse_races - the races from the first `lines` of an input `count` lines
+long, which has to be just a 'Time:' and a 'Distance:' line */
+fn parse_races(
+  lines: &[&str],
+  count: usize,
+  part: Part,
+) -> Result<Vec<(i64, i64)>, Error> {
+  if count != 2 {
+    return Err(Error::syntax(format!(
+      "expected a 'Time:' and a 'Distance:' line, found {count} lines"
+    )));
+  }
+
+  let times = parse_numbers(lines[0], 1, part)?;
+  let distances = parse_numbers(lines[1], 2, part)?;
+  advent::debug!("times {times:?}, distances {distances:?}");
+  if times.len() != distances.len() {
+    return Err(
+      Error::syntax(format!("expected {} distances", times.len()))
+        .on_line(2, lines[1]),
+    );
+  }
+
+  Ok(times.into_iter().zip(distances).collect())
+}
+
+/* parse_numbers - the numbers after the label of the `number`th line. Part two
+reads them as a single number, ignoring the spaces in between. */
+fn parse_numbers(
+  line: &str,
+  number: usize,
+  part: Part,
+) -> Result<Vec<i64>, Error> {
+  let Some((_label, values)) = line.split_once(':') else {
+    return Err(
+      Error::syntax("expected '<label>: <numbers>'").on_line(number, line),
+    );
+  };
+  let parse = |value: &str, fragment: &str| {
+    let invalid = |reason: String| {
+      Error::value(format!("invalid number '{value}': {reason}"))
+        .near(fragment)
+        .on_line(number, line)
+    };
+    match value.parse::<i64>() {
+      Ok(n) if n < 0 => Err(invalid("races cannot be negative".to_string())),
+      Ok(n) => Ok(n),
+      Err(e) => Err(invalid(e.to_string())),
+    }
+  };
+
+  match part {
+    Part::One => values.split_whitespace().map(|v| parse(v, v)).collect(),
+    Part::Two => {
+      let joined: String =
+        values.chars().filter(|c| !c.is_whitespace()).collect();
+
+      Ok(vec![parse(&joined, values.trim())?])
+    }
+  }
+}
+
+fn extract(part: Part) -> Result<Vec<(i64, i64)>, Error> {
+  parse_input(src_provider()?, part)
+}
+
+/* check - the ways to win are the whole numbers between the roots of
+`hold × (time - hold) = record`, found in floating point, so every record
+has to be beatable and every race short enough for its square to be exact */
+fn check(races: &[(i64, i64)]) -> Vec<Check> {
+  let square = |time: i64| (time as i128).pow(2);
+  let unbeatable = races
+    .iter()
+    .position(|&(time, record)| square(time) <= 4 * record as i128);
+  let inexact = races
+    .iter()
+    .position(|&(time, _)| square(time) > 1 << f64::MANTISSA_DIGITS);
+
+  vec![
+    Check::of(
+      "every record can be beaten",
+      unbeatable.map_or(Ok(()), |i| {
+        let (time, record) = races[i];
+        Err(format!("race {} cannot beat {record} in {time}", i + 1))
+      }),
+    ),
+    Check::of(
+      "every race is exact in floating point",
+      inexact.map_or(Ok(()), |i| {
+        Err(format!("race {} lasts {}", i + 1, races[i].0))
+      }),
+    ),
+  ]
+}
+
+/* stream - the ways to win counted rather than listed, as part two's single
+race can be won in as many ways as it lasts. Only the first two lines are
+kept; any more are just counted, for the error. */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let mut kept = Vec::new();
+  let mut count = 0;
+  for line in lines {
+    let line = line?;
+    if kept.len() < 2 {
+      kept.push(line);
+    }
+    count += 1;
+  }
+  let lines: Vec<&str> = kept.iter().map(String::as_str).collect();
+
+  let ways = parse_races(&lines, count, part)?
+    .into_iter()
+    .map(|(time, threshold)| {
+      let (lower_bound, upper_bound) = bounds(time as f64, threshold as f64);
+      (upper_bound - lower_bound + 1).max(0)
+    })
+    .product::<Total>();
+
+  Ok(ways.to_string())
+}
+
+fn find_time_to_threshold(
+  total_time: f64,
+  threshold: f64,
+) -> Result<Vec<isize>, Error> {
+  let (lower_bound, upper_bound) = bounds(total_time, threshold);
+
+  // Populate solutions within the open interval
+  Ok((lower_bound..=upper_bound).collect::<Vec<isize>>())
+}
+
+/* bounds - the first and last whole hold time that beats `threshold` */
+fn bounds(total_time: f64, threshold: f64) -> (isize, isize) {
+  // find the open interval above threshold
+  let open_lower_bound =
+    total_time / 2.0 - ((total_time.powi(2) - 4.0 * threshold).sqrt()) / 2.0;
+  let open_upper_bound =
+    total_time / 2.0 + ((total_time.powi(2) - 4.0 * threshold).sqrt()) / 2.0;
+
+  // bind to largest and smallest integer
+  let lower_bound = (open_lower_bound + 1.0).floor() as isize;
+  let upper_bound = (open_upper_bound - 1.0).ceil() as isize;
+
+  (lower_bound, upper_bound)
+}
+
+fn transform(data: Vec<(i64, i64)>) -> Result<Vec<Vec<isize>>, Error> {
+  let mut result = vec![];
+
+  for (time, threshold) in data {
+    match find_time_to_threshold(time as f64, threshold as f64) {
+      Ok(solutions) => result.push(solutions),
+      Err(e) => return Err(e),
+    }
+  }
+
+  Ok(result)
+}
+
+fn parse_result(findings: &[Vec<isize>]) -> Total {
+  findings.iter().map(Vec::len).product()
+}
+
+fn load(result: Result<Vec<Vec<isize>>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(findings) => println!("{}", parse_result(&findings)),
+    Err(err) => eprintln!("{err}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+  const MALFORMED: [&str; 5] = [
+    "Time: 7 15 30",
+    "Time: 7 15 30\nDistance: 9 40",
+    "Time: 7 15 30\nDistance 9 40 200",
+    "Time: 7 -15 30\nDistance: 9 40 200",
+    "Time: 7 15 30\nDistance: 9 40 2x0",
+  ];
+
+  // MARK extract
+  #[test]
+  fn it_should_parse_races_per_part() {
+    let races = parse_input(SAMPLE.to_string(), Part::One);
+    assert_eq!(races, Ok(vec![(7, 9), (15, 40), (30, 200)]));
+    let races = parse_input(SAMPLE.to_string(), Part::Two);
+    assert_eq!(races, Ok(vec![(71530, 940200)]));
+  }
+
+  #[test]
+  fn it_should_position_an_invalid_number() {
+    let error =
+      parse_input("Time: 7 15\nDistance: 9 4o".to_string(), Part::One)
+        .unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 13 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract(Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  fn it_should_count_the_ways_to_win() {
+    let races = parse_input(SAMPLE.to_string(), Part::One).unwrap();
+    assert_eq!(
+      transform(races).map(|findings| parse_result(&findings)),
+      Ok(Total::from(288))
+    );
+    let races = parse_input(SAMPLE.to_string(), Part::Two).unwrap();
+    assert_eq!(
+      transform(races).map(|findings| parse_result(&findings)),
+      Ok(Total::from(71503))
+    );
+  }
+
+  #[test]
+  fn it_should_stream_the_same_product() {
+    let stream =
+      |input: &'static str, part| stream(Lines::new(input.as_bytes()), part);
+
+    assert_eq!(stream(SAMPLE, Part::One), Ok("288".to_string()));
+    assert_eq!(stream(SAMPLE, Part::Two), Ok("71503".to_string()));
+    // even where `check` would refuse the races
+    let unbeatable = "Time: 7 3\nDistance: 9 5";
+    let races = parse_input(unbeatable.to_string(), Part::One).unwrap();
+    assert_eq!(
+      stream(unbeatable, Part::One),
+      transform(races).map(|findings| parse_result(&findings).to_string())
+    );
+    let error = stream("Time: 7\nDistance: 9\n\n", Part::One).unwrap_err();
+    assert!(error.message.ends_with("found 3 lines"));
+  }
+
+  #[test]
+  fn it_should_check_every_record_can_be_beaten() {
+    let races = parse_input(SAMPLE.to_string(), Part::Two).unwrap();
+    assert!(check(&races).iter().all(Check::holds));
+
+    let races = [(7, 9), (3, 5), (1 << 30, 0)];
+    let violations: Vec<_> = check(&races)
+      .into_iter()
+      .map(|check| check.violation)
+      .collect();
+    assert_eq!(
+      violations,
+      [
+        Some("race 2 cannot beat 5 in 3".to_string()),
+        Some("race 3 lasts 1073741824".to_string())
+      ]
+    );
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day6>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
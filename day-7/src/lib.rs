@@ -0,0 +1,409 @@
+use advent::input::{self, Lines};
+use advent::{Error, Part, Solution, Total};
+use std::{cmp::Ordering, collections::BTreeMap};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(feature = "sample")]
+const PART2_DATA_PATH: &str =
+  concat!(env!("CARGO_MANIFEST_DIR"), "/sample-part2.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+#[cfg(not(feature = "sample"))]
+const PART2_DATA_PATH: &str = DATA_PATH;
+
+/* in part 2 a 'J' is a Joker: the weakest card, but wild when typing a hand */
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+enum Card {
+  Joker,
+  Two,
+  Three,
+  Four,
+  Five,
+  Six,
+  Seven,
+  Eight,
+  Nine,
+  Ten,
+  Jack,
+  Queen,
+  King,
+  Ace,
+}
+
+impl Card {
+  fn from_char(card: char, part: Part) -> Option<Card> {
+    match card {
+      '2' => Some(Card::Two),
+      '3' => Some(Card::Three),
+      '4' => Some(Card::Four),
+      '5' => Some(Card::Five),
+      '6' => Some(Card::Six),
+      '7' => Some(Card::Seven),
+      '8' => Some(Card::Eight),
+      '9' => Some(Card::Nine),
+      'T' => Some(Card::Ten),
+      'J' => match part {
+        Part::One => Some(Card::Jack),
+        Part::Two => Some(Card::Joker),
+      },
+      'Q' => Some(Card::Queen),
+      'K' => Some(Card::King),
+      'A' => Some(Card::Ace),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+  HighCard,
+  OnePair,
+  TwoPair,
+  ThreeOfAKind,
+  FullHouse,
+  FourOfAKind,
+  FiveOfAKind,
+}
+
+impl HandType {
+  fn from(hand: &Hand) -> Option<HandType> {
+    /* Jokers only exist in part 2 hands; without them these rules reduce to
+    plain part 1 typing. */
+    let count = count_elements(hand);
+    match count.len() {
+      1 => Some(HandType::FiveOfAKind),
+      2 => {
+        if hand.contains(&Card::Joker) {
+          Some(HandType::FiveOfAKind)
+        } else if *count.values().max().unwrap_or(&0) == 4 {
+          Some(HandType::FourOfAKind)
+        } else {
+          Some(HandType::FullHouse)
+        }
+      }
+      3 => {
+        if hand.contains(&Card::Joker) {
+          let max_count = *count.values().max().unwrap_or(&0);
+          match max_count {
+            3 => Some(HandType::FourOfAKind),
+            2 => {
+              // Check if one pair involves a Joker
+              if count
+                .iter()
+                .any(|(&card, &count)| card == &Card::Joker && count == 2)
+              {
+                Some(HandType::FourOfAKind) // Joker completes the other pair to form 4 of a kind
+              } else {
+                // Joker completes either pair for a full house
+                Some(HandType::FullHouse)
+              }
+            }
+            _ => unreachable!(),
+          }
+        } else if *count.values().max().unwrap_or(&0) == 3 {
+          Some(HandType::ThreeOfAKind)
+        } else {
+          Some(HandType::TwoPair)
+        }
+      }
+      4 => {
+        if hand.contains(&Card::Joker) {
+          Some(HandType::ThreeOfAKind)
+        } else {
+          Some(HandType::OnePair)
+        }
+      }
+      5 => {
+        if hand.contains(&Card::Joker) {
+          Some(HandType::OnePair)
+        } else {
+          Some(HandType::HighCard)
+        }
+      }
+      _ => unreachable!(),
+    }
+  }
+}
+
+type Hand = [Card; 5];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Seat {
+  hand: Hand,
+  bid: usize,
+}
+
+impl Seat {
+  pub fn from(
+    hand_str: &str,
+    bid_str: &str,
+    part: Part,
+  ) -> Result<Self, Error> {
+    let hand: Vec<Card> = hand_str
+      .char_indices()
+      .map(|(i, c)| {
+        Card::from_char(c, part).ok_or(
+          Error::value(format!("invalid card '{c}'"))
+            .near(&hand_str[i..i + c.len_utf8()]),
+        )
+      })
+      .collect::<Result<_, _>>()?;
+
+    let hand = hand.try_into().map_err(|cards: Vec<Card>| {
+      Error::syntax(format!("expected 5 cards, found {}", cards.len()))
+        .near(hand_str)
+    })?;
+
+    let bid: usize = bid_str.parse().map_err(|e| {
+      Error::value(format!("invalid bid '{bid_str}': {e}")).near(bid_str)
+    })?;
+
+    Ok(Self { hand, bid })
+  }
+}
+
+impl PartialOrd for Seat {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Seat {
+  fn cmp(&self, other: &Self) -> Ordering {
+    // First, compare by hand type
+    let left = HandType::from(&self.hand).unwrap();
+    let right = HandType::from(&other.hand).unwrap();
+    let type_ordering = left.cmp(&right);
+
+    if type_ordering != Ordering::Equal {
+      return type_ordering;
+    }
+
+    // Then, by cards in left-to-right-order
+    for (self_card, other_card) in self.hand.iter().zip(other.hand.iter()) {
+      let card_ordering = self_card.cmp(other_card);
+      if card_ordering != Ordering::Equal {
+        return card_ordering;
+      }
+    }
+
+    Ordering::Equal
+  }
+}
+
+fn count_elements<T: Eq + Ord + std::hash::Hash>(
+  iter: impl IntoIterator<Item = T>,
+) -> BTreeMap<T, usize> {
+  let mut counts = BTreeMap::new();
+  for item in iter.into_iter() {
+    *counts.entry(item).or_insert(0) += 1;
+  }
+  counts
+}
+
+
+pub struct Day7;
+
+impl Solution for Day7 {
+  type ProblemDefinition = Vec<Seat>;
+  type Consequent = Vec<Total>;
+
+  const DAY: u8 = 7;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(part)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    _part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<Total>().to_string())
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(data_path(part))?, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider(part: Part) -> Result<String, Error> {
+  input::read(data_path(part))
+}
+
+fn data_path(part: Part) -> &'static str {
+  match part {
+    Part::One => DATA_PATH,
+    Part::Two => PART2_DATA_PATH,
+  }
+}
+
+fn extract(part: Part) -> Result<Vec<Seat>, Error> {
+  src_provider(part)?
+    .lines()
+    .enumerate()
+    .map(|(i, line)| parse_seat(i + 1, line, part))
+    .collect()
+}
+
+fn parse_seat(number: usize, line: &str, part: Part) -> Result<Seat, Error> {
+  line
+    .split_once(' ')
+    .ok_or(Error::syntax("expected '<hand> <bid>'"))
+    .and_then(|(hand_str, bid_str)| Seat::from(hand_str, bid_str, part))
+    .map_err(|e| e.on_line(number, line))
+}
+
+/* stream - ranking needs every hand, but there are only 13^5 of them, so
+bids are kept by hand, in rank order, which bounds the memory however long
+the input is. A hand dealt twice would leave its seats' ranks to the sort, so
+it is refused rather than guessed at. */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let mut bids = BTreeMap::new();
+
+  for (i, line) in lines.enumerate() {
+    let line = line?;
+    let Seat { hand, bid } = parse_seat(i + 1, &line, part)?;
+    let Some(kind) = HandType::from(&hand) else {
+      return Err(Error::value("a hand with no type").on_line(i + 1, &line));
+    };
+    if bids.insert((kind, hand), bid).is_some() {
+      let hand = line.split(' ').next().unwrap_or_default();
+      return Err(
+        Error::value(format!("hand {hand} is dealt twice"))
+          .near(hand)
+          .on_line(i + 1, &line),
+      );
+    }
+  }
+
+  let winnings = bids
+    .values()
+    .enumerate()
+    .map(|(rank, &bid)| Total::from(bid) * (rank + 1))
+    .sum::<Total>();
+
+  Ok(winnings.to_string())
+}
+
+fn transform(mut data: Vec<Seat>) -> Result<Vec<Total>, Error> {
+  data.sort_unstable();
+  Ok(
+    data
+      .iter()
+      .enumerate()
+      .map(|(rank, seat)| Total::from(seat.bid) * (rank + 1))
+      .collect(),
+  )
+}
+
+
+fn load(result: Result<Vec<Total>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(winnings) => println!("{}", winnings.iter().sum::<Total>()),
+    Err(e) => eprintln!("{e}"),
+  };
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+
+  const MALFORMED: [&str; 5] = [
+    "32T3K 765\nT55J5",
+    "32T3K 765\nT55J 684",
+    "32T3K 765\nt55j5 684",
+    "32T3K 765\nT55J5 -684",
+    "32T3K 765\nT55J5 684 1",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_read_j_as_jack_or_joker() {
+    mock_src_provider(mry::Any).returns(Ok("KTJJT 220".to_string()));
+
+    let seats = extract(Part::One).unwrap();
+    assert_eq!(seats[0].hand[2], Card::Jack);
+    let seats = extract(Part::Two).unwrap();
+    assert_eq!(seats[0].hand[2], Card::Joker);
+    assert_eq!(HandType::from(&seats[0].hand), Some(HandType::FourOfAKind));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_an_invalid_card() {
+    mock_src_provider(mry::Any).returns(Ok("32T3K 765\nT55X5 684".to_string()));
+
+    let error = extract(Part::One).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 4 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider(mry::Any)
+      .returns_with(move |_| Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract(Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_rank_hands_into_total_winnings() {
+    mock_src_provider(mry::Any).returns(Ok(SAMPLE.to_string()));
+
+    let winnings = transform(extract(Part::One).unwrap()).unwrap();
+    assert_eq!(winnings.iter().sum::<Total>(), 6440);
+    let winnings = transform(extract(Part::Two).unwrap()).unwrap();
+    assert_eq!(winnings.iter().sum::<Total>(), 5905);
+  }
+
+  #[test]
+  fn it_should_stream_the_same_winnings() {
+    let stream =
+      |input: &'static str, part| stream(Lines::new(input.as_bytes()), part);
+
+    assert_eq!(stream(SAMPLE, Part::One), Ok("6440".to_string()));
+    assert_eq!(stream(SAMPLE, Part::Two), Ok("5905".to_string()));
+    let error = stream("32T3K 765\nKK677 28\n32T3K 1", Part::One).unwrap_err();
+    assert_eq!(error.position, Some(Position { line: 3, column: 1 }));
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider(mry::Any).returns_with(move |_| input.input());
+
+    cases::assert_solves::<Day7>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,477 @@
+use advent::{Check, Error, Part, Solution, Total};
+use num::{BigInt, Integer};
+use petgraph::{
+  graph::{DiGraph, NodeIndex},
+  visit::EdgeRef,
+  Direction,
+};
+use sscanf::sscanf;
+use std::collections::{BTreeMap, HashSet};
+
+
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const START_NODE: &str = "AAA";
+const TARGET_NODE: &str = "ZZZ";
+
+/* part 1 walks from the single `AAA` root, part 2 from every `..A` node */
+#[derive(Debug)]
+pub struct ProblemDefinition {
+  instructions: Vec<char>,
+  root_index: Vec<NodeIndex>,
+  graph: DiGraph<String, EdgeLabel>,
+}
+
+#[derive(Debug, PartialEq)]
+enum EdgeLabel {
+  Left,
+  Right,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Total;
+
+  const DAY: u8 = 8;
+
+  fn extract(part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract(part)
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.to_string())
+  }
+
+  fn check(data: &Self::ProblemDefinition, part: Part) -> Vec<Check> {
+    check(data, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract(part: Part) -> Result<ProblemDefinition, Error> {
+  let src = src_provider()?;
+  let mut lines = src.lines();
+  let instructions: Vec<char> = lines
+    .next()
+    .map(|line| line.chars())
+    .ok_or(Error::missing("no instructions"))?
+    .collect();
+  if instructions.is_empty() {
+    return Err(Error::missing("no instructions").at(1, 1));
+  }
+
+  if let Some(pos) = instructions.iter().position(|c| c != &'R' && c != &'L') {
+    let line: String = instructions.iter().collect();
+    return Err(
+      Error::value(format!("invalid instruction '{}'", instructions[pos]))
+        .at(1, pos + 1)
+        .snippet(&line),
+    );
+  }
+
+  let mut graph = DiGraph::new();
+  let mut node_indices = BTreeMap::new();
+  let mut root_node_index = Vec::new();
+  let mut defined = HashSet::new();
+  match lines.next() {
+    Some("") => {}
+    Some(line) => {
+      return Err(
+        Error::syntax("expected a blank line after the instructions")
+          .on_line(2, line),
+      )
+    }
+    None => return Err(Error::missing("no nodes")),
+  }
+  for (i, line) in lines.enumerate() {
+    let parsed = sscanf!(line, "{str} = ({str}, {str})")
+      .ok()
+      .filter(|names| {
+        [names.0, names.1, names.2].iter().all(|name| {
+          !name.is_empty() && name.chars().all(char::is_alphanumeric)
+        })
+      });
+    if let Some((node, left, right)) = parsed {
+      if !defined.insert(node.to_string()) {
+        return Err(
+          Error::value(format!("node '{node}' is defined twice"))
+            .near(node)
+            .on_line(i + 3, line),
+        );
+      }
+      // Add nodes if they don't exist
+      let node_index = *node_indices
+        .entry(node.to_string())
+        .or_insert_with(|| graph.add_node(node.to_string()));
+
+      let left_index = *node_indices
+        .entry(left.to_string())
+        .or_insert_with(|| graph.add_node(left.to_string()));
+
+      let right_index = *node_indices
+        .entry(right.to_string())
+        .or_insert_with(|| graph.add_node(right.to_string()));
+
+      // Add edges with the corresponding edge labels
+      let edge_label_left = EdgeLabel::Left;
+      let edge_label_right = EdgeLabel::Right;
+
+      graph.add_edge(node_index, left_index, edge_label_left);
+      graph.add_edge(node_index, right_index, edge_label_right);
+      let is_root = match part {
+        Part::One => node == START_NODE,
+        Part::Two => node.ends_with('A'),
+      };
+      if is_root {
+        root_node_index.push(node_index);
+      }
+    } else {
+      return Err(
+        Error::syntax("expected '<node> = (<left>, <right>)'")
+          .on_line(i + 3, line),
+      );
+    }
+  }
+
+  if let Some(node) = node_indices.keys().find(|node| !defined.contains(*node))
+  {
+    return Err(Error::missing(format!("node '{node}' is never defined")));
+  }
+  if root_node_index.is_empty() {
+    return Err(Error::missing("no root node"));
+  }
+
+  Ok(ProblemDefinition { instructions, root_index: root_node_index, graph })
+}
+
+fn transform(data: ProblemDefinition, part: Part) -> Result<Total, Error> {
+  let mut path_lengths = Vec::new();
+  for node_index in data.root_index {
+    let traversal =
+      traverse(&node_index, &data.instructions, &data.graph, part);
+    match traversal {
+      Ok(steps) => path_lengths.push(steps),
+      Err(e) => return Err(e),
+    };
+  }
+
+  match get_lcm(path_lengths) {
+    Some(lim) => Ok(lim),
+    None => Err(Error::no_solution("no lcm in paths")),
+  }
+}
+
+fn traverse(
+  starting_node: &NodeIndex,
+  instructions: &[char],
+  graph: &DiGraph<String, EdgeLabel>,
+  part: Part,
+) -> Result<usize, Error> {
+  let mut node_index = *starting_node;
+  let len = instructions.len();
+  let instruction_stream = instructions.iter().cycle();
+  let mut visited = HashSet::new();
+  for (hops, instruction) in instruction_stream.enumerate() {
+    let is_target = match part {
+      Part::One => graph[node_index] == TARGET_NODE,
+      Part::Two => graph[node_index].ends_with('Z'),
+    };
+    if is_target {
+      return Ok(hops);
+    }
+
+    let key = (hops % len, node_index);
+    if visited.contains(&key) {
+      return Err(Error::no_solution("infinite loop found"));
+    }
+    visited.insert(key);
+
+    let label = match instruction {
+      'L' => EdgeLabel::Left,
+      'R' => EdgeLabel::Right,
+      _ => {
+        return Err(Error::value(format!(
+          "invalid instruction in stream: {instruction}"
+        )))
+      }
+    };
+
+    let edge_references = graph.edges_directed(node_index, Direction::Outgoing);
+    for edge in edge_references {
+      let edge_label = edge.weight();
+      if *edge_label == label {
+        let neighbor = edge.target();
+
+        node_index = neighbor;
+      }
+    }
+  }
+
+  unreachable!()
+}
+
+/* follow - the node an instruction leads to from `node` */
+fn follow(
+  graph: &DiGraph<String, EdgeLabel>,
+  node: NodeIndex,
+  instruction: char,
+) -> NodeIndex {
+  let label = match instruction {
+    'L' => EdgeLabel::Left,
+    _ => EdgeLabel::Right,
+  };
+
+  graph
+    .edges_directed(node, Direction::Outgoing)
+    .find(|edge| *edge.weight() == label)
+    .map_or(node, |edge| edge.target())
+}
+
+/* check - part 2 takes the least common multiple of the steps each start
+needs to reach its first `..Z` node, which is only when they all meet if every
+walk keeps coming back to that node at the same interval */
+fn check(data: &ProblemDefinition, part: Part) -> Vec<Check> {
+  let graph = &data.graph;
+  match part {
+    Part::One => vec![Check::that(
+      format!("there is a {TARGET_NODE} node"),
+      graph.node_weights().any(|node| node == TARGET_NODE),
+      || "none is defined".to_string(),
+    )],
+    Part::Two => data
+      .root_index
+      .iter()
+      .map(|&start| {
+        Check::of(
+          format!("{} reaches a ..Z node on a clean cycle", graph[start]),
+          cycle(data, start),
+        )
+      })
+      .collect(),
+  }
+}
+
+/* cycle - whether the walk from `start` reaches a `..Z` node after some
+steps and, as many steps later, that node again without passing another */
+fn cycle(data: &ProblemDefinition, start: NodeIndex) -> Result<(), String> {
+  let graph = &data.graph;
+  let steps = traverse(&start, &data.instructions, graph, Part::Two)
+    .map_err(|_| "it never reaches one".to_string())?;
+
+  let mut node = start;
+  let mut targets = Vec::new();
+  let walk = data.instructions.iter().cycle().take(2 * steps);
+  for (hops, &instruction) in walk.enumerate() {
+    node = follow(graph, node, instruction);
+    if graph[node].ends_with('Z') {
+      targets.push((hops + 1, node));
+    }
+  }
+
+  match targets[..] {
+    [(_, first), (hops, again)] if hops == 2 * steps && first == again => {
+      Ok(())
+    }
+    [(_, first), (hops, other), ..] => Err(format!(
+      "it reaches {} after {}, then {} {} later",
+      graph[first],
+      count(steps),
+      graph[other],
+      count(hops - steps)
+    )),
+    _ => Err(format!(
+      "it reaches {} after {} but not again in as many",
+      graph[node],
+      count(steps)
+    )),
+  }
+}
+
+/* count - `steps` as a number of steps, in words */
+fn count(steps: usize) -> String {
+  match steps {
+    1 => "1 step".to_string(),
+    _ => format!("{steps} steps"),
+  }
+}
+
+/* get_lcm - the steps until every ghost is on a `..Z` node at once, which
+outgrows 64 bits long before the ghosts' own cycles do */
+pub fn get_lcm<T: AsRef<[usize]>>(numbers: T) -> Option<Total> {
+  let slice = numbers.as_ref();
+
+  if slice.is_empty() {
+    return None;
+  }
+
+  let mut result = BigInt::from(slice[0]);
+
+  for &number in slice[1..].iter() {
+    result = result.lcm(&BigInt::from(number));
+  }
+
+  Some(Total::from(result))
+}
+
+fn load(result: Result<Total, Error>) -> Result<(), Error> {
+  match result {
+    Ok(steps) => println!("{steps} steps"),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const SAMPLE_DATA_1: &str = include_str!("../sample_1.txt");
+  const SAMPLE_DATA_2: &str = include_str!("../sample_2.txt");
+  const SAMPLE_DATA_3: &str = include_str!("../sample_3.txt");
+
+  // 11A reaches 11Z after two steps, then 22Z two steps after that
+  const UNCLEAN: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11C, XXX)
+11C = (XXX, 22Z)
+22Z = (11Z, 11Z)
+XXX = (XXX, XXX)";
+
+  // 11A reaches 11Z after a step, then 22Z a step after that
+  const HASTY: &str = "L
+
+11A = (11Z, XXX)
+11Z = (22Z, XXX)
+22Z = (XXX, XXX)
+XXX = (XXX, XXX)";
+
+  const MALFORMED: [&str; 6] = [
+    "\n\nAAA = (BBB, ZZZ)",
+    "LR\nAAA = (ZZZ, ZZZ)",
+    "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)",
+    "LR\n\nAAA = (ZZZ, ZZZ)\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)",
+    "LR\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ,",
+    "LR\n\nAAA = (Z Z, ZZZ)\nZZZ = (ZZZ, ZZZ)",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_use_data_from_tests() {
+    mock_src_provider().returns(Ok(SAMPLE_DATA_1.to_string()));
+
+    let data = src_provider();
+    assert!(data.is_ok());
+
+    assert!(!data.unwrap().is_empty());
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_a_malformed_node() {
+    mock_src_provider()
+      .returns(Ok("LR\n\nAAA = (BBB, ZZZ)\nBBB = BBB, ZZZ\n".to_string()));
+
+    let error = extract(Part::One).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Syntax);
+    assert_eq!(error.position, Some(Position { line: 4, column: 1 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract(Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_find_route_to_target_node() {
+    mock_src_provider().returns(Ok(SAMPLE_DATA_2.to_string()));
+
+    let data = extract(Part::One);
+    assert!(data.is_ok());
+    let result = transform(data.unwrap(), Part::One);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 6);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_find_simultaneous_routes_to_target_nodes() {
+    mock_src_provider().returns(Ok(SAMPLE_DATA_3.to_string()));
+
+    let data = extract(Part::Two);
+    assert!(data.is_ok());
+    let result = transform(data.unwrap(), Part::Two);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 6);
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_check_every_start_cycles_cleanly() -> Result<(), Error> {
+    let mut inputs = [SAMPLE_DATA_3, UNCLEAN, HASTY].into_iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or("").to_string()));
+
+    let checks = check(&extract(Part::Two)?, Part::Two);
+    assert_eq!(checks.len(), 2);
+    assert!(checks.iter().all(Check::holds));
+    let checks = check(&extract(Part::Two)?, Part::Two);
+    assert_eq!(
+      checks[0].violation.as_deref(),
+      Some("it reaches 11Z after 2 steps, then 22Z 2 steps later")
+    );
+    let checks = check(&extract(Part::Two)?, Part::Two);
+    assert_eq!(
+      checks[0].violation.as_deref(),
+      Some("it reaches 11Z after 1 step, then 22Z 1 step later")
+    );
+
+    Ok(())
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day8>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
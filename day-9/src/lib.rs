@@ -0,0 +1,242 @@
+use advent::input::{self, Lines};
+use advent::{parallel, Error, Part, Solution, Total};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day9;
+
+impl Solution for Day9 {
+  type ProblemDefinition = Vec<Vec<isize>>;
+  type Consequent = Vec<Total>;
+
+  const DAY: u8 = 9;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(&data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(consequent: &Self::Consequent) -> Result<String, Error> {
+    Ok(consequent.iter().sum::<Total>().to_string())
+  }
+
+  fn stream(part: Part) -> Result<String, Error> {
+    stream(input::lines(DATA_PATH)?, part)
+  }
+}
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  input::read(DATA_PATH)
+}
+
+fn extract() -> Result<Vec<Vec<isize>>, Error> {
+  let mut result = Vec::new();
+
+  let mut expected = None;
+  for (i, line) in src_provider()?.lines().enumerate() {
+    result.push(parse_series(i + 1, line, &mut expected)?);
+  }
+
+  Ok(result)
+}
+
+/* parse_series - the readings on the `number`th line. Every history is as
+long as the first, or the file was cut short. */
+fn parse_series(
+  number: usize,
+  line: &str,
+  expected: &mut Option<usize>,
+) -> Result<Vec<isize>, Error> {
+  let numbers = line
+    .split_whitespace()
+    .map(|s| {
+      s.parse().map_err(|e| {
+        Error::value(format!("invalid reading '{s}': {e}")).near(s)
+      })
+    })
+    .collect::<Result<Vec<isize>, Error>>()
+    .map_err(|e| e.on_line(number, line))?;
+  let expected = *expected.get_or_insert(numbers.len());
+  if numbers.is_empty() || numbers.len() != expected {
+    return Err(
+      Error::syntax(format!("expected {} readings", expected.max(1)))
+        .on_line(number, line),
+    );
+  }
+
+  Ok(numbers)
+}
+
+/* stream - each history extrapolated as soon as it is read, keeping only the
+running sum */
+fn stream(lines: Lines, part: Part) -> Result<String, Error> {
+  let mut sum = Total::ZERO;
+
+  let mut expected = None;
+  for (i, line) in lines.enumerate() {
+    let line = line?;
+    let series = parse_series(i + 1, &line, &mut expected)?;
+    sum += transform(&[series], part)?.iter().sum::<Total>();
+  }
+
+  Ok(sum.to_string())
+}
+
+/* the differences of values far enough apart outgrow the values themselves,
+so they are worked out as totals */
+fn transform(data: &[Vec<isize>], part: Part) -> Result<Vec<Total>, Error> {
+  parallel::map(data, |time_series| {
+    // extrapolating backwards is extrapolating the reversed series
+    let series = time_series.iter().map(|&x| Total::from(x));
+    let mut stack: Vec<Vec<Total>> = match part {
+      Part::One => vec![series.collect()],
+      Part::Two => vec![series.rev().collect()],
+    };
+
+    while let Some(series) = stack
+      .last()
+      .filter(|series| !series.iter().all(|x| *x == Total::ZERO))
+    {
+      if series.len() < 2 {
+        return Err(Error::no_solution("the differences never settle to zero"));
+      }
+      let differences: Vec<Total> =
+        series.windows(2).map(|w| w[1].clone() - &w[0]).collect();
+      stack.push(differences);
+    }
+    advent::debug!(
+      "differences, bottom up:\n{}",
+      stack
+        .iter()
+        .rev()
+        .map(|l| l
+          .iter()
+          .map(|i| i.to_string())
+          .collect::<Vec<_>>()
+          .join(" "))
+        .collect::<Vec<_>>()
+        .join("\n")
+    );
+
+    let mut prev_diff = Total::ZERO;
+    for line in stack.iter().rev() {
+      prev_diff += line.last().unwrap_or(&Total::ZERO);
+    }
+
+    Ok(prev_diff)
+  })
+  .into_iter()
+  .collect()
+}
+
+fn load(result: Result<Vec<Total>, Error>) -> Result<(), Error> {
+  match result {
+    Ok(values) => println!("{}", values.iter().sum::<Total>()),
+    Err(e) => eprintln!("{e}"),
+  }
+
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+  use advent::{ErrorKind, Position};
+
+  const MALFORMED: [&str; 4] = [
+    "0 3 6 9\n1 3 6",
+    "0 3 6 9\n\n1 3 6 10",
+    "0 3 6 9\n1 3 six 10",
+    "0 3 6 9\n1 3 6 10 15",
+  ];
+
+  // MARK extract
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_extract_series() {
+    mock_src_provider().returns(Ok("0 3 6\n-1 -2 4".to_string()));
+
+    assert_eq!(extract(), Ok(vec![vec![0, 3, 6], vec![-1, -2, 4]]));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_point_at_an_invalid_reading() {
+    mock_src_provider().returns(Ok("0 3 6\n-1 x2 4".to_string()));
+
+    let error = extract().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Value);
+    assert_eq!(error.position, Some(Position { line: 2, column: 4 }));
+  }
+
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_reject_malformed_input() {
+    let mut inputs = MALFORMED.iter();
+    mock_src_provider()
+      .returns_with(move || Ok(inputs.next().unwrap_or(&"").to_string()));
+
+    for input in MALFORMED {
+      assert!(extract().is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK transform
+  #[test]
+  fn it_should_extrapolate_forwards_and_backwards() {
+    let data = vec![
+      vec![0, 3, 6, 9, 12, 15],
+      vec![1, 3, 6, 10, 15, 21],
+      vec![10, 13, 16, 21, 30, 45],
+    ];
+
+    let totals = |values: [i32; 3]| Ok(values.map(Total::from).to_vec());
+    assert_eq!(transform(&data, Part::One), totals([18, 28, 68]));
+    assert_eq!(transform(&data, Part::Two), totals([-3, 0, 5]));
+  }
+
+  #[test]
+  fn it_should_stream_the_same_sums() {
+    let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+    let stream =
+      |input: &'static str, part| stream(Lines::new(input.as_bytes()), part);
+
+    assert_eq!(stream(input, Part::One), Ok("114".to_string()));
+    assert_eq!(stream(input, Part::Two), Ok("2".to_string()));
+    for input in MALFORMED {
+      assert!(stream(input, Part::One).is_err(), "accepted {input:?}");
+    }
+  }
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<Day9>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
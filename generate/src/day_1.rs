@@ -0,0 +1,32 @@
+use crate::{lines, scaled, Rng};
+
+
+const WORDS: [&str; 9] = [
+  "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/* calibration lines: letters, digits and spelled-out digits, each line with
+at least one real digit so part 1 has an answer too */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  lines((0..scaled(1000, scale)).map(|_| line(rng)))
+}
+
+fn line(rng: &mut Rng) -> String {
+  let mut line = String::new();
+  for _ in 0..rng.between(1, 8) {
+    match rng.below(3) {
+      0 => {
+        let len = rng.between(1, 5);
+        line += &rng.word(len, b"abcdefghijklmnopqrstuvwxyz");
+      }
+      1 => line.push(char::from(b'1' + rng.below(9) as u8)),
+      _ => line += *rng.pick(&WORDS),
+    }
+  }
+  if !line.chars().any(|c| c.is_ascii_digit()) {
+    let at = rng.below(line.len() + 1);
+    line.insert(at, char::from(b'1' + rng.below(9) as u8));
+  }
+
+  line
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{lines, side, Rng};
+
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+/* a field of pipes holding a single loop through `S`. The loop is the
+outline of a random blob of cells on a half-size grid, so it can neither
+touch nor cross itself. Everything else is junk, except next to `S`, which
+has to connect to exactly two pipes. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let size = side(140, scale, 5);
+  let cells = (size - 1) / 2;
+  let blob = blob(rng, cells);
+
+  // each cell of the loop, with the directions it connects in
+  let mut pipes: HashMap<(usize, usize), u8> = HashMap::new();
+  let mut connect = |from: (usize, usize), horizontal: bool| {
+    let (x, y) = (from.0 * 2, from.1 * 2);
+    let line = match horizontal {
+      true => [
+        ((x, y), EAST),
+        ((x + 1, y), EAST | WEST),
+        ((x + 2, y), WEST),
+      ],
+      false => [
+        ((x, y), SOUTH),
+        ((x, y + 1), NORTH | SOUTH),
+        ((x, y + 2), NORTH),
+      ],
+    };
+    for (cell, directions) in line {
+      *pipes.entry(cell).or_default() |= directions;
+    }
+  };
+  for y in 0..cells {
+    for x in 0..cells {
+      if !blob[y][x] {
+        continue;
+      }
+      let outside = |dx: isize, dy: isize| {
+        let (x, y) = (x as isize + dx, y as isize + dy);
+        let within =
+          (0..cells as isize).contains(&x) && (0..cells as isize).contains(&y);
+        !within || !blob[y as usize][x as usize]
+      };
+      if outside(0, -1) {
+        connect((x, y), true);
+      }
+      if outside(0, 1) {
+        connect((x, y + 1), true);
+      }
+      if outside(-1, 0) {
+        connect((x, y), false);
+      }
+      if outside(1, 0) {
+        connect((x + 1, y), false);
+      }
+    }
+  }
+
+  let mut loop_cells: Vec<(usize, usize)> = pipes.keys().copied().collect();
+  loop_cells.sort_unstable();
+  let start = *rng.pick(&loop_cells);
+  lines((0..size).map(|y| {
+    (0..size)
+      .map(|x| match pipes.get(&(x, y)) {
+        _ if (x, y) == start => 'S',
+        Some(&directions) => pipe(directions),
+        None if x.abs_diff(start.0) + y.abs_diff(start.1) == 1 => '.',
+        None => *rng.pick(&['|', '-', 'L', 'J', '7', 'F', '.', '.', '.']),
+      })
+      .collect::<String>()
+  }))
+}
+
+fn pipe(directions: u8) -> char {
+  match directions {
+    d if d == NORTH | SOUTH => '|',
+    d if d == EAST | WEST => '-',
+    d if d == NORTH | EAST => 'L',
+    d if d == NORTH | WEST => 'J',
+    d if d == SOUTH | WEST => '7',
+    _ => 'F',
+  }
+}
+
+/* blob - a connected set of cells without holes, and without two cells that
+only meet at a corner, whose outline is therefore a single simple loop */
+fn blob(rng: &mut Rng, cells: usize) -> Vec<Vec<bool>> {
+  let mut blob = vec![vec![false; cells]; cells];
+  let mut members = vec![(cells / 2, cells / 2)];
+  blob[cells / 2][cells / 2] = true;
+  let target = (cells * cells * 2 / 5).max(1);
+  while members.len() < target {
+    let (x, y) = *rng.pick(&members);
+    let (dx, dy) = *rng.pick(&[(0, -1), (1, 0), (0, 1), (-1, 0)]);
+    let (Some(x), Some(y)) =
+      (x.checked_add_signed(dx), y.checked_add_signed(dy))
+    else {
+      continue;
+    };
+    if x < cells && y < cells && !blob[y][x] {
+      blob[y][x] = true;
+      members.push((x, y));
+    }
+  }
+
+  loop {
+    let mut changed = false;
+    // cells the outside cannot reach are holes
+    let outside = reachable_from_edges(&blob);
+    for y in 0..cells {
+      for x in 0..cells {
+        if !blob[y][x] && !outside[y][x] {
+          blob[y][x] = true;
+          changed = true;
+        }
+      }
+    }
+    for y in 0..cells.saturating_sub(1) {
+      for x in 0..cells - 1 {
+        let (a, b) = (blob[y][x], blob[y][x + 1]);
+        let (c, d) = (blob[y + 1][x], blob[y + 1][x + 1]);
+        if a == d && b == c && a != b {
+          match a {
+            true => blob[y][x + 1] = true,
+            false => blob[y][x] = true,
+          }
+          changed = true;
+        }
+      }
+    }
+    if !changed {
+      return blob;
+    }
+  }
+}
+
+fn reachable_from_edges(blob: &[Vec<bool>]) -> Vec<Vec<bool>> {
+  let cells = blob.len();
+  let mut reached = vec![vec![false; cells]; cells];
+  let mut queue: VecDeque<(usize, usize)> = (0..cells)
+    .flat_map(|i| [(i, 0), (i, cells - 1), (0, i), (cells - 1, i)])
+    .filter(|&(x, y)| !blob[y][x])
+    .collect();
+  while let Some((x, y)) = queue.pop_front() {
+    if reached[y][x] {
+      continue;
+    }
+    reached[y][x] = true;
+    let neighbours = [
+      (x.wrapping_sub(1), y),
+      (x + 1, y),
+      (x, y.wrapping_sub(1)),
+      (x, y + 1),
+    ];
+    for (x, y) in neighbours {
+      if x < cells && y < cells && !blob[y][x] && !reached[y][x] {
+        queue.push_back((x, y));
+      }
+    }
+  }
+
+  reached
+}
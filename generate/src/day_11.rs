@@ -0,0 +1,34 @@
+use crate::{lines, side, Rng};
+
+
+/* an image of sparse galaxies with about one row and one column in ten left
+empty to expand */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let size = side(140, scale, 4);
+  let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+  let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+
+  let mut rows: Vec<Vec<u8>> = (0..size)
+    .map(|y| {
+      (0..size)
+        .map(|x| {
+          let open = !empty_rows[y] && !empty_columns[x];
+          match open && rng.chance(0.03) {
+            true => b'#',
+            false => b'.',
+          }
+        })
+        .collect()
+    })
+    .collect();
+  // the puzzle needs a pair of galaxies
+  for (x, y) in [(0, 0), (size - 1, size - 1)] {
+    rows[y][x] = b'#';
+  }
+
+  lines(
+    rows
+      .into_iter()
+      .map(|row| String::from_utf8(row).unwrap_or_default()),
+  )
+}
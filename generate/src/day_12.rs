@@ -0,0 +1,30 @@
+use crate::{lines, scaled, Rng};
+
+
+/* condition records, each hiding a real row of springs so at least one
+arrangement fits */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  lines((0..scaled(1000, scale)).map(|_| {
+    let len = rng.between(3, 20);
+    let mut springs: Vec<bool> = (0..len).map(|_| rng.chance(0.5)).collect();
+    if !springs.contains(&true) {
+      springs[rng.below(len)] = true;
+    }
+
+    let groups: Vec<String> = springs
+      .split(|&damaged| !damaged)
+      .filter(|run| !run.is_empty())
+      .map(|run| run.len().to_string())
+      .collect();
+    let record: String = springs
+      .iter()
+      .map(|&damaged| match (rng.chance(0.5), damaged) {
+        (true, _) => '?',
+        (false, true) => '#',
+        (false, false) => '.',
+      })
+      .collect();
+
+    format!("{record} {}", groups.join(","))
+  }))
+}
@@ -0,0 +1,97 @@
+use crate::{scaled, Rng};
+
+
+type Pattern = Vec<Vec<bool>>;
+
+/* patterns of ash and rocks separated by blank lines. Each pattern mirrors
+perfectly across one line, and across exactly one other line but for a
+single smudge, as parts 1 and 2 need. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let patterns: Vec<String> = (0..scaled(100, scale))
+    .map(|_| {
+      let pattern = pattern(rng);
+      pattern
+        .iter()
+        .map(|row| {
+          let row: String = row
+            .iter()
+            .map(|&rock| if rock { '#' } else { '.' })
+            .collect();
+          row + "\n"
+        })
+        .collect()
+    })
+    .collect();
+
+  patterns.join("\n")
+}
+
+/* pattern - mirrored across a row line and a column line at once; flipping a
+cell in a row the row line leaves unmirrored then smudges only the column
+line. Accidental extra lines are rare, and rejected. */
+fn pattern(rng: &mut Rng) -> Pattern {
+  loop {
+    let (height, width) = (rng.between(5, 17), rng.between(5, 17));
+    let row_line = rng.below(height - 1);
+    let row_reach = (row_line + 1).min(height - row_line - 1);
+    if row_reach * 2 == height {
+      continue;
+    }
+    let column_line = rng.below(width - 1);
+    let column_reach = (column_line + 1).min(width - column_line - 1);
+
+    let mut pattern: Pattern = (0..height)
+      .map(|_| (0..width).map(|_| rng.chance(0.5)).collect())
+      .collect();
+    for row in pattern.iter_mut() {
+      for i in 0..column_reach {
+        row[column_line + 1 + i] = row[column_line - i];
+      }
+    }
+    for i in 0..row_reach {
+      pattern[row_line + 1 + i] = pattern[row_line - i].clone();
+    }
+
+    let mirrored = row_line + 1 - row_reach..=row_line + row_reach;
+    let unmirrored: Vec<usize> =
+      (0..height).filter(|y| !mirrored.contains(y)).collect();
+    let y = *rng.pick(&unmirrored);
+    let x =
+      rng.between(column_line + 1 - column_reach, column_line + column_reach);
+    pattern[y][x] = !pattern[y][x];
+
+    if rng.chance(0.5) {
+      pattern = transpose(&pattern);
+    }
+    let mut lines = smudges(&pattern);
+    lines.extend(smudges(&transpose(&pattern)));
+    lines.sort_unstable();
+    if lines == [0, 1] {
+      return pattern;
+    }
+  }
+}
+
+/* smudges - for every line between two rows with a difference or less across
+it, how many cells differ from their reflection */
+fn smudges(pattern: &Pattern) -> Vec<usize> {
+  (0..pattern.len() - 1)
+    .map(|line| {
+      (0..=line)
+        .rev()
+        .zip(line + 1..pattern.len())
+        .map(|(above, below)| {
+          let pairs = pattern[above].iter().zip(&pattern[below]);
+          pairs.filter(|(a, b)| a != b).count()
+        })
+        .sum()
+    })
+    .filter(|&differences| differences <= 1)
+    .collect()
+}
+
+fn transpose(pattern: &Pattern) -> Pattern {
+  (0..pattern[0].len())
+    .map(|x| pattern.iter().map(|row| row[x]).collect())
+    .collect()
+}
@@ -0,0 +1,18 @@
+use crate::{lines, side, Rng};
+
+
+/* a platform of round rocks (`O`) free to roll and cube rocks (`#`) that
+stay put */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let size = side(100, scale, 3);
+
+  lines((0..size).map(|_| {
+    (0..size)
+      .map(|_| match rng.below(10) {
+        0 => '#',
+        1 | 2 => 'O',
+        _ => '.',
+      })
+      .collect::<String>()
+  }))
+}
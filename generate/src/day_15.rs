@@ -0,0 +1,24 @@
+use crate::{scaled, Rng};
+
+
+/* one line of comma-separated steps, inserting (`label=focal length`) and
+removing (`label-`) lenses among a pool of labels */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let labels: Vec<String> = (0..scaled(500, scale))
+    .map(|_| {
+      let len = rng.between(2, 6);
+      rng.word(len, b"abcdefghijklmnopqrstuvwxyz")
+    })
+    .collect();
+  let steps: Vec<String> = (0..scaled(4000, scale))
+    .map(|_| {
+      let label = rng.pick(&labels);
+      match rng.chance(0.7) {
+        true => format!("{label}={}", rng.between(1, 9)),
+        false => format!("{label}-"),
+      }
+    })
+    .collect();
+
+  format!("{}\n", steps.join(","))
+}
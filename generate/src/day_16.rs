@@ -0,0 +1,20 @@
+use crate::{lines, side, Rng};
+
+
+/* a contraption of mostly empty space with mirrors (`/`, `\`) and splitters
+(`|`, `-`) */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let size = side(110, scale, 3);
+
+  lines((0..size).map(|_| {
+    (0..size)
+      .map(|_| match rng.below(40) {
+        0 => '/',
+        1 => '\\',
+        2 => '|',
+        3 => '-',
+        _ => '.',
+      })
+      .collect::<String>()
+  }))
+}
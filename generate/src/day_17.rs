@@ -0,0 +1,14 @@
+use crate::{lines, side, Rng};
+
+
+/* a city of blocks, each losing one to nine units of heat. The ultra
+crucible of part 2 needs room for four straight moves, hence the minimum. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let size = side(141, scale, 5);
+
+  lines((0..size).map(|_| {
+    (0..size)
+      .map(|_| char::from(b'1' + rng.below(9) as u8))
+      .collect::<String>()
+  }))
+}
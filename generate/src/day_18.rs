@@ -0,0 +1,77 @@
+use crate::{lines, scaled, Rng};
+
+
+/* a dig plan whose two readings (the direction and distance, and the colour
+read as a distance and a direction) each outline a simple loop, clockwise
+like the puzzle's. Both outlines are columns of varying top and bottom
+around a common middle, so neither can touch itself, and both have four
+moves per column so the readings pair up line by line. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let columns = scaled(175, scale).max(2);
+  let small = outline(rng, columns, 10, 60);
+  // a hexadecimal distance has five digits
+  let large = outline(rng, columns, 40_000, 500_000);
+
+  lines(
+    small
+      .into_iter()
+      .zip(large)
+      .map(|((direction, distance), colour)| {
+        let code = match colour.0 {
+          'R' => 0,
+          'D' => 1,
+          'L' => 2,
+          _ => 3,
+        };
+        format!("{direction} {distance} (#{:05x}{code})", colour.1)
+      }),
+  )
+}
+
+/* outline - the moves around `columns` columns of width up to `width`,
+each reaching up to `height` above and below the middle */
+fn outline(
+  rng: &mut Rng,
+  columns: usize,
+  width: usize,
+  height: usize,
+) -> Vec<(char, usize)> {
+  let reach = |rng: &mut Rng| {
+    let mut levels = vec![rng.between(1, height)];
+    while levels.len() < columns {
+      let level = rng.between(1, height);
+      if Some(&level) != levels.last() {
+        levels.push(level);
+      }
+    }
+    levels
+  };
+  let tops = reach(rng);
+  let bottoms = reach(rng);
+  let widths: Vec<usize> =
+    (0..columns).map(|_| rng.between(1, width)).collect();
+
+  let vertical = |from: usize, to: usize, up: char, down: char| match to > from
+  {
+    true => (up, to - from),
+    false => (down, from - to),
+  };
+  let mut moves = Vec::with_capacity(columns * 4);
+  for i in 0..columns {
+    moves.push(('R', widths[i]));
+    if i + 1 < columns {
+      moves.push(vertical(tops[i], tops[i + 1], 'U', 'D'));
+    }
+  }
+  moves.push(('D', tops[columns - 1] + bottoms[columns - 1]));
+  for i in (0..columns).rev() {
+    moves.push(('L', widths[i]));
+    if i > 0 {
+      // bottoms are measured downwards
+      moves.push(vertical(bottoms[i], bottoms[i - 1], 'D', 'U'));
+    }
+  }
+  moves.push(('U', tops[0] + bottoms[0]));
+
+  moves
+}
@@ -0,0 +1,87 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{lines, scaled, Rng};
+
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/* workflows branching out from `in` as a tree, so every part ends up
+accepted or rejected, followed by the parts to sort. The tree grows until it
+holds `budget` workflows: the last one pending always sends on to a new one
+while it is short. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let budget = scaled(550, scale);
+  let mut taken: HashSet<String> = HashSet::from(["in".to_string()]);
+  let mut pending = VecDeque::from(["in".to_string()]);
+  let mut workflows = Vec::new();
+  while let Some(workflow) = pending.pop_front() {
+    let rules: Vec<String> = (0..rng.between(1, 3))
+      .map(|_| {
+        let category = rng.pick(&CATEGORIES);
+        let comparison = rng.pick(&['<', '>']);
+        let value = rng.between(1, 4000);
+        let target = target(rng, &mut taken, &mut pending, budget, false);
+        format!("{category}{comparison}{value}:{target}")
+      })
+      .collect();
+    let grow = pending.is_empty();
+    let fallback = target(rng, &mut taken, &mut pending, budget, grow);
+    workflows.push(format!("{workflow}{{{},{fallback}}}", rules.join(",")));
+  }
+  rng.shuffle(&mut workflows);
+
+  let parts = (0..scaled(200, scale)).map(|_| {
+    let ratings: Vec<String> = CATEGORIES
+      .iter()
+      .map(|category| format!("{category}={}", rng.between(1, 4000)))
+      .collect();
+    format!("{{{}}}", ratings.join(","))
+  });
+
+  format!("{}\n{}", lines(workflows), lines(parts))
+}
+
+/* target - where a rule sends its parts: while there are fewer than `budget`
+workflows, a new one, always when `grow` is set, or else `A` or `R` */
+fn target(
+  rng: &mut Rng,
+  taken: &mut HashSet<String>,
+  pending: &mut VecDeque<String>,
+  budget: usize,
+  grow: bool,
+) -> String {
+  if taken.len() < budget && (grow || rng.chance(0.6)) {
+    loop {
+      let len = rng.between(2, 4);
+      let name = rng.word(len, b"abcdefghijklmnopqrstuvwxyz");
+      if taken.insert(name.clone()) {
+        pending.push_back(name.clone());
+        return name;
+      }
+    }
+  }
+  match rng.chance(0.5) {
+    true => "A".to_string(),
+    false => "R".to_string(),
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_grow_the_workflows_with_the_scale() {
+    for seed in [7, 10] {
+      let workflows = |scale: f64| {
+        let input = generate(&mut Rng::new(seed), scale);
+        input.lines().take_while(|line| !line.is_empty()).count()
+      };
+
+      assert_eq!(workflows(0.2), 110, "seed {seed}");
+      assert_eq!(workflows(1.0), 550, "seed {seed}");
+      assert_eq!(workflows(10.0), 5500, "seed {seed}");
+    }
+  }
+}
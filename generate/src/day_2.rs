@@ -0,0 +1,23 @@
+use crate::{lines, scaled, Rng};
+
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/* games of a few draws each, every draw showing one to three colours */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  lines((1..=scaled(100, scale)).map(|id| {
+    let draws: Vec<String> = (0..rng.between(1, 6))
+      .map(|_| {
+        let mut colours = COLOURS;
+        rng.shuffle(&mut colours);
+        colours[..rng.between(1, 3)]
+          .iter()
+          .map(|colour| format!("{} {colour}", rng.between(1, 20)))
+          .collect::<Vec<_>>()
+          .join(", ")
+      })
+      .collect();
+
+    format!("Game {id}: {}", draws.join("; "))
+  }))
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use crate::{lines, scaled, Rng};
+
+
+const BITS: usize = 12;
+// the solver gives up on part 2 after this many presses
+const MAX_PERIOD: usize = 4095;
+
+/* a module configuration built like the puzzle's: the broadcaster starts a
+few twelve-bit counters of flip-flops, each reset by a conjunction when it
+reaches its period, and the conjunctions feed through inverters into the
+one conjunction in front of `rx`. Part 2's answer is the least common
+multiple of the periods, which is kept within 64 bits by reusing a period
+rather than overflowing. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let counters = scaled(4, scale).min(12);
+  let mut taken: HashSet<String> =
+    ["rx", "broadcaster"].map(String::from).into();
+  let mut name = |rng: &mut Rng| loop {
+    let name = rng.word(2 + taken.len() / 500, b"abcdefghijklmnopqrstuvwxyz");
+    if taken.insert(name.clone()) {
+      return name;
+    }
+  };
+
+  let last = name(rng);
+  let mut modules = vec![format!("&{last} -> rx")];
+  let mut starts = Vec::new();
+  let mut periods: Vec<usize> = Vec::new();
+  for _ in 0..counters {
+    let period = period(rng, &periods);
+    periods.push(period);
+
+    let bits: Vec<String> = (0..BITS).map(|_| name(rng)).collect();
+    let (reset, inverter) = (name(rng), name(rng));
+    // the reset hears the bits set in the period and toggles the others,
+    // and the lowest bit, which carries the count back round to zero
+    let mut resets = vec![bits[0].clone(), inverter.clone()];
+    for (i, bit) in bits.iter().enumerate() {
+      let mut outputs = Vec::new();
+      if i + 1 < BITS {
+        outputs.push(bits[i + 1].clone());
+      }
+      match period >> i & 1 == 1 {
+        true => outputs.push(reset.clone()),
+        false => resets.push(bit.clone()),
+      }
+      rng.shuffle(&mut outputs);
+      modules.push(format!("%{bit} -> {}", outputs.join(", ")));
+    }
+    rng.shuffle(&mut resets);
+    modules.push(format!("&{reset} -> {}", resets.join(", ")));
+    modules.push(format!("&{inverter} -> {last}"));
+    starts.push(bits[0].clone());
+  }
+  modules.push(format!("broadcaster -> {}", starts.join(", ")));
+  rng.shuffle(&mut modules);
+
+  lines(modules)
+}
+
+/* period - an odd period of twelve bits whose least common multiple with
+the `others` still fits in 64 bits, or one of the others when none does */
+fn period(rng: &mut Rng, others: &[usize]) -> usize {
+  for _ in 0..100 {
+    let period = rng.between(MAX_PERIOD / 2 + 1, MAX_PERIOD) | 1;
+    let lcm = others.iter().try_fold(period as u64, |lcm, &other| {
+      lcm.checked_mul(other as u64 / gcd(lcm, other as u64))
+    });
+    if lcm.is_some() {
+      return period;
+    }
+  }
+
+  *rng.pick(others)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+  match b {
+    0 => a,
+    _ => gcd(b, a % b),
+  }
+}
@@ -0,0 +1,34 @@
+use crate::{lines, side, Rng};
+
+
+// the sides whose copies the 26501365-step walk ends exactly on the edge of
+const SIDES: [usize; 2] = [131, 393];
+
+/* a square garden with the start in the middle. Like the puzzle's, its
+edges, middle row and column and the diamond between the edges' midpoints are
+clear of rocks, which is what part 2 relies on. Part 2 also needs the walk to
+end on the edge of a copy, so the side is whichever of the two that allow it
+is nearer the scaled one. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let wanted = side(131, scale, 11);
+  let size = SIDES[usize::from(wanted.abs_diff(393) < wanted.abs_diff(131))];
+  let middle = size / 2;
+
+  lines((0..size).map(|y| {
+    (0..size)
+      .map(|x| {
+        let edge = x == 0 || y == 0 || x == size - 1 || y == size - 1;
+        let axis = x == middle || y == middle;
+        let diamond = x.abs_diff(middle) + y.abs_diff(middle);
+        let clear = edge || axis || diamond.abs_diff(middle) <= 1;
+        if x == middle && y == middle {
+          'S'
+        } else if !clear && rng.chance(0.12) {
+          '#'
+        } else {
+          '.'
+        }
+      })
+      .collect::<String>()
+  }))
+}
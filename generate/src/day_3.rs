@@ -0,0 +1,35 @@
+use crate::{lines, side, Rng};
+
+
+const SYMBOLS: &[u8] = b"*#+$/@%=-&";
+
+/* an engine schematic: numbers of one to three digits that never run into
+each other, and symbols scattered around them */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let size = side(140, scale, 5);
+  let mut rows = vec![vec![b'.'; size]; size];
+
+  for _ in 0..size * size / 12 {
+    let (x, y, len) = (rng.below(size), rng.below(size), rng.between(1, 3));
+    // a number needs a non-digit on either side
+    let span = x.saturating_sub(1)..(x + len + 1).min(size);
+    if x + len > size || rows[y][span].iter().any(|&c| c != b'.') {
+      continue;
+    }
+    let number =
+      rng.between(10usize.pow(len as u32 - 1), 10usize.pow(len as u32) - 1);
+    rows[y][x..x + len].copy_from_slice(number.to_string().as_bytes());
+  }
+  for _ in 0..size * size / 25 {
+    let (x, y) = (rng.below(size), rng.below(size));
+    if rows[y][x] == b'.' {
+      rows[y][x] = *rng.pick(SYMBOLS);
+    }
+  }
+
+  lines(
+    rows
+      .into_iter()
+      .map(|row| String::from_utf8(row).unwrap_or_default()),
+  )
+}
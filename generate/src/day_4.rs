@@ -0,0 +1,45 @@
+use crate::{lines, scaled, Rng};
+
+
+const WINNING: usize = 10;
+const HAVE: usize = 25;
+
+/* scratchcards of ten winning numbers and twenty-five held ones. Cards come
+in runs whose matches never reach past the end of the run, so the copies of
+part 2 stay bounded however many cards there are. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let cards = scaled(200, scale);
+  let width = cards.to_string().len();
+
+  let mut matches = Vec::with_capacity(cards);
+  while matches.len() < cards {
+    let run = rng.between(4, 12).min(cards - matches.len());
+    for i in 0..run {
+      let most = WINNING.min(run - 1 - i);
+      matches.push(rng.between(0, most));
+    }
+  }
+
+  lines(matches.into_iter().enumerate().map(|(i, matching)| {
+    let mut numbers: Vec<usize> = (1..=99).collect();
+    rng.shuffle(&mut numbers);
+    let winning = &numbers[..WINNING];
+    let mut have: Vec<usize> = winning[..matching].to_vec();
+    have.extend(&numbers[WINNING..WINNING + HAVE - matching]);
+    rng.shuffle(&mut have);
+
+    let show = |numbers: &[usize]| {
+      numbers
+        .iter()
+        .map(|n| format!("{n:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    };
+    format!(
+      "Card {:>width$}: {} | {}",
+      i + 1,
+      show(winning),
+      show(&have)
+    )
+  }))
+}
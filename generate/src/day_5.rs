@@ -0,0 +1,67 @@
+use crate::{scaled, Rng};
+
+
+const CATEGORIES: [&str; 8] = [
+  "seed",
+  "soil",
+  "fertilizer",
+  "water",
+  "light",
+  "temperature",
+  "humidity",
+  "location",
+];
+// every number has to fit in 32 bits
+const UNIVERSE: usize = 4_000_000_000;
+
+/* an almanac: seed ranges, then a map from each category to the next. Each
+map cuts the numbers into pieces and lays most of them out again in another
+order, leaving the rest where they are. Part 2 walks every seed, so the seed
+ranges grow with the scale rather than spanning billions. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let pairs = scaled(10, scale).max(2);
+  let mut seeds = Vec::new();
+  for _ in 0..pairs {
+    let length = rng.between(1, scaled(400, scale));
+    seeds.push(rng.below(UNIVERSE - length));
+    seeds.push(length);
+  }
+  let seeds: Vec<String> = seeds.iter().map(usize::to_string).collect();
+  let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+  for pair in CATEGORIES.windows(2) {
+    almanac += &format!("\n{}-to-{} map:\n", pair[0], pair[1]);
+    for (destination, source, length) in map(rng, scaled(30, scale)) {
+      almanac += &format!("{destination} {source} {length}\n");
+    }
+  }
+
+  almanac
+}
+
+/* map - `pieces` ranges of the numbers below `UNIVERSE`, each as
+(destination, source, length) */
+fn map(rng: &mut Rng, pieces: usize) -> Vec<(usize, usize, usize)> {
+  let mut cuts: Vec<usize> = (1..pieces).map(|_| rng.below(UNIVERSE)).collect();
+  cuts.extend([0, UNIVERSE]);
+  cuts.sort_unstable();
+  cuts.dedup();
+
+  let sources: Vec<(usize, usize)> =
+    cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+  let mut order: Vec<usize> = (0..sources.len()).collect();
+  rng.shuffle(&mut order);
+
+  let mut destination = 0;
+  let mut ranges = Vec::new();
+  for i in order {
+    let (source, length) = sources[i];
+    // about one piece in ten is left out, so it maps to itself
+    if !rng.chance(0.1) {
+      ranges.push((destination, source, length));
+    }
+    destination += length;
+  }
+
+  ranges
+}
@@ -0,0 +1,46 @@
+use crate::{lines, scaled, Rng};
+
+
+/* at most four races of two-digit times, each record beatable. Part 2 reads
+the races as one long race, so more or longer races would no longer fit in 64
+bits; the input stops growing at puzzle size. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let races = scaled(4, scale).min(4);
+  loop {
+    let times: Vec<u64> =
+      (0..races).map(|_| rng.between(10, 99) as u64).collect();
+    let records: Vec<u64> = times
+      .iter()
+      .map(|&time| {
+        let best = (time / 2) * (time - time / 2);
+        rng.between(best as usize / 2, best as usize - 1) as u64
+      })
+      .collect();
+
+    // the joined race has to be beatable as well
+    let join = |values: &[u64]| {
+      values
+        .iter()
+        .map(u64::to_string)
+        .collect::<String>()
+        .parse::<u128>()
+    };
+    let (Ok(time), Ok(record)) = (join(&times), join(&records)) else {
+      continue;
+    };
+    if record < (time / 2) * (time - time / 2) {
+      // records reach four digits, so every value keeps a space before it
+      let row = |values: &[u64]| {
+        values
+          .iter()
+          .map(|v| format!(" {v:>4}"))
+          .collect::<String>()
+      };
+
+      return lines([
+        format!("Time:    {}", row(&times)),
+        format!("Distance:{}", row(&records)),
+      ]);
+    }
+  }
+}
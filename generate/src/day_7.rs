@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+use crate::{lines, scaled, Rng};
+
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+/* distinct hands of five cards, each with a bid */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  // there are only 13^5 distinct hands
+  let count = scaled(1000, scale).min(CARDS.len().pow(5));
+  let mut seen = HashSet::new();
+  let mut hands = Vec::with_capacity(count);
+  while hands.len() < count {
+    let hand = rng.word(5, CARDS);
+    if seen.insert(hand.clone()) {
+      hands.push(format!("{hand} {}", rng.between(1, 1000)));
+    }
+  }
+
+  lines(hands)
+}
@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+
+use crate::{lines, scaled, Rng};
+
+
+const PRIMES: [usize; 10] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+// no `A` or `Z`, which only ever end a start or an end
+const NAME: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+/* a map of left/right instructions and nodes. As in the puzzle, each ghost
+starts on a node ending in `A` and follows a path of its own that first
+reaches a node ending in `Z` after a multiple of the instructions' length,
+and that node leads on as the start does, so part 2's least common multiple
+is the answer. The first ghost walks from `AAA` to `ZZZ` for part 1. */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  let length = scaled(50, scale).max(2);
+  let instructions: Vec<bool> = (0..length).map(|_| rng.chance(0.5)).collect();
+  let mut cycles = PRIMES;
+  rng.shuffle(&mut cycles);
+  let ghosts = &cycles[..scaled(6, scale).min(6)];
+
+  // names as short as the number of nodes allows
+  let nodes: usize = ghosts.iter().map(|cycle| cycle * length + 1).sum();
+  let mut width = 3;
+  while NAME.len().pow(width as u32) < nodes * 2 {
+    width += 1;
+  }
+  let mut taken: HashSet<String> = HashSet::new();
+  let mut name = |rng: &mut Rng, last: &[u8]| loop {
+    let name = rng.word(width - 1, NAME) + &rng.word(1, last);
+    if taken.insert(name.clone()) {
+      return name;
+    }
+  };
+
+  let mut rows = Vec::new();
+  for (g, &cycle) in ghosts.iter().enumerate() {
+    let steps = cycle * length;
+    let mut path: Vec<String> = Vec::with_capacity(steps + 1);
+    path.push(match g {
+      0 => "AAA".to_string(),
+      _ => name(rng, b"A"),
+    });
+    for _ in 1..steps {
+      path.push(name(rng, NAME));
+    }
+    path.push(match g {
+      0 => "ZZZ".to_string(),
+      _ => name(rng, b"Z"),
+    });
+
+    let mut successors = Vec::with_capacity(steps);
+    for (i, &right) in instructions.iter().cycle().take(steps).enumerate() {
+      // the branch not taken stays on this ghost's path
+      let other = path[rng.between(1, steps - 1)].clone();
+      let next = path[i + 1].clone();
+      successors.push(match right {
+        true => (other, next),
+        false => (next, other),
+      });
+    }
+    // the end leads on as the start does
+    successors.push(successors[0].clone());
+
+    for (node, (left, right)) in path.iter().zip(successors) {
+      rows.push(format!("{node} = ({left}, {right})"));
+    }
+  }
+  rng.shuffle(&mut rows);
+
+  let instructions: String = instructions
+    .iter()
+    .map(|&right| if right { 'R' } else { 'L' })
+    .collect();
+  format!("{instructions}\n\n{}", lines(rows))
+}
@@ -0,0 +1,35 @@
+use crate::{lines, scaled, Rng};
+
+
+const VALUES: usize = 21;
+
+/* sequences of 21 values of polynomials of degree at most seven, written in
+the binomial basis so every value is an integer and the differences always
+settle to zero */
+pub fn generate(rng: &mut Rng, scale: f64) -> String {
+  lines((0..scaled(200, scale)).map(|_| {
+    let degree = rng.between(0, 7);
+    let coefficients: Vec<i64> = (0..=degree)
+      .map(|_| rng.between(0, 18) as i64 - 9)
+      .collect();
+
+    (0..VALUES as i64)
+      .map(|x| {
+        let value: i64 = coefficients
+          .iter()
+          .enumerate()
+          .map(|(k, c)| c * binomial(x, k as i64))
+          .sum();
+        value.to_string()
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+  }))
+}
+
+fn binomial(n: i64, k: i64) -> i64 {
+  match k > n {
+    true => 0,
+    false => (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1)),
+  }
+}
@@ -0,0 +1,143 @@
+pub mod rng;
+
+mod day_1;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_2;
+mod day_20;
+mod day_21;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod day_8;
+mod day_9;
+
+pub use rng::Rng;
+
+
+/* Generator - writes a syntactically valid puzzle input for one day that
+also meets the promises the puzzle makes (a single loop, a reachable goal, an
+answer that fits in 64 bits). At a scale of 1 the input is about as large as
+a real one; a scale of 10 makes it about ten times larger, so a grid gains
+about √10 times as many rows and columns. */
+pub type Generator = fn(&mut Rng, f64) -> String;
+
+/* GENERATORS - a generator for every day, in order */
+pub const GENERATORS: [(u8, Generator); 21] = [
+  (1, day_1::generate),
+  (2, day_2::generate),
+  (3, day_3::generate),
+  (4, day_4::generate),
+  (5, day_5::generate),
+  (6, day_6::generate),
+  (7, day_7::generate),
+  (8, day_8::generate),
+  (9, day_9::generate),
+  (10, day_10::generate),
+  (11, day_11::generate),
+  (12, day_12::generate),
+  (13, day_13::generate),
+  (14, day_14::generate),
+  (15, day_15::generate),
+  (16, day_16::generate),
+  (17, day_17::generate),
+  (18, day_18::generate),
+  (19, day_19::generate),
+  (20, day_20::generate),
+  (21, day_21::generate),
+];
+
+/* LARGEST_SCALES - the days whose inputs stop growing, at the scale they
+stop: day 6's races would overflow 64 bits once joined, and day 20's counters
+once their periods multiply */
+pub const LARGEST_SCALES: [(u8, f64); 2] = [(6, 1.0), (20, 3.0)];
+
+/* largest_scale - the scale past which `day`'s input no longer grows, if
+there is one */
+pub fn largest_scale(day: u8) -> Option<f64> {
+  let (_, scale) = LARGEST_SCALES.iter().find(|(number, _)| *number == day)?;
+
+  Some(*scale)
+}
+
+/* generate - the input for `day` from `seed` at `scale`, if there is a
+generator for that day */
+pub fn generate(day: u8, seed: u64, scale: f64) -> Option<String> {
+  let (_, generator) = GENERATORS.iter().find(|(number, _)| *number == day)?;
+
+  Some(generator(&mut Rng::new(seed), scale))
+}
+
+/* scaled - a count of `base` at scale 1, grown with `scale` and never below
+one */
+pub fn scaled(base: usize, scale: f64) -> usize {
+  ((base as f64 * scale).round() as usize).max(1)
+}
+
+/* side - the side of a square grid of `base` at scale 1, grown so its area
+follows `scale`, and never below `min` */
+pub fn side(base: usize, scale: f64, min: usize) -> usize {
+  ((base as f64 * scale.sqrt()).round() as usize).max(min)
+}
+
+/* lines - `rows` joined into an input, one per line */
+fn lines<I>(rows: I) -> String
+where
+  I: IntoIterator,
+  I::Item: std::fmt::Display,
+{
+  rows.into_iter().map(|row| format!("{row}\n")).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_reproduce_every_day_from_its_seed() {
+    for (day, _) in GENERATORS {
+      let first = generate(day, 3, 0.2).unwrap();
+
+      assert!(!first.trim().is_empty(), "day {day} generated nothing");
+      assert_eq!(generate(day, 3, 0.2), Some(first.clone()), "day {day}");
+      assert_ne!(generate(day, 4, 0.2), Some(first), "day {day}");
+    }
+    assert_eq!(generate(25, 1, 1.0), None);
+  }
+
+  #[test]
+  fn it_should_stop_growing_at_the_largest_scale() {
+    for (day, largest) in LARGEST_SCALES {
+      assert_eq!(
+        generate(day, 5, largest),
+        generate(day, 5, largest * 10.0),
+        "day {day}"
+      );
+      assert_ne!(
+        generate(day, 5, largest / 4.0),
+        generate(day, 5, largest),
+        "day {day}"
+      );
+    }
+    assert_eq!(largest_scale(19), None);
+  }
+
+  #[test]
+  fn it_should_scale_counts_and_sides() {
+    assert_eq!(scaled(1000, 0.01), 10);
+    assert_eq!(scaled(10, 0.01), 1);
+    assert_eq!(side(100, 100.0, 5), 1000);
+    assert_eq!(side(100, 0.0001, 5), 5);
+  }
+}
@@ -0,0 +1,126 @@
+use advent::{Error, ErrorKind};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+
+/* generate - `generate <DAY> [--seed <N>] [--scale <X>]` writes a random but
+valid puzzle input for a day to stdout; the same seed and scale always write
+the same input */
+fn main() -> ExitCode {
+  match run(std::env::args().skip(1)) {
+    // a reader that stops early, like `head`, has all it wants
+    Ok(input) => match write(&input) {
+      Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+        eprintln!("[generate] could not write the input: {e}");
+        ExitCode::FAILURE
+      }
+      _ => ExitCode::SUCCESS,
+    },
+    Err(e) => {
+      eprintln!("{e}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+fn write(input: &str) -> io::Result<()> {
+  let mut stdout = io::stdout().lock();
+  stdout.write_all(input.as_bytes())?;
+
+  stdout.flush()
+}
+
+fn run<I>(args: I) -> Result<String, Error>
+where
+  I: IntoIterator<Item = String>,
+{
+  let usage = |message: String| Error::new(ErrorKind::Usage, message);
+  let (mut day, mut seed, mut scale) = (None, 1, 1.0);
+  let mut args = args.into_iter();
+  while let Some(arg) = args.next() {
+    let mut value = |arg: &str| {
+      args
+        .next()
+        .ok_or(usage(format!("[generate] missing value for '{arg}'")))
+    };
+    match arg.as_str() {
+      "-s" | "--seed" => {
+        let value = value(&arg)?;
+        seed = value
+          .parse()
+          .map_err(|_| usage(format!("[generate] invalid seed '{value}'")))?;
+      }
+      "--scale" => {
+        let value = value(&arg)?;
+        scale = value
+          .parse::<f64>()
+          .ok()
+          .filter(|scale| scale.is_finite() && *scale > 0.0)
+          .ok_or(usage(format!("[generate] invalid scale '{value}'")))?;
+      }
+      flag if flag.starts_with('-') => {
+        return Err(usage(format!("[generate] unknown option '{flag}'")));
+      }
+      number => {
+        let parsed = number.parse::<u8>().ok();
+        if day
+          .replace(
+            parsed
+              .ok_or(usage(format!("[generate] invalid day '{number}'")))?,
+          )
+          .is_some()
+        {
+          return Err(usage("[generate] more than one day given".to_string()));
+        }
+      }
+    }
+  }
+
+  let day = day.ok_or(usage("[generate] no day given".to_string()))?;
+  if let Some(largest) = generate::largest_scale(day) {
+    if scale > largest {
+      return Err(usage(format!(
+        "[generate] day {day}'s input stops growing at scale {largest}, so \
+         scale {scale} would give the same"
+      )));
+    }
+  }
+  generate::generate(day, seed, scale)
+    .ok_or(usage(format!("[generate] no generator for day {day}")))
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(list: &[&str]) -> Result<String, Error> {
+    run(list.iter().map(|s| s.to_string()))
+  }
+
+  #[test]
+  fn it_should_generate_a_day_from_a_seed_and_scale() {
+    let input = parse(&["15", "--seed", "9", "--scale", "0.01"]);
+
+    assert_eq!(input, Ok(generate::generate(15, 9, 0.01).unwrap()));
+    assert_eq!(
+      parse(&["15", "--scale", "0.01"]),
+      parse(&["15", "-s", "1", "--scale", "0.01"])
+    );
+  }
+
+  #[test]
+  fn it_should_reject_bad_arguments() {
+    for args in [
+      &[][..],
+      &["26"],
+      &["1", "2"],
+      &["x"],
+      &["1", "--scale", "0"],
+      &["6", "--scale", "1.5"],
+      &["1", "--bogus"],
+    ] {
+      assert_eq!(parse(args).map_err(|e| e.kind), Err(ErrorKind::Usage));
+    }
+  }
+}
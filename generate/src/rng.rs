@@ -0,0 +1,82 @@
+/* Rng - a small seeded generator (SplitMix64). It is kept in-tree rather
+than taken from a crate so that a seed produces the same input on every
+machine and every version, which is the whole point of a seed. */
+#[derive(Clone, Debug)]
+pub struct Rng {
+  state: u64,
+}
+
+impl Rng {
+  pub fn new(seed: u64) -> Rng {
+    Rng { state: seed }
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+  }
+
+  /* below - a number in `0..n`; `n` must not be zero */
+  pub fn below(&mut self, n: usize) -> usize {
+    ((self.next_u64() as u128 * n as u128) >> 64) as usize
+  }
+
+  /* between - a number in `low..=high` */
+  pub fn between(&mut self, low: usize, high: usize) -> usize {
+    low + self.below(high - low + 1)
+  }
+
+  /* chance - true with probability `p` */
+  pub fn chance(&mut self, p: f64) -> bool {
+    ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+  }
+
+  pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+    &items[self.below(items.len())]
+  }
+
+  pub fn shuffle<T>(&mut self, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+      items.swap(i, self.below(i + 1));
+    }
+  }
+
+  /* word - `len` characters drawn from `alphabet` */
+  pub fn word(&mut self, len: usize, alphabet: &[u8]) -> String {
+    (0..len).map(|_| *self.pick(alphabet) as char).collect()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_repeat_a_seed() {
+    let draw = |seed| {
+      let mut rng = Rng::new(seed);
+      (0..8).map(|_| rng.below(1000)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(draw(7), draw(7));
+    assert_ne!(draw(7), draw(8));
+    // pinned, so a change to the generator is a deliberate one
+    assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+  }
+
+  #[test]
+  fn it_should_stay_within_bounds() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1000 {
+      assert!((3..=5).contains(&rng.between(3, 5)));
+      assert!(rng.below(1) == 0);
+    }
+    assert!(!rng.chance(0.0));
+    assert!(rng.chance(1.0));
+  }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+
+/* Coord - a cell of a grid, `x` columns across and `y` rows down from the
+top left corner */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+  pub x: usize,
+  pub y: usize,
+}
+
+impl Coord {
+  pub const fn new(x: usize, y: usize) -> Coord {
+    Coord { x, y }
+  }
+
+  /* offset - the coord `dx` across and `dy` down, unless that would leave the
+  grid's quadrant (a negative x or y) */
+  pub fn offset(self, dx: isize, dy: isize) -> Option<Coord> {
+    Some(Coord {
+      x: self.x.checked_add_signed(dx)?,
+      y: self.y.checked_add_signed(dy)?,
+    })
+  }
+
+  pub fn step(self, direction: Direction) -> Option<Coord> {
+    let (dx, dy) = direction.delta();
+    self.offset(dx, dy)
+  }
+
+  pub fn manhattan(self, other: Coord) -> usize {
+    self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+  }
+}
+
+impl fmt::Display for Coord {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "({}, {})", self.x, self.y)
+  }
+}
+
+/* Direction - the four orthogonal headings, clockwise from north (up) */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+  North,
+  East,
+  South,
+  West,
+}
+
+impl Direction {
+  pub const ALL: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+  ];
+
+  /* delta - (dx, dy) of one step; y grows downwards */
+  pub fn delta(self) -> (isize, isize) {
+    match self {
+      Direction::North => (0, -1),
+      Direction::East => (1, 0),
+      Direction::South => (0, 1),
+      Direction::West => (-1, 0),
+    }
+  }
+
+  pub fn turn_right(self) -> Direction {
+    match self {
+      Direction::North => Direction::East,
+      Direction::East => Direction::South,
+      Direction::South => Direction::West,
+      Direction::West => Direction::North,
+    }
+  }
+
+  pub fn turn_left(self) -> Direction {
+    self.turn_right().opposite()
+  }
+
+  pub fn opposite(self) -> Direction {
+    self.turn_right().turn_right()
+  }
+}
+
+/* Neighbourhood - which cells count as adjacent: the four orthogonal ones, or
+those and the four diagonals */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Neighbourhood {
+  Four,
+  Eight,
+}
+
+impl Neighbourhood {
+  pub fn deltas(self) -> &'static [(isize, isize)] {
+    match self {
+      Neighbourhood::Four => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+      Neighbourhood::Eight => &[
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+      ],
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_step_without_leaving_the_quadrant() {
+    let origin = Coord::new(0, 0);
+
+    assert_eq!(origin.step(Direction::East), Some(Coord::new(1, 0)));
+    assert_eq!(origin.step(Direction::South), Some(Coord::new(0, 1)));
+    assert_eq!(origin.step(Direction::North), None);
+    assert_eq!(origin.offset(-1, 2), None);
+    assert_eq!(Coord::new(3, 1).manhattan(Coord::new(1, 4)), 5);
+  }
+
+  #[test]
+  fn it_should_turn_clockwise_and_back() {
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::East.opposite(), Direction::West);
+    assert!(Direction::ALL
+      .iter()
+      .all(|d| d.turn_left().turn_right() == *d));
+  }
+}
@@ -0,0 +1,349 @@
+use advent::{Cell, Error, Frame};
+use std::ops::{Index, IndexMut};
+
+use crate::coord::{Coord, Direction, Neighbourhood};
+
+
+/* Grid - a rectangular block of cells stored row by row. `get` is the
+bounds-checked access; indexing with a `Coord` panics outside the grid, like
+indexing a `Vec`. */
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+  /* from_rows - a grid from equally long rows */
+  pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+    let width = rows.first().map_or(0, Vec::len);
+    let height = rows.len();
+    if let Some(y) = rows.iter().position(|row| row.len() != width) {
+      return Err(
+        Error::syntax(format!(
+          "expected a row of {width} cells, found {}",
+          rows[y].len()
+        ))
+        .at(y + 1, 1),
+      );
+    }
+
+    Ok(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+  }
+
+  /* parse - a grid with one line of `input` per row, turning each character
+  into a cell with `tile`. Ragged rows and characters `tile` rejects are
+  reported at their line and column. */
+  pub fn parse<F>(input: &str, mut tile: F) -> Result<Grid<T>, Error>
+  where
+    F: FnMut(char) -> Option<T>,
+  {
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    if width == 0 {
+      return Err(Error::missing("empty grid").at(1, 1));
+    }
+
+    let mut cells = Vec::new();
+    let mut height = 0;
+    for (y, line) in input.lines().enumerate() {
+      if line.chars().count() != width {
+        return Err(
+          Error::syntax(format!("expected a row of {width} tiles"))
+            .on_line(y + 1, line),
+        );
+      }
+      for (x, c) in line.chars().enumerate() {
+        let Some(cell) = tile(c) else {
+          return Err(
+            Error::value(format!("unexpected tile '{c}'"))
+              .at(y + 1, x + 1)
+              .snippet(line),
+          );
+        };
+        cells.push(cell);
+      }
+      height += 1;
+    }
+
+    Ok(Grid { width, height, cells })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn contains(&self, coord: Coord) -> bool {
+    coord.x < self.width && coord.y < self.height
+  }
+
+  pub fn get(&self, coord: Coord) -> Option<&T> {
+    self.index_of(coord).map(|i| &self.cells[i])
+  }
+
+  pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+    self.index_of(coord).map(|i| &mut self.cells[i])
+  }
+
+  /* step - the neighbouring coord in `direction`, if it is on the grid */
+  pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+    coord.step(direction).filter(|&next| self.contains(next))
+  }
+
+  /* neighbours - the adjacent coords that are on the grid */
+  pub fn neighbours(
+    &self,
+    coord: Coord,
+    neighbourhood: Neighbourhood,
+  ) -> impl Iterator<Item = Coord> + '_ {
+    neighbourhood
+      .deltas()
+      .iter()
+      .filter_map(move |&(dx, dy)| coord.offset(dx, dy))
+      .filter(|&next| self.contains(next))
+  }
+
+  /* coords - every coord, row by row */
+  pub fn coords(&self) -> impl Iterator<Item = Coord> {
+    let width = self.width;
+    (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord { x, y }))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+    self.coords().zip(&self.cells)
+  }
+
+  pub fn position<P>(&self, mut predicate: P) -> Option<Coord>
+  where
+    P: FnMut(&T) -> bool,
+  {
+    self
+      .iter()
+      .find(|(_, cell)| predicate(cell))
+      .map(|(coord, _)| coord)
+  }
+
+  pub fn row(&self, y: usize) -> Option<&[T]> {
+    (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+    // a zero-sized chunk is not allowed, and an empty grid has no rows anyway
+    self.cells.chunks(self.width.max(1))
+  }
+
+  /* column - the cells of column `x`, top to bottom */
+  pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+    let cells = match x < self.width {
+      true => &self.cells[x..],
+      false => &[],
+    };
+    cells.iter().step_by(self.width.max(1))
+  }
+
+  pub fn map<U, F>(&self, f: F) -> Grid<U>
+  where
+    F: FnMut(&T) -> U,
+  {
+    Grid {
+      width: self.width,
+      height: self.height,
+      cells: self.cells.iter().map(f).collect(),
+    }
+  }
+
+  /* frame - the grid drawn a cell at a time, `paint` choosing how each tile
+  looks at its coord */
+  pub fn frame<F>(&self, mut paint: F) -> Frame
+  where
+    F: FnMut(Coord, &T) -> Cell,
+  {
+    Frame::from_fn(self.width, self.height, |x, y| {
+      let coord = Coord { x, y };
+      paint(coord, &self[coord])
+    })
+  }
+
+  fn index_of(&self, coord: Coord) -> Option<usize> {
+    self
+      .contains(coord)
+      .then_some(coord.y * self.width + coord.x)
+  }
+
+  /* rearranged - a `width` × `height` grid whose cell at each coord is the
+  cell of this one at `source(coord)` */
+  fn rearranged<F>(&self, width: usize, height: usize, source: F) -> Grid<T>
+  where
+    T: Clone,
+    F: Fn(Coord) -> Coord,
+  {
+    let coords = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+    let cells = coords
+      .map(|(x, y)| self[source(Coord { x, y })].clone())
+      .collect();
+
+    Grid { width, height, cells }
+  }
+}
+
+impl<T: Clone> Grid<T> {
+  pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+    Grid { width, height, cells: vec![fill; width * height] }
+  }
+
+  /* transpose - rows become columns, reflecting across the main diagonal */
+  pub fn transpose(&self) -> Grid<T> {
+    self.rearranged(self.height, self.width, |c| Coord { x: c.y, y: c.x })
+  }
+
+  /* rotate_clockwise - a quarter turn: the left column becomes the top row */
+  pub fn rotate_clockwise(&self) -> Grid<T> {
+    let height = self.height;
+    self.rearranged(self.height, self.width, |c| Coord {
+      x: c.y,
+      y: height - 1 - c.x,
+    })
+  }
+
+  /* rotate_counter_clockwise - a quarter turn: the top row becomes the left
+  column */
+  pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+    let width = self.width;
+    self.rearranged(self.height, self.width, |c| Coord {
+      x: width - 1 - c.y,
+      y: c.x,
+    })
+  }
+
+  /* flip_horizontal - mirrors left and right */
+  pub fn flip_horizontal(&self) -> Grid<T> {
+    let width = self.width;
+    self.rearranged(self.width, self.height, |c| Coord {
+      x: width - 1 - c.x,
+      y: c.y,
+    })
+  }
+
+  /* flip_vertical - mirrors top and bottom */
+  pub fn flip_vertical(&self) -> Grid<T> {
+    let height = self.height;
+    self.rearranged(self.width, self.height, |c| Coord {
+      x: c.x,
+      y: height - 1 - c.y,
+    })
+  }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, coord: Coord) -> &T {
+    match self.get(coord) {
+      Some(cell) => cell,
+      None => {
+        panic!("{coord} is outside the {}×{} grid", self.width, self.height)
+      }
+    }
+  }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+  fn index_mut(&mut self, coord: Coord) -> &mut T {
+    let (width, height) = (self.width, self.height);
+    match self.get_mut(coord) {
+      Some(cell) => cell,
+      None => panic!("{coord} is outside the {width}×{height} grid"),
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::{ErrorKind, Position, Rgb};
+
+  fn digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10)).unwrap()
+  }
+
+  #[test]
+  fn it_should_parse_rows_of_tiles() {
+    let grid = digits("123\n456");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(Coord::new(2, 1)), Some(&6));
+    assert_eq!(grid.get(Coord::new(3, 0)), None);
+    assert_eq!(grid.position(|&d| d == 5), Some(Coord::new(1, 1)));
+    assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+  }
+
+  #[test]
+  fn it_should_position_ragged_rows_and_unknown_tiles() {
+    let parse = |input| Grid::parse(input, |c| c.to_digit(10)).unwrap_err();
+
+    let ragged = parse("123\n45\n789");
+    assert_eq!(ragged.kind, ErrorKind::Syntax);
+    assert_eq!(ragged.position, Some(Position { line: 2, column: 1 }));
+
+    let unknown = parse("123\n4x6");
+    assert_eq!(unknown.kind, ErrorKind::Value);
+    assert_eq!(unknown.position, Some(Position { line: 2, column: 2 }));
+
+    assert_eq!(parse("").kind, ErrorKind::Missing);
+  }
+
+  #[test]
+  fn it_should_list_neighbours_within_bounds() {
+    let grid = digits("123\n456\n789");
+    let around = |coord, n| {
+      grid
+        .neighbours(coord, n)
+        .map(|c| grid[c])
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(around(Coord::new(0, 0), Neighbourhood::Four), vec![2, 4]);
+    assert_eq!(
+      around(Coord::new(0, 0), Neighbourhood::Eight),
+      vec![2, 5, 4]
+    );
+    assert_eq!(around(Coord::new(1, 1), Neighbourhood::Eight).len(), 8);
+    assert_eq!(grid.step(Coord::new(2, 2), Direction::East), None);
+  }
+
+  #[test]
+  fn it_should_rotate_and_reflect() {
+    let grid = digits("123\n456");
+
+    assert_eq!(grid.rotate_clockwise(), digits("41\n52\n63"));
+    assert_eq!(grid.rotate_counter_clockwise(), digits("36\n25\n14"));
+    assert_eq!(grid.transpose(), digits("14\n25\n36"));
+    assert_eq!(grid.flip_horizontal(), digits("321\n654"));
+    assert_eq!(grid.flip_vertical(), digits("456\n123"));
+    assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+  }
+
+  #[test]
+  fn it_should_draw_each_cell_where_it_is() {
+    let grid = digits(
+      "12
+34",
+    );
+    let frame = grid.frame(|c, &d| {
+      Cell::new(
+        char::from_digit(d, 10).unwrap(),
+        Rgb(0, c.x as u8, c.y as u8),
+      )
+    });
+
+    assert_eq!((frame.width(), frame.height()), (2, 2));
+    assert_eq!(frame.get(0, 1), Some(&Cell::new('3', Rgb(0, 0, 1))));
+    assert_eq!(frame.get(1, 0), Some(&Cell::new('2', Rgb(0, 1, 0))));
+  }
+}
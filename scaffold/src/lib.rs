@@ -0,0 +1,249 @@
+use advent::answers::ANSWERS_FILE;
+use advent::{Error, ErrorKind};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+
+// every day starts from these, with `DayN`, `DAY_N` and `day_N` filled in
+const LIBRARY: &str = include_str!("../../template/lib.rs");
+const BINARY: &str = include_str!("../../template/main.rs");
+const ANSWERS_HEADER: &str = "# <input file> <part> <answer>; record \
+                              input.txt once an answer is accepted\n";
+const SAMPLE_FILE: &str = "sample.txt";
+const INPUT_FILE: &str = "input.txt";
+
+/* Files - puzzle files on disk to copy into a day as its `input.txt` and
+`sample.txt` */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Files {
+  pub input: Option<PathBuf>,
+  pub sample: Option<PathBuf>,
+}
+
+/* create - a new `day-N` crate under `root`, from the template, registered
+with the `aoc` runner and holding whatever puzzle files were given. Nothing
+is written if the day exists or cannot be registered. */
+pub fn create(root: &Path, day: u8, files: &Files) -> Result<(), Error> {
+  let dir = root.join(format!("day-{day}"));
+  if dir.exists() {
+    return Err(Error::value(format!("'{}' already exists", dir.display())));
+  }
+  let aoc_manifest = root.join("aoc/Cargo.toml");
+  let aoc_days = root.join("aoc/src/days.rs");
+  let registered_manifest = register_dependency(&read(&aoc_manifest)?, day)?;
+  let registered_days = register_runner(&read(&aoc_days)?, day)?;
+
+  write(&dir.join("Cargo.toml"), &manifest(day))?;
+  write(&dir.join("src/lib.rs"), &library(day))?;
+  write(&dir.join("src/main.rs"), &binary(day))?;
+  write(&dir.join(ANSWERS_FILE), ANSWERS_HEADER)?;
+  write(&dir.join(SAMPLE_FILE), "")?;
+  import(root, day, files)?;
+  write(&aoc_manifest, &registered_manifest)?;
+  write(&aoc_days, &registered_days)
+}
+
+/* import - copies puzzle files into an existing day */
+pub fn import(root: &Path, day: u8, files: &Files) -> Result<(), Error> {
+  let dir = existing(root, day)?;
+  let copies = [(&files.input, INPUT_FILE), (&files.sample, SAMPLE_FILE)];
+  for (from, name) in copies {
+    if let Some(from) = from {
+      write(&dir.join(name), &read(from)?)?;
+    }
+  }
+
+  Ok(())
+}
+
+/* manifest - a day's Cargo.toml */
+pub fn manifest(day: u8) -> String {
+  format!(
+    "[package]
+name = \"day-{day}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+advent = {{ path = \"../advent\" }}
+mry = \"0.2\"
+
+[features]
+sample = []
+"
+  )
+}
+
+pub fn library(day: u8) -> String {
+  LIBRARY
+    .replace("DayN", &format!("Day{day}"))
+    .replace("DAY_N", &day.to_string())
+}
+
+pub fn binary(day: u8) -> String {
+  BINARY
+    .replace("DayN", &format!("Day{day}"))
+    .replace("day_N", &format!("day_{day}"))
+}
+
+/* register_dependency - `aoc/Cargo.toml` with the day added among the other
+days, in order */
+pub fn register_dependency(manifest: &str, day: u8) -> Result<String, Error> {
+  let number = |line: &str| {
+    let (name, _) = line.split_once('=')?;
+    name.trim().strip_prefix("day-")?.parse::<u8>().ok()
+  };
+  let lines: Vec<&str> = manifest.lines().collect();
+  if lines.iter().any(|line| number(line) == Some(day)) {
+    return Err(Error::value(format!("day {day} is already a dependency")));
+  }
+
+  let at = match lines.iter().rposition(|line| number(line) < Some(day)) {
+    Some(y) if number(lines[y]).is_some() => y + 1,
+    _ => match lines.iter().position(|line| number(line).is_some()) {
+      Some(y) => y,
+      None => {
+        let dependencies = lines
+          .iter()
+          .position(|line| line.trim() == "[dependencies]")
+          .ok_or(Error::missing("no [dependencies] section"))?;
+        dependencies + 1
+      }
+    },
+  };
+
+  let entry = format!("day-{day} = {{ path = \"../day-{day}\" }}");
+  Ok(splice(&lines, at, &entry))
+}
+
+/* register_runner - `aoc/src/days.rs` with the day added to `DAYS`, in
+order */
+pub fn register_runner(days: &str, day: u8) -> Result<String, Error> {
+  const HEADER: &str = "pub const DAYS: [(u8, Runner); ";
+  let lines: Vec<&str> = days.lines().collect();
+  let start = lines
+    .iter()
+    .position(|line| line.starts_with(HEADER))
+    .ok_or(Error::missing("no DAYS table"))?;
+  let end = start
+    + lines[start..]
+      .iter()
+      .position(|line| line.trim() == "];")
+      .ok_or(
+        Error::syntax("DAYS is never closed").on_line(start + 1, lines[start]),
+      )?;
+  let count: usize = lines[start][HEADER.len()..]
+    .split(']')
+    .next()
+    .and_then(|count| count.parse().ok())
+    .ok_or(
+      Error::syntax("DAYS has no length").on_line(start + 1, lines[start]),
+    )?;
+
+  let number = |line: &str| {
+    let (number, _) = line.trim().strip_prefix('(')?.split_once(',')?;
+    number.parse::<u8>().ok()
+  };
+  let entries = start + 1..end;
+  if entries.clone().any(|y| number(lines[y]) == Some(day)) {
+    return Err(Error::value(format!("day {day} is already in DAYS")));
+  }
+  let at = entries
+    .clone()
+    .find(|&y| number(lines[y]).is_some_and(|number| number > day))
+    .unwrap_or(end);
+
+  let mut lines = lines;
+  let header = format!("{HEADER}{}] = [", count + 1);
+  lines[start] = &header;
+  let entry = format!("  ({day}, measure::<day_{day}::Day{day}>),");
+  Ok(splice(&lines, at, &entry))
+}
+
+/* splice - `lines` with `line` inserted before line `at`, as a file */
+fn splice(lines: &[&str], at: usize, line: &str) -> String {
+  let mut lines = lines.to_vec();
+  lines.insert(at, line);
+
+  lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+fn existing(root: &Path, day: u8) -> Result<PathBuf, Error> {
+  let dir = root.join(format!("day-{day}"));
+  match dir.is_dir() {
+    true => Ok(dir),
+    false => Err(Error::missing(format!(
+      "'{}' does not exist",
+      dir.display()
+    ))),
+  }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+  fs::read_to_string(path).map_err(|e| {
+    Error::new(
+      ErrorKind::Input,
+      format!("could not read '{}': {e}", path.display()),
+    )
+  })
+}
+
+fn write(path: &Path, text: &str) -> Result<(), Error> {
+  let create = path.parent().map_or(Ok(()), fs::create_dir_all);
+  create.and_then(|_| fs::write(path, text)).map_err(|e| {
+    Error::new(
+      ErrorKind::Input,
+      format!("could not write '{}': {e}", path.display()),
+    )
+  })
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const MANIFEST: &str = "[package]
+name = \"aoc\"
+
+[dependencies]
+advent = { path = \"../advent\" }
+day-1 = { path = \"../day-1\" }
+day-3 = { path = \"../day-3\" }
+";
+
+  const DAYS: &str = "pub const DAYS: [(u8, Runner); 2] = [
+  (1, measure::<day_1::Day1>),
+  (3, measure::<day_3::Day3>),
+];
+";
+
+  #[test]
+  fn it_should_fill_in_the_template() {
+    let library = library(22);
+
+    assert!(library.contains("pub struct Day22;"));
+    assert!(library.contains("const DAY: u8 = 22;"));
+    assert!(binary(22).contains("use day_22::Day22;"));
+    assert!(manifest(22).contains("name = \"day-22\""));
+  }
+
+  #[test]
+  fn it_should_register_days_in_order() {
+    let manifest = register_dependency(MANIFEST, 2).unwrap();
+    assert!(manifest.contains(concat!(
+      "day-1 = { path = \"../day-1\" }\n",
+      "day-2 = { path = \"../day-2\" }\n",
+      "day-3"
+    )));
+
+    let days = register_runner(DAYS, 4).unwrap();
+    assert!(days.starts_with("pub const DAYS: [(u8, Runner); 3] = [\n"));
+    assert!(days.ends_with("  (4, measure::<day_4::Day4>),\n];\n"));
+
+    assert!(register_dependency(MANIFEST, 3).is_err());
+    assert!(register_runner(DAYS, 1).is_err());
+  }
+}
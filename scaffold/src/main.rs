@@ -0,0 +1,113 @@
+use advent::{Error, ErrorKind};
+use scaffold::Files;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+
+/* scaffold - sets up a day without a network connection:
+
+  scaffold new <DAY> [--input <FILE>] [--sample <FILE>]
+  scaffold import <DAY> [--input <FILE>] [--sample <FILE>]
+
+`new` creates `day-N` from the template and registers it with `aoc`, and
+`import` copies puzzle files into a day. `--root` picks another workspace. */
+fn main() -> ExitCode {
+  match run(std::env::args().skip(1)) {
+    Ok(done) => {
+      println!("{done}");
+      ExitCode::SUCCESS
+    }
+    Err(e) => {
+      eprintln!("{e}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Command {
+  New,
+  Import,
+}
+
+fn run<I>(args: I) -> Result<String, Error>
+where
+  I: IntoIterator<Item = String>,
+{
+  let usage = |message: String| Error::new(ErrorKind::Usage, message);
+  let mut args = args.into_iter();
+  let command = match args.next().as_deref() {
+    Some("new") => Command::New,
+    Some("import") => Command::Import,
+    Some(other) => {
+      return Err(usage(format!("[scaffold] unknown command '{other}'")))
+    }
+    None => return Err(usage("[scaffold] no command given".to_string())),
+  };
+
+  let mut root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+  let (mut day, mut files) = (None, Files::default());
+  while let Some(arg) = args.next() {
+    let mut value = |arg: &str| {
+      args
+        .next()
+        .map(PathBuf::from)
+        .ok_or(usage(format!("[scaffold] missing value for '{arg}'")))
+    };
+    match arg.as_str() {
+      "--input" => files.input = Some(value(&arg)?),
+      "--sample" => files.sample = Some(value(&arg)?),
+      "--root" => root = value(&arg)?,
+      flag if flag.starts_with('-') => {
+        return Err(usage(format!("[scaffold] unknown option '{flag}'")));
+      }
+      number => {
+        let parsed = number
+          .parse::<u8>()
+          .ok()
+          .filter(|&day| day > 0)
+          .ok_or(usage(format!("[scaffold] invalid day '{number}'")))?;
+        if day.replace(parsed).is_some() {
+          return Err(usage("[scaffold] more than one day given".to_string()));
+        }
+      }
+    }
+  }
+  let day = day.ok_or(usage("[scaffold] no day given".to_string()))?;
+
+  match command {
+    Command::New => {
+      scaffold::create(&root, day, &files)?;
+      Ok(format!("created day-{day} and added it to aoc"))
+    }
+    Command::Import => {
+      scaffold::import(&root, day, &files)?;
+      Ok(format!("imported into day-{day}"))
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(list: &[&str]) -> Result<String, Error> {
+    run(list.iter().map(|s| s.to_string()))
+  }
+
+  #[test]
+  fn it_should_reject_bad_arguments() {
+    let bad: [&[&str]; 6] = [
+      &[],
+      &["make", "22"],
+      &["new"],
+      &["new", "0"],
+      &["new", "22", "23"],
+      &["tests", "22"],
+    ];
+    for args in bad {
+      assert_eq!(parse(args).map_err(|e| e.kind), Err(ErrorKind::Usage));
+    }
+  }
+}
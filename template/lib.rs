@@ -0,0 +1,84 @@
+use advent::{Error, Part, Solution};
+
+
+#[cfg(feature = "sample")]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt");
+#[cfg(not(feature = "sample"))]
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct ProblemDefinition {}
+pub type Consequent = String;
+
+
+#[mry::mry]
+fn src_provider() -> Result<String, Error> {
+  advent::input::read(DATA_PATH)
+}
+
+fn extract() -> Result<ProblemDefinition, Error> {
+  todo!()
+}
+
+fn transform(
+  _data: ProblemDefinition,
+  _part: Part,
+) -> Result<Consequent, Error> {
+  todo!()
+}
+
+fn load(_result: Result<Consequent, Error>) -> Result<(), Error> {
+  todo!()
+}
+
+pub struct DayN;
+
+impl Solution for DayN {
+  type ProblemDefinition = ProblemDefinition;
+  type Consequent = Consequent;
+
+  const DAY: u8 = DAY_N;
+
+  fn extract(_part: Part) -> Result<Self::ProblemDefinition, Error> {
+    extract()
+  }
+
+  fn transform(
+    data: Self::ProblemDefinition,
+    part: Part,
+  ) -> Result<Self::Consequent, Error> {
+    transform(data, part)
+  }
+
+  fn load(
+    result: Result<Self::Consequent, Error>,
+    _part: Part,
+  ) -> Result<(), Error> {
+    load(result)
+  }
+
+  fn answer(_consequent: &Self::Consequent) -> Result<String, Error> {
+    todo!()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use advent::cases::{self, Feed};
+
+  // MARK extract
+
+  // MARK transform
+
+  // MARK load
+  #[test]
+  #[mry::lock(src_provider)]
+  fn it_should_solve_every_case() {
+    let feed = Feed::default();
+    let input = feed.clone();
+    mock_src_provider().returns_with(move || input.input());
+
+    cases::assert_solves::<DayN>(env!("CARGO_MANIFEST_DIR"), &feed);
+  }
+}
@@ -0,0 +1,7 @@
+use day_N::DayN;
+use std::process::ExitCode;
+
+
+fn main() -> ExitCode {
+  advent::main::<DayN>()
+}